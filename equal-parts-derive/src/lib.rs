@@ -0,0 +1,135 @@
+//! Derive macro for the [`equal-parts`](https://docs.rs/equal-parts) crate.
+//!
+//! This crate is not meant to be used directly. Enable the `derive` feature of
+//! `equal-parts` and use `equal_parts::EqualParts` as a derive macro instead.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{Data, DeriveInput, Fields, GenericArgument, PathArguments, Type, parse_macro_input};
+
+/// Derives `EqualParts` and `IntoEqualParts` for a newtype around a `Vec<T>` or `[T]`.
+///
+/// For a struct whose only field is a `Vec<T>`, this implements:
+///
+/// - `EqualParts` for `&Newtype`, yielding `&[T]` parts of the inner vector.
+/// - `IntoEqualParts` for `Newtype`, yielding parts wrapped in `Newtype` again.
+///
+/// For a struct whose only field is an unsized `[T]`, only the borrowed `EqualParts`
+/// implementation is generated.
+#[proc_macro_derive(EqualParts)]
+pub fn derive_equal_parts(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// The kind of collection wrapped by the newtype.
+enum Inner<'a> {
+    Vec(&'a Type),
+    Slice(&'a Type),
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                input,
+                "EqualParts can only be derived for structs",
+            ));
+        }
+    };
+    let field = match fields {
+        Fields::Named(named) if named.named.len() == 1 => &named.named[0],
+        Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => &unnamed.unnamed[0],
+        _ => {
+            return Err(syn::Error::new_spanned(
+                fields,
+                "EqualParts can only be derived for structs with exactly one field",
+            ));
+        }
+    };
+    let inner = inner_collection(&field.ty).ok_or_else(|| {
+        syn::Error::new_spanned(&field.ty, "expected the field to be a `Vec<T>` or `[T]`")
+    })?;
+
+    let name = &input.ident;
+    let access = match &field.ident {
+        Some(ident) => quote!(#ident),
+        None => quote!(0),
+    };
+    let (_, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let mut borrowed_generics = input.generics.clone();
+    borrowed_generics
+        .params
+        .insert(0, syn::parse_quote!('__equal_parts));
+    let (borrowed_impl_generics, _, _) = borrowed_generics.split_for_impl();
+
+    let element = match inner {
+        Inner::Vec(element) | Inner::Slice(element) => element,
+    };
+    let borrowed = quote! {
+        impl #borrowed_impl_generics ::equal_parts::EqualParts for &'__equal_parts #name #ty_generics
+        #where_clause
+        {
+            type Item = &'__equal_parts [#element];
+            type Iter = ::equal_parts::EqualPartsIter<'__equal_parts, #element>;
+
+            fn equal_parts(self, num_parts: usize) -> Self::Iter {
+                let inner: &'__equal_parts [#element] = &self.#access;
+                ::equal_parts::EqualParts::equal_parts(inner, num_parts)
+            }
+        }
+    };
+
+    let Inner::Vec(_) = inner else {
+        return Ok(borrowed);
+    };
+    let (impl_generics, _, _) = input.generics.split_for_impl();
+    let wrap = match &field.ident {
+        Some(ident) => quote!((|#ident| #name { #ident })),
+        None => quote!(#name),
+    };
+    Ok(quote! {
+        #borrowed
+
+        impl #impl_generics ::equal_parts::IntoEqualParts for #name #ty_generics #where_clause {
+            type Item = Self;
+            type IntoIter = ::core::iter::Map<
+                ::equal_parts::into::vec::IntoEqualPartsIter<#element>,
+                fn(::std::vec::Vec<#element>) -> Self,
+            >;
+
+            fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+                ::equal_parts::IntoEqualParts::into_equal_parts(self.#access, num_parts)
+                    .map(#wrap as fn(::std::vec::Vec<#element>) -> Self)
+            }
+        }
+    })
+}
+
+/// Returns the wrapped collection if `ty` is a `Vec<T>` or a `[T]`.
+fn inner_collection(ty: &Type) -> Option<Inner<'_>> {
+    match ty {
+        Type::Slice(slice) => Some(Inner::Slice(&slice.elem)),
+        Type::Path(path) if path.qself.is_none() => {
+            let segment = path.path.segments.last()?;
+            if segment.ident != "Vec" {
+                return None;
+            }
+            let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+                return None;
+            };
+            match arguments.args.first()? {
+                GenericArgument::Type(element) if arguments.args.len() == 1 => {
+                    Some(Inner::Vec(element))
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
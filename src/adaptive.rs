@@ -0,0 +1,293 @@
+use crate::parallel::{scoped_map, timed};
+use crate::{EqualParts, gather_round_robin};
+
+/// Number of elements timed in each region by [`choose_strategy`].
+const SAMPLES_PER_REGION: usize = 3;
+
+/// Coefficient of variation of the per-region costs above which round-robin splitting
+/// is chosen over contiguous splitting.
+const VARIATION_THRESHOLD: f64 = 0.25;
+
+/// How the elements of a collection are assigned to parts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitStrategy {
+    /// Each part is a contiguous run of elements, as produced by
+    /// [`equal_parts`](EqualParts::equal_parts).
+    Contiguous,
+    /// Part `i` receives the elements at indices `i, i + n, i + 2n, …`.
+    RoundRobin,
+}
+
+/// Chooses a split strategy by timing `f` on a small sample of elements.
+///
+/// The data is divided into `num_parts` contiguous regions and `f` is timed on a few
+/// evenly spaced elements from each one. If the average cost is roughly the same in
+/// every region, contiguous splitting is chosen since it keeps each part's data
+/// together. If the cost differs a lot between regions (for example because the input
+/// is sorted by difficulty), contiguous parts would finish at very different times, so
+/// round-robin splitting is chosen instead. The decision itself is made by
+/// [`strategy_for_costs`].
+///
+/// The results of the sampled calls are discarded. Since the samples are timed with
+/// the wall clock, a heavily loaded machine can make uniform costs look uneven.
+/// On targets without a clock, such as `wasm32-unknown-unknown`, the costs cannot be
+/// measured and contiguous splitting is always chosen.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::thread::sleep;
+/// use std::time::Duration;
+/// use equal_parts::adaptive::{choose_strategy, SplitStrategy};
+///
+/// let data: Vec<u64> = (0..1000).collect();
+/// // Every element costs the same, so contiguous parts stay balanced.
+/// let strategy = choose_strategy(&data, 4, |_| sleep(Duration::from_millis(1)));
+/// assert_eq!(strategy, SplitStrategy::Contiguous);
+/// ```
+pub fn choose_strategy<T, R, F>(data: &[T], num_parts: usize, f: F) -> SplitStrategy
+where
+    F: Fn(&T) -> R,
+{
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    let regions = num_parts.min(data.len());
+    if regions < 2 {
+        return SplitStrategy::Contiguous;
+    }
+
+    let costs: Option<Vec<f64>> = data
+        .equal_parts(regions)
+        .map(|region| {
+            let step = (region.len() / SAMPLES_PER_REGION).max(1);
+            let (count, elapsed) = timed(|| {
+                let mut count = 0;
+                for item in region.iter().step_by(step).take(SAMPLES_PER_REGION) {
+                    std::hint::black_box(f(item));
+                    count += 1;
+                }
+                count
+            });
+            Some(elapsed?.as_secs_f64() / count as f64)
+        })
+        .collect();
+    let Some(costs) = costs else {
+        return SplitStrategy::Contiguous;
+    };
+
+    strategy_for_costs(&costs)
+}
+
+/// Chooses a split strategy from the measured cost of each contiguous region.
+///
+/// `costs` holds the average cost per element of each region, in any unit, such as
+/// the seconds per element that [`choose_strategy`] measures. Round-robin splitting is
+/// chosen when the costs vary by more than a quarter of their mean (their coefficient
+/// of variation is above 0.25), and contiguous splitting otherwise. Fewer than two
+/// regions, or costs that are all zero, always give contiguous splitting.
+///
+/// This is the deterministic half of [`choose_strategy`], for callers that measure
+/// costs in their own way, such as from a previous run's timings.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::adaptive::{strategy_for_costs, SplitStrategy};
+///
+/// assert_eq!(strategy_for_costs(&[1.0, 1.1, 0.9, 1.0]), SplitStrategy::Contiguous);
+/// // The last region is much more expensive, as in a work list sorted by difficulty.
+/// assert_eq!(strategy_for_costs(&[1.0, 1.0, 1.0, 5.0]), SplitStrategy::RoundRobin);
+/// ```
+pub fn strategy_for_costs(costs: &[f64]) -> SplitStrategy {
+    if costs.len() < 2 {
+        return SplitStrategy::Contiguous;
+    }
+
+    let mean = costs.iter().sum::<f64>() / costs.len() as f64;
+    if mean == 0.0 {
+        return SplitStrategy::Contiguous;
+    }
+    let variance = costs.iter().map(|cost| (cost - mean).powi(2)).sum::<f64>() / costs.len() as f64;
+
+    if variance.sqrt() / mean > VARIATION_THRESHOLD {
+        SplitStrategy::RoundRobin
+    } else {
+        SplitStrategy::Contiguous
+    }
+}
+
+/// Maps `f` over `data` on `num_parts` threads, choosing the split strategy
+/// automatically.
+///
+/// The strategy is picked with [`choose_strategy`], then each part is processed on its
+/// own scoped thread, or on the calling thread where
+/// [`map_parts`](crate::parallel::map_parts) would do so. The results are returned in
+/// the same order as `data` regardless of the strategy used. The sampled elements are
+/// processed a second time as part of the job, so `f` should not rely on being called
+/// exactly once per element.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0, or if `f` panics on any thread.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::adaptive::adaptive_map;
+///
+/// let data: Vec<u64> = (0..100).collect();
+/// let squares = adaptive_map(&data, 4, |x| x * x);
+/// assert_eq!(squares, data.iter().map(|x| x * x).collect::<Vec<_>>());
+/// ```
+pub fn adaptive_map<T, R, F>(data: &[T], num_parts: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let strategy = choose_strategy(data, num_parts, &f);
+    map_with_strategy(data, num_parts, strategy, f)
+}
+
+/// Maps `f` over `data` on `num_parts` threads using the given split strategy.
+///
+/// This is the second half of [`adaptive_map`], for callers that want to pick the
+/// strategy themselves or reuse a previous [`choose_strategy`] result. The results are
+/// returned in the same order as `data`.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0, or if `f` panics on any thread.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::adaptive::{map_with_strategy, SplitStrategy};
+///
+/// let data = vec![1, 2, 3, 4, 5];
+/// let doubled = map_with_strategy(&data, 2, SplitStrategy::RoundRobin, |x| x * 2);
+/// assert_eq!(doubled, vec![2, 4, 6, 8, 10]);
+/// ```
+pub fn map_with_strategy<T, R, F>(
+    data: &[T],
+    num_parts: usize,
+    strategy: SplitStrategy,
+    f: F,
+) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+    let f = &f;
+
+    match strategy {
+        SplitStrategy::Contiguous => scoped_map(
+            data.equal_parts(num_parts)
+                .map(|part| move || part.iter().map(f).collect::<Vec<R>>()),
+        )
+        .into_iter()
+        .flatten()
+        .collect(),
+        SplitStrategy::RoundRobin => {
+            gather_round_robin(scoped_map((0..num_parts.min(data.len())).map(|first| {
+                move || {
+                    data[first..]
+                        .iter()
+                        .step_by(num_parts)
+                        .map(f)
+                        .collect::<Vec<R>>()
+                }
+            })))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        SplitStrategy, adaptive_map, choose_strategy, map_with_strategy, strategy_for_costs,
+    };
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    /// Cost grows with the value, like a work list sorted by difficulty.
+    fn skewed(x: &u64) -> u64 {
+        if *x >= 75 {
+            sleep(Duration::from_millis(5));
+        }
+        x * 2
+    }
+
+    #[test]
+    fn uniform_cost_is_contiguous() {
+        assert_eq!(strategy_for_costs(&[2.0; 4]), SplitStrategy::Contiguous);
+        // A coefficient of variation of 0.2 is below the threshold.
+        let costs = [0.8, 1.2, 0.8, 1.2];
+        assert_eq!(strategy_for_costs(&costs), SplitStrategy::Contiguous);
+        assert_eq!(strategy_for_costs(&[0.0; 3]), SplitStrategy::Contiguous);
+    }
+
+    #[test]
+    fn uneven_cost_is_round_robin() {
+        // A coefficient of variation of 0.3 is above the threshold.
+        let costs = [0.7, 1.3, 0.7, 1.3];
+        assert_eq!(strategy_for_costs(&costs), SplitStrategy::RoundRobin);
+        assert_eq!(
+            strategy_for_costs(&[0.0, 0.0, 1.0]),
+            SplitStrategy::RoundRobin
+        );
+    }
+
+    #[test]
+    fn fewer_than_two_costs_is_contiguous() {
+        assert_eq!(strategy_for_costs(&[]), SplitStrategy::Contiguous);
+        assert_eq!(strategy_for_costs(&[3.0]), SplitStrategy::Contiguous);
+    }
+
+    #[test]
+    fn skewed_cost_is_round_robin() {
+        let data: Vec<u64> = (0..100).collect();
+        assert_eq!(choose_strategy(&data, 4, skewed), SplitStrategy::RoundRobin);
+    }
+
+    #[test]
+    fn single_region_is_contiguous() {
+        let data = [1];
+        assert_eq!(choose_strategy(&data, 4, |x| *x), SplitStrategy::Contiguous);
+    }
+
+    #[test]
+    fn adaptive_map_preserves_order() {
+        let data: Vec<u64> = (0..100).collect();
+        let expected: Vec<u64> = data.iter().map(|x| x * 2).collect();
+        assert_eq!(adaptive_map(&data, 4, skewed), expected);
+    }
+
+    #[test]
+    fn both_strategies_agree() {
+        let data: Vec<i32> = (0..23).collect();
+        let contiguous = map_with_strategy(&data, 5, SplitStrategy::Contiguous, |x| x + 1);
+        let round_robin = map_with_strategy(&data, 5, SplitStrategy::RoundRobin, |x| x + 1);
+        assert_eq!(contiguous, round_robin);
+        assert_eq!(contiguous, (1..24).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn empty_data() {
+        let data: Vec<i32> = vec![];
+        assert_eq!(adaptive_map(&data, 3, |x| *x), Vec::<i32>::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let data = [1, 2, 3];
+        let _ = adaptive_map(&data, 0, |x| *x);
+    }
+}
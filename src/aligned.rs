@@ -0,0 +1,352 @@
+use std::mem::size_of;
+
+use crate::boundary::snapped_boundaries;
+use crate::count::SplitCount;
+use crate::split_count;
+
+/// The cache line size assumed by
+/// [`equal_parts_cache_aligned`](AddressAlignedParts::equal_parts_cache_aligned), in
+/// bytes.
+///
+/// This is the line size of current x86-64 and most ARM processors. Some processors
+/// fetch lines in adjacent pairs, so use
+/// [`equal_parts_address_aligned`](AddressAlignedParts::equal_parts_address_aligned)
+/// with 128 to keep parts apart on those as well.
+pub const CACHE_LINE_SIZE: usize = 64;
+
+/// Iterator over approximately equal parts of a slice whose boundaries are aligned.
+///
+/// This iterator is created by calling
+/// [`equal_parts_aligned`](AlignedEqualParts::equal_parts_aligned) on a slice.
+#[derive(Debug, Clone)]
+pub struct AlignedParts<'a, T> {
+    rest: &'a [T],
+    blocks: SplitCount,
+    align: usize,
+}
+
+impl<'a, T> Iterator for AlignedParts<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let blocks = self.blocks.next()?;
+        let len = blocks.saturating_mul(self.align).min(self.rest.len());
+        let (part, rest) = self.rest.split_at(len);
+        self.rest = rest;
+        Some(part)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.blocks.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for AlignedParts<'_, T> {}
+
+/// A trait for splitting slices into approximately equal parts that start at
+/// multiples of an alignment.
+pub trait AlignedEqualParts<'a, T> {
+    /// Splits the slice into approximately equal parts whose boundaries are all
+    /// multiples of `align`.
+    ///
+    /// This keeps fixed-size records intact, for example when an array of packed
+    /// structs is viewed as bytes, or when a buffer must be processed in whole SIMD
+    /// lanes or pages. The slice is balanced in blocks of `align` elements using the
+    /// same rule as [`equal_parts`](crate::EqualParts::equal_parts), so part lengths
+    /// differ by at most one block and the longer parts come first. Every part is a
+    /// whole number of blocks, except that the last part also holds any final partial
+    /// block when the length is not a multiple of `align`.
+    ///
+    /// If there are fewer blocks than `num_parts`, there is one part per block. No
+    /// part is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` or `align` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::aligned::AlignedEqualParts;
+    ///
+    /// // Seven 4-byte records and a 2-byte trailer.
+    /// let bytes = [0u8; 30];
+    /// let lens: Vec<usize> = bytes.equal_parts_aligned(3, 4).map(|part| part.len()).collect();
+    ///
+    /// assert_eq!(lens, vec![12, 12, 6]);
+    /// ```
+    fn equal_parts_aligned(self, num_parts: usize, align: usize) -> AlignedParts<'a, T>;
+}
+
+impl<'a, T> AlignedEqualParts<'a, T> for &'a [T] {
+    fn equal_parts_aligned(self, num_parts: usize, align: usize) -> AlignedParts<'a, T> {
+        assert!(align > 0, "Alignment must be greater than 0");
+
+        AlignedParts {
+            rest: self,
+            blocks: split_count(self.len().div_ceil(align), num_parts),
+            align,
+        }
+    }
+}
+
+impl<'a, T> AlignedEqualParts<'a, T> for &'a Vec<T> {
+    fn equal_parts_aligned(self, num_parts: usize, align: usize) -> AlignedParts<'a, T> {
+        self.as_slice().equal_parts_aligned(num_parts, align)
+    }
+}
+
+/// A trait for splitting slices into approximately equal parts that start at aligned
+/// memory addresses.
+///
+/// Unlike [`AlignedEqualParts`], which aligns boundaries to indices, this aligns them
+/// to addresses, so it depends on where the slice is stored. When parallel threads
+/// write to neighboring parts, parts that share a cache line make the processors
+/// fight over it (false sharing), and SIMD loops run best when their data starts on
+/// a vector boundary. Splitting at aligned addresses avoids both.
+///
+/// This trait is implemented for both shared and mutable slices, and the parts have
+/// the same type as the slice.
+pub trait AddressAlignedParts: Sized {
+    /// Splits the slice into up to `num_parts` parts such that every part but the
+    /// first starts at an address that is a multiple of `align` bytes.
+    ///
+    /// The boundaries start at the offsets
+    /// [`equal_parts`](crate::EqualParts::equal_parts) would use and each moves to the
+    /// nearest aligned element, with ties going to the earlier one. Each boundary
+    /// therefore moves by at most half an alignment block, and every part is within
+    /// one block of its equal share. The first part holds any elements before the
+    /// first aligned address, and the last part any elements after the last one.
+    ///
+    /// A boundary is dropped if it would make a part empty, so fewer than `num_parts`
+    /// parts are returned when the slice spans only a few blocks. If no element starts
+    /// at an aligned address, the whole slice is a single part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0, if `align` is not a power of two, or if `align` is
+    /// not a multiple of the element size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::aligned::AddressAlignedParts;
+    ///
+    /// let data = vec![0u32; 1000];
+    /// let parts = data.as_slice().equal_parts_address_aligned(3, 32);
+    ///
+    /// assert_eq!(parts.len(), 3);
+    /// for part in &parts[1..] {
+    ///     assert_eq!(part.as_ptr() as usize % 32, 0);
+    /// }
+    /// ```
+    fn equal_parts_address_aligned(self, num_parts: usize, align: usize) -> Vec<Self>;
+
+    /// Splits the slice into up to `num_parts` parts that start on separate cache
+    /// lines.
+    ///
+    /// This is [`equal_parts_address_aligned`](AddressAlignedParts::equal_parts_address_aligned)
+    /// with an alignment of [`CACHE_LINE_SIZE`], so threads writing to different parts
+    /// never write to the same cache line.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0, or if [`CACHE_LINE_SIZE`] is not a multiple of the
+    /// element size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::thread;
+    /// use equal_parts::aligned::AddressAlignedParts;
+    ///
+    /// let mut counts = vec![0u64; 100];
+    /// thread::scope(|scope| {
+    ///     for part in counts.as_mut_slice().equal_parts_cache_aligned(4) {
+    ///         scope.spawn(move || part.iter_mut().for_each(|count| *count += 1));
+    ///     }
+    /// });
+    /// assert!(counts.iter().all(|&count| count == 1));
+    /// ```
+    fn equal_parts_cache_aligned(self, num_parts: usize) -> Vec<Self> {
+        self.equal_parts_address_aligned(num_parts, CACHE_LINE_SIZE)
+    }
+}
+
+impl<T> AddressAlignedParts for &[T] {
+    fn equal_parts_address_aligned(self, num_parts: usize, align: usize) -> Vec<Self> {
+        let boundaries = address_aligned_boundaries(self.as_ptr(), self.len(), num_parts, align);
+
+        let mut rest = self;
+        let mut parts = Vec::with_capacity(boundaries.len() + 1);
+        let mut start = 0;
+        for boundary in boundaries {
+            let (part, tail) = rest.split_at(boundary - start);
+            parts.push(part);
+            rest = tail;
+            start = boundary;
+        }
+        if !rest.is_empty() {
+            parts.push(rest);
+        }
+        parts
+    }
+}
+
+impl<T> AddressAlignedParts for &mut [T] {
+    fn equal_parts_address_aligned(self, num_parts: usize, align: usize) -> Vec<Self> {
+        let boundaries = address_aligned_boundaries(self.as_ptr(), self.len(), num_parts, align);
+
+        let mut rest = self;
+        let mut parts = Vec::with_capacity(boundaries.len() + 1);
+        let mut start = 0;
+        for boundary in boundaries {
+            let (part, tail) = rest.split_at_mut(boundary - start);
+            parts.push(part);
+            rest = tail;
+            start = boundary;
+        }
+        if !rest.is_empty() {
+            parts.push(rest);
+        }
+        parts
+    }
+}
+
+/// Returns the inner boundaries of an equal split of the `len` elements at `ptr`,
+/// each moved to the nearest element whose address is a multiple of `align`.
+fn address_aligned_boundaries<T>(
+    ptr: *const T,
+    len: usize,
+    num_parts: usize,
+    align: usize,
+) -> Vec<usize> {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+    assert!(align.is_power_of_two(), "Alignment must be a power of two");
+    let size = size_of::<T>();
+    assert!(
+        size > 0 && align.is_multiple_of(size),
+        "Alignment must be a multiple of the element size"
+    );
+
+    // Aligned elements are `step` apart, starting at `first`. `align_offset` returns
+    // `usize::MAX` when no element can be aligned.
+    let step = align / size;
+    let first = ptr.align_offset(align);
+    if first >= len {
+        return Vec::new();
+    }
+    snapped_boundaries(len, num_parts, |position| {
+        position >= first && (position - first).is_multiple_of(step)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AddressAlignedParts, AlignedEqualParts};
+
+    #[test]
+    fn boundaries_are_aligned() {
+        let data: Vec<u32> = (0..103).collect();
+        for align in 1..12 {
+            for num_parts in 1..20 {
+                let parts: Vec<&[u32]> = data.equal_parts_aligned(num_parts, align).collect();
+                assert_eq!(parts.len(), num_parts.min(103usize.div_ceil(align)));
+                assert!(parts.iter().all(|part| !part.is_empty()));
+                assert_eq!(parts.concat(), data);
+
+                let mut offset = 0;
+                for part in &parts {
+                    assert_eq!(offset % align, 0);
+                    offset += part.len();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn align_of_one_matches_equal_parts() {
+        use crate::EqualParts;
+
+        let data: Vec<u32> = (0..17).collect();
+        let aligned: Vec<&[u32]> = data.equal_parts_aligned(5, 1).collect();
+        let equal: Vec<&[u32]> = data.equal_parts(5).collect();
+        assert_eq!(aligned, equal);
+    }
+
+    #[test]
+    fn shorter_than_one_block() {
+        let data = [1, 2, 3];
+        let parts: Vec<&[i32]> = data.equal_parts_aligned(4, 8).collect();
+        assert_eq!(parts, vec![&data[..]]);
+        assert_eq!(Vec::<u8>::new().equal_parts_aligned(2, 4).next(), None);
+    }
+
+    #[test]
+    fn address_aligned_parts_start_on_aligned_addresses() {
+        let data: Vec<u16> = (0..500).collect();
+        // Start at every possible misalignment within a cache line.
+        for skip in 0..32 {
+            let slice = &data[skip..];
+            for num_parts in 1..12 {
+                let parts = slice.equal_parts_cache_aligned(num_parts);
+                assert_eq!(parts.len(), num_parts);
+                assert_eq!(parts.concat(), slice);
+                for part in &parts[1..] {
+                    assert_eq!(part.as_ptr() as usize % 64, 0);
+                }
+
+                // Every part is within one cache line of its equal share.
+                let share = slice.len() as f64 / num_parts as f64;
+                for part in &parts {
+                    assert!((part.len() as f64 - share).abs() <= 32.0 + 1.0);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn address_aligned_mut_parts_match_shared_parts() {
+        let mut data: Vec<u64> = (0..300).collect();
+        let lens: Vec<usize> = data
+            .as_slice()
+            .equal_parts_address_aligned(5, 128)
+            .iter()
+            .map(|part| part.len())
+            .collect();
+        let parts = data.as_mut_slice().equal_parts_address_aligned(5, 128);
+        assert_eq!(
+            parts.iter().map(|part| part.len()).collect::<Vec<_>>(),
+            lens
+        );
+    }
+
+    #[test]
+    fn address_aligned_short_slices() {
+        let data = [1u8, 2, 3];
+        assert_eq!(
+            data.as_slice().equal_parts_address_aligned(4, 64).concat(),
+            data
+        );
+        assert!((&[] as &[u8]).equal_parts_address_aligned(4, 64).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn address_aligned_panics_with_unaligned_element_size() {
+        let data = [[0u8; 3]; 10];
+        let _ = data.as_slice().equal_parts_address_aligned(2, 64);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_align() {
+        let _ = [1, 2].equal_parts_aligned(2, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = [1, 2].equal_parts_aligned(0, 2);
+    }
+}
@@ -0,0 +1,192 @@
+use std::ops::Range;
+
+use crate::full_part_count;
+
+/// A trait for splitting the slot index space of an arena by occupancy.
+///
+/// Arenas such as `slab::Slab` and `generational_arena::Arena` store their values
+/// in a vector of slots, some of which are vacant. Splitting the slot indices evenly
+/// can leave one part with far more live values than another. This trait instead
+/// splits the index space into contiguous ranges that each contain an approximately
+/// equal number of occupied slots, using the same largest-first rule as
+/// [`equal_parts`](crate::EqualParts::equal_parts).
+///
+/// The ranges cover every slot from 0 up to the arena's capacity, so together they
+/// visit every value exactly once. Each range can then be iterated independently
+/// (for example on its own scoped thread) without cloning or densifying the arena.
+pub trait OccupancyParts {
+    /// Splits the arena's slot indices into ranges with balanced occupancy.
+    ///
+    /// Returns at most `num_parts` ranges. Fewer ranges are returned when the arena
+    /// holds fewer values than `num_parts`, and none when it is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    fn occupancy_parts(&self, num_parts: usize) -> Vec<Range<usize>>;
+}
+
+/// Computes slot ranges containing balanced numbers of occupied slots.
+///
+/// This is the building block behind [`OccupancyParts`], exposed for arena types that
+/// do not have a built-in implementation. `keys` must yield the occupied slot indices
+/// in increasing order, `occupied` must be their count, and every key must be less than
+/// `slots`.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::arena::occupancy_ranges;
+///
+/// // Slots 1, 2, 9, 10, 11 and 15 out of 20 are occupied.
+/// let keys = [1, 2, 9, 10, 11, 15];
+/// let ranges = occupancy_ranges(keys.into_iter(), keys.len(), 20, 3);
+/// assert_eq!(ranges, vec![0..3, 3..11, 11..20]);
+/// ```
+pub fn occupancy_ranges(
+    keys: impl Iterator<Item = usize>,
+    occupied: usize,
+    slots: usize,
+    num_parts: usize,
+) -> Vec<Range<usize>> {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    let part_size = occupied.div_ceil(num_parts);
+    let mut full_parts_left = full_part_count(occupied, num_parts);
+
+    let mut ranges = Vec::with_capacity(num_parts.min(occupied));
+    let mut start = 0;
+    let mut in_part = 0;
+    let mut target = part_size;
+    for key in keys {
+        in_part += 1;
+        if in_part == target {
+            ranges.push(start..key + 1);
+            start = key + 1;
+            in_part = 0;
+            full_parts_left = full_parts_left.saturating_sub(1);
+            target = part_size - (full_parts_left.min(1) ^ 1);
+        }
+    }
+
+    // Extend the last range over any trailing vacant slots.
+    if let Some(last) = ranges.last_mut() {
+        last.end = slots;
+    }
+    ranges
+}
+
+#[cfg(feature = "slab")]
+impl<T> OccupancyParts for slab::Slab<T> {
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::OccupancyParts;
+    /// use slab::Slab;
+    ///
+    /// let mut slab = Slab::with_capacity(8);
+    /// let keys: Vec<usize> = (0..8).map(|i| slab.insert(i)).collect();
+    /// // Free most of the first half.
+    /// for key in &keys[..3] {
+    ///     slab.remove(*key);
+    /// }
+    ///
+    /// let ranges = slab.occupancy_parts(2);
+    /// assert_eq!(ranges, vec![0..6, 6..slab.capacity()]);
+    /// ```
+    fn occupancy_parts(&self, num_parts: usize) -> Vec<Range<usize>> {
+        occupancy_ranges(
+            self.iter().map(|(key, _)| key),
+            self.len(),
+            self.capacity(),
+            num_parts,
+        )
+    }
+}
+
+#[cfg(feature = "generational-arena")]
+impl<T> OccupancyParts for generational_arena::Arena<T> {
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::OccupancyParts;
+    /// use generational_arena::Arena;
+    ///
+    /// let mut arena = Arena::with_capacity(6);
+    /// let indices: Vec<_> = (0..6).map(|i| arena.insert(i)).collect();
+    /// arena.remove(indices[4]);
+    /// arena.remove(indices[5]);
+    ///
+    /// let ranges = arena.occupancy_parts(2);
+    /// assert_eq!(ranges, vec![0..2, 2..arena.capacity()]);
+    /// ```
+    fn occupancy_parts(&self, num_parts: usize) -> Vec<Range<usize>> {
+        occupancy_ranges(
+            self.iter().map(|(index, _)| index.into_raw_parts().0),
+            self.len(),
+            self.capacity(),
+            num_parts,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::occupancy_ranges;
+
+    #[test]
+    fn dense_slots() {
+        let ranges = occupancy_ranges(0..7, 7, 7, 3);
+        assert_eq!(ranges, vec![0..3, 3..5, 5..7]);
+    }
+
+    #[test]
+    fn sparse_slots() {
+        let keys = [1, 2, 9, 10, 11, 15];
+        let ranges = occupancy_ranges(keys.into_iter(), keys.len(), 20, 3);
+        assert_eq!(ranges, vec![0..3, 3..11, 11..20]);
+    }
+
+    #[test]
+    fn fewer_values_than_parts() {
+        let keys = [4, 6];
+        let ranges = occupancy_ranges(keys.into_iter(), keys.len(), 8, 4);
+        assert_eq!(ranges, vec![0..5, 5..8]);
+    }
+
+    #[test]
+    fn empty_arena() {
+        let ranges = occupancy_ranges(std::iter::empty(), 0, 16, 4);
+        assert!(ranges.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = occupancy_ranges(0..4, 4, 4, 0);
+    }
+
+    #[cfg(feature = "slab")]
+    #[test]
+    fn slab_ranges_cover_every_value() {
+        use super::OccupancyParts;
+
+        let mut slab = slab::Slab::new();
+        let keys: Vec<usize> = (0..100).map(|i| slab.insert(i)).collect();
+        for key in keys.iter().filter(|key| *key % 3 != 0 && **key < 50) {
+            slab.remove(*key);
+        }
+
+        let ranges = slab.occupancy_parts(4);
+        let counts: Vec<usize> = ranges
+            .iter()
+            .map(|range| range.clone().filter(|key| slab.contains(*key)).count())
+            .collect();
+        assert_eq!(counts.iter().sum::<usize>(), slab.len());
+        assert!(counts.iter().max().unwrap() - counts.iter().min().unwrap() <= 1);
+    }
+}
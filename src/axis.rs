@@ -0,0 +1,206 @@
+use ndarray::{ArrayBase, ArrayView, ArrayViewMut, Axis, Data, DataMut, Dimension};
+
+use crate::full_part_count;
+
+/// A trait for splitting `ndarray` arrays into approximately equal blocks along an axis.
+///
+/// The blocks are contiguous ranges of indices along `axis`, with the same sizes
+/// [`equal_parts`](crate::EqualParts::equal_parts) would give a slice as long as that
+/// axis, larger blocks first. They are yielded as [`ArrayView`]s, so no elements are
+/// copied; call [`to_owned`](ArrayBase::to_owned) on a block to get an owned `Array`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::axis::EqualPartsAxis;
+/// use ndarray::{Axis, array};
+///
+/// let matrix = array![[1, 2], [3, 4], [5, 6], [7, 8], [9, 10]];
+/// let sums: Vec<i32> = matrix
+///     .equal_parts_axis(Axis(0), 2)
+///     .map(|block| block.sum())
+///     .collect();
+/// assert_eq!(sums, vec![21, 34]);
+/// ```
+pub trait EqualPartsAxis<A, D: Dimension> {
+    /// Splits the array into approximately equal blocks along `axis`.
+    ///
+    /// If the axis is shorter than `num_parts`, one block per index is returned.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0 or `axis` is out of bounds.
+    fn equal_parts_axis(&self, axis: Axis, num_parts: usize) -> AxisParts<'_, A, D>;
+}
+
+/// A trait for splitting `ndarray` arrays into approximately equal mutable blocks along
+/// an axis.
+///
+/// This is the mutable counterpart of [`EqualPartsAxis`]. The blocks do not overlap,
+/// so each one can be handed to its own thread and modified in place.
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+///
+/// use equal_parts::axis::EqualPartsAxisMut;
+/// use ndarray::{Array2, Axis};
+///
+/// let mut image = Array2::<u8>::zeros((6, 4));
+/// thread::scope(|scope| {
+///     for (i, mut rows) in image.equal_parts_axis_mut(Axis(0), 3).enumerate() {
+///         scope.spawn(move || rows.fill(i as u8));
+///     }
+/// });
+/// assert_eq!(image.row(5).to_vec(), vec![2; 4]);
+/// ```
+pub trait EqualPartsAxisMut<A, D: Dimension> {
+    /// Splits the array into approximately equal mutable blocks along `axis`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0 or `axis` is out of bounds.
+    fn equal_parts_axis_mut(&mut self, axis: Axis, num_parts: usize) -> AxisPartsMut<'_, A, D>;
+}
+
+/// Iterator that yields approximately equal blocks of an array along an axis.
+///
+/// This iterator is created by [`EqualPartsAxis::equal_parts_axis`].
+#[derive(Debug, Clone)]
+pub struct AxisParts<'a, A, D: Dimension> {
+    rest: ArrayView<'a, A, D>,
+    axis: Axis,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<'a, A, D: Dimension> Iterator for AxisParts<'a, A, D> {
+    type Item = ArrayView<'a, A, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.len_of(self.axis) == 0 {
+            None
+        } else {
+            let split_point = self.part_size - (self.full_parts_left.min(1) ^ 1);
+            self.full_parts_left -= self.full_parts_left.min(1);
+
+            let (chunk, rest) = self.rest.clone().split_at(self.axis, split_point);
+            self.rest = rest;
+            Some(chunk)
+        }
+    }
+}
+
+/// Iterator that yields approximately equal mutable blocks of an array along an axis.
+///
+/// This iterator is created by [`EqualPartsAxisMut::equal_parts_axis_mut`].
+#[derive(Debug)]
+pub struct AxisPartsMut<'a, A, D: Dimension> {
+    rest: Option<ArrayViewMut<'a, A, D>>,
+    axis: Axis,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<'a, A, D: Dimension> Iterator for AxisPartsMut<'a, A, D> {
+    type Item = ArrayViewMut<'a, A, D>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.take().filter(|rest| rest.len_of(self.axis) > 0)?;
+
+        let split_point = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let (chunk, rest) = rest.split_at(self.axis, split_point);
+        self.rest = Some(rest);
+        Some(chunk)
+    }
+}
+
+/// Returns the part size and the number of full-size parts for an axis of `len`.
+fn plan(len: usize, num_parts: usize) -> (usize, usize) {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    let part_size = len.div_ceil(num_parts);
+    (part_size, full_part_count(len, num_parts))
+}
+
+impl<A, S, D> EqualPartsAxis<A, D> for ArrayBase<S, D>
+where
+    S: Data<Elem = A>,
+    D: Dimension,
+{
+    fn equal_parts_axis(&self, axis: Axis, num_parts: usize) -> AxisParts<'_, A, D> {
+        let (part_size, full_parts_left) = plan(self.len_of(axis), num_parts);
+        AxisParts {
+            rest: self.view(),
+            axis,
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+impl<A, S, D> EqualPartsAxisMut<A, D> for ArrayBase<S, D>
+where
+    S: DataMut<Elem = A>,
+    D: Dimension,
+{
+    fn equal_parts_axis_mut(&mut self, axis: Axis, num_parts: usize) -> AxisPartsMut<'_, A, D> {
+        let (part_size, full_parts_left) = plan(self.len_of(axis), num_parts);
+        AxisPartsMut {
+            rest: Some(self.view_mut()),
+            axis,
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{Array, Array3, Axis, array};
+
+    use super::{EqualPartsAxis, EqualPartsAxisMut};
+
+    #[test]
+    fn splits_along_columns() {
+        let matrix = array![[1, 2, 3, 4, 5], [6, 7, 8, 9, 10]];
+        let blocks: Vec<_> = matrix.equal_parts_axis(Axis(1), 2).collect();
+        assert_eq!(blocks[0], array![[1, 2, 3], [6, 7, 8]]);
+        assert_eq!(blocks[1], array![[4, 5], [9, 10]]);
+    }
+
+    #[test]
+    fn block_heights_match_slices() {
+        let cube = Array3::<f32>::zeros((10, 2, 3));
+        let heights: Vec<usize> = cube
+            .equal_parts_axis(Axis(0), 4)
+            .map(|block| block.len_of(Axis(0)))
+            .collect();
+        assert_eq!(heights, vec![3, 3, 2, 2]);
+        assert_eq!(cube.equal_parts_axis(Axis(1), 5).count(), 2);
+    }
+
+    #[test]
+    fn mutable_blocks_write_through() {
+        let mut data = Array::from_elem((5, 2), 0);
+        for (i, mut block) in data.equal_parts_axis_mut(Axis(0), 3).enumerate() {
+            block.fill(i);
+        }
+        assert_eq!(data.column(0).to_vec(), vec![0, 0, 1, 1, 2]);
+    }
+
+    #[test]
+    fn empty_axis() {
+        let data = Array::<u8, _>::zeros((0, 4));
+        assert_eq!(data.equal_parts_axis(Axis(0), 3).count(), 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = array![1, 2].equal_parts_axis(Axis(0), 0);
+    }
+}
@@ -0,0 +1,212 @@
+use std::ops::{Deref, DerefMut};
+
+use image::{ImageBuffer, Pixel};
+
+use crate::split_count;
+
+/// A band of whole rows borrowed from an image, paired with the index of its first row.
+pub type Band<'a, P> = (u32, ImageBuffer<P, &'a [<P as Pixel>::Subpixel]>);
+
+/// A mutable band of whole rows borrowed from an image, paired with the index of its
+/// first row.
+pub type BandMut<'a, P> = (u32, ImageBuffer<P, &'a mut [<P as Pixel>::Subpixel]>);
+
+/// A trait for splitting images into approximately equal horizontal bands.
+///
+/// The rows of the image are divided into contiguous bands whose heights differ by at
+/// most one, taller bands first, following the same rules as
+/// [`equal_parts`](crate::EqualParts::equal_parts). A band never splits a row, and each
+/// one is itself an [`ImageBuffer`] borrowing the original samples, so the usual pixel
+/// accessors work on it and nothing is copied.
+///
+/// Each band comes with the index of its first row in the original image, which is
+/// what filters need to map band coordinates back to image coordinates.
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+///
+/// use equal_parts::bands::{EqualBands, EqualBandsMut};
+/// use image::{GrayImage, Luma};
+///
+/// let mut image = GrayImage::new(4, 7);
+/// thread::scope(|scope| {
+///     for (top, mut band) in image.equal_bands_mut(3) {
+///         scope.spawn(move || {
+///             for (_, y, pixel) in band.enumerate_pixels_mut() {
+///                 *pixel = Luma([(top + y) as u8]);
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(image.get_pixel(0, 6), &Luma([6]));
+/// let heights: Vec<u32> = image.equal_bands(3).iter().map(|(_, band)| band.height()).collect();
+/// assert_eq!(heights, vec![3, 2, 2]);
+/// ```
+pub trait EqualBands<P: Pixel> {
+    /// Splits the image into approximately equal bands of whole rows.
+    ///
+    /// Fewer than `num_parts` bands are returned if the image has fewer rows than
+    /// that, and none if it has no rows.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    fn equal_bands(&self, num_parts: usize) -> Vec<Band<'_, P>>;
+}
+
+/// A trait for splitting images into approximately equal mutable horizontal bands.
+///
+/// This is the mutable counterpart of [`EqualBands`], for images whose samples can be
+/// borrowed mutably.
+pub trait EqualBandsMut<P: Pixel> {
+    /// Splits the image into approximately equal mutable bands of whole rows.
+    ///
+    /// The bands do not overlap, so each one can be handed to its own thread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    fn equal_bands_mut(&mut self, num_parts: usize) -> Vec<BandMut<'_, P>>;
+}
+
+impl<P, Container> EqualBands<P> for ImageBuffer<P, Container>
+where
+    P: Pixel,
+    Container: Deref<Target = [P::Subpixel]>,
+{
+    fn equal_bands(&self, num_parts: usize) -> Vec<Band<'_, P>> {
+        let width = self.width();
+        let row_len = width as usize * usize::from(P::CHANNEL_COUNT);
+        let samples: &[P::Subpixel] = self;
+        split_count(self.height() as usize, num_parts)
+            .with_offsets()
+            .map(|(top, height)| {
+                let band = &samples[top * row_len..(top + height) * row_len];
+                let buffer = ImageBuffer::from_raw(width, height as u32, band)
+                    .expect("band length matches its dimensions");
+                (top as u32, buffer)
+            })
+            .collect()
+    }
+}
+
+impl<P, Container> EqualBandsMut<P> for ImageBuffer<P, Container>
+where
+    P: Pixel,
+    Container: Deref<Target = [P::Subpixel]> + DerefMut,
+{
+    fn equal_bands_mut(&mut self, num_parts: usize) -> Vec<BandMut<'_, P>> {
+        let (width, height) = self.dimensions();
+        let row_len = width as usize * usize::from(P::CHANNEL_COUNT);
+        let mut rest: &mut [P::Subpixel] = self;
+        split_count(height as usize, num_parts)
+            .with_offsets()
+            .map(|(top, height)| {
+                let (band, tail) = std::mem::take(&mut rest).split_at_mut(height * row_len);
+                rest = tail;
+                let buffer = ImageBuffer::from_raw(width, height as u32, band)
+                    .expect("band length matches its dimensions");
+                (top as u32, buffer)
+            })
+            .collect()
+    }
+}
+
+/// Splits a row-major slice of pixels into approximately equal bands of whole rows.
+///
+/// This is the [`EqualBands`] split for pixel data that is not in an
+/// [`ImageBuffer`], such as a `&[Rgb<u8>]` decoded by another library. `width` is the
+/// number of pixels per row.
+///
+/// # Panics
+///
+/// Panics if `num_parts` or `width` is 0, or if the length of `pixels` is not a
+/// multiple of `width`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::bands::pixel_bands;
+/// use image::Rgb;
+///
+/// let pixels = vec![Rgb([0u8, 0, 0]); 3 * 5];
+/// let heights: Vec<usize> = pixel_bands(&pixels, 3, 2).iter().map(|band| band.len() / 3).collect();
+/// assert_eq!(heights, vec![3, 2]);
+/// ```
+pub fn pixel_bands<P>(pixels: &[P], width: usize, num_parts: usize) -> Vec<&[P]> {
+    assert!(width > 0, "Width must be greater than 0");
+    assert!(
+        pixels.len().is_multiple_of(width),
+        "Pixel count must be a multiple of the width"
+    );
+
+    split_count(pixels.len() / width, num_parts)
+        .with_offsets()
+        .map(|(top, height)| &pixels[top * width..(top + height) * width])
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use image::{ImageBuffer, Rgb, RgbImage};
+
+    use super::{EqualBands, EqualBandsMut, pixel_bands};
+
+    #[test]
+    fn bands_keep_whole_rows() {
+        let image = RgbImage::from_fn(5, 10, |x, y| Rgb([x as u8, y as u8, 0]));
+        let bands = image.equal_bands(4);
+        let layout: Vec<(u32, u32)> = bands
+            .iter()
+            .map(|(top, band)| (*top, band.height()))
+            .collect();
+        assert_eq!(layout, vec![(0, 3), (3, 3), (6, 2), (8, 2)]);
+        for (top, band) in &bands {
+            assert_eq!(band.width(), 5);
+            assert_eq!(band.get_pixel(4, 1), image.get_pixel(4, top + 1));
+        }
+    }
+
+    #[test]
+    fn mutable_bands_write_through() {
+        let mut image = RgbImage::new(2, 3);
+        for (top, mut band) in image.equal_bands_mut(3) {
+            band.put_pixel(1, 0, Rgb([top as u8; 3]));
+        }
+        assert_eq!(image.get_pixel(1, 2), &Rgb([2; 3]));
+    }
+
+    #[test]
+    fn borrowed_samples_split_into_bands() {
+        let samples: Vec<u8> = (0..18).collect();
+        let image: ImageBuffer<Rgb<u8>, &[u8]> = ImageBuffer::from_raw(2, 3, &samples[..]).unwrap();
+        let bands = image.equal_bands(2);
+        assert_eq!(bands.len(), 2);
+        assert_eq!(bands[0].1.height(), 2);
+        assert_eq!(bands[1].0, 2);
+        assert_eq!(bands[1].1.get_pixel(1, 0), &Rgb([15, 16, 17]));
+    }
+
+    #[test]
+    fn fewer_rows_than_parts() {
+        let image = RgbImage::new(3, 2);
+        assert_eq!(image.equal_bands(5).len(), 2);
+        assert!(RgbImage::new(3, 0).equal_bands(2).is_empty());
+    }
+
+    #[test]
+    fn pixel_bands_split_rows() {
+        let pixels: Vec<u32> = (0..12).collect();
+        let bands = pixel_bands(&pixels, 4, 2);
+        assert_eq!(bands, vec![&pixels[..8], &pixels[8..]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = RgbImage::new(1, 1).equal_bands(0);
+    }
+}
@@ -0,0 +1,315 @@
+use std::ffi::OsStr;
+use std::fmt;
+use std::ops::Range;
+
+use crate::min_max::{greedy_part_count, min_max_ranges};
+use crate::{EqualParts, EqualPartsIter};
+
+/// A conservative default for the total size of a command line, in bytes.
+///
+/// This matches the default buffer size used by GNU `xargs`, and is well below the
+/// `ARG_MAX` of any common platform.
+pub const DEFAULT_ARG_LIMIT: usize = 128 * 1024;
+
+/// Splits command-line arguments into the fewest balanced batches that fit within a
+/// command-line size limit.
+///
+/// Operating systems cap the total size of the arguments passed to a new process
+/// (`ARG_MAX`). When invoking a command over many paths, `xargs`-style tools pack the
+/// arguments into as few invocations as possible, but the last invocation is often
+/// nearly empty. `ArgBatches` instead finds the fewest batches that fit and then
+/// balances their byte sizes, so parallel invocations finish at about the same time.
+///
+/// Each argument costs its length in bytes plus a per-argument overhead, which by
+/// default accounts for the NUL terminator and the `argv` pointer. A fixed overhead
+/// can be reserved for the command name, leading options, and the environment.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::batch::ArgBatches;
+///
+/// let args = ["aaaaaa", "bbbb", "ccccc", "ddddd", "e"];
+/// let batches = ArgBatches::new(10)
+///     .per_arg_overhead(0)
+///     .split(&args)
+///     .unwrap();
+///
+/// // Greedy packing would give ["aaaaaa", "bbbb"], ["ccccc", "ddddd"], ["e"].
+/// assert_eq!(batches, vec![&args[..1], &args[1..3], &args[3..]]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArgBatches {
+    limit: usize,
+    fixed_overhead: usize,
+    per_arg_overhead: usize,
+}
+
+impl Default for ArgBatches {
+    fn default() -> Self {
+        ArgBatches::new(DEFAULT_ARG_LIMIT)
+    }
+}
+
+impl ArgBatches {
+    /// Creates a batcher whose batches never exceed `limit` bytes in total.
+    pub fn new(limit: usize) -> Self {
+        ArgBatches {
+            limit,
+            fixed_overhead: 0,
+            per_arg_overhead: 1 + size_of::<*const u8>(),
+        }
+    }
+
+    /// Reserves `bytes` of every batch for the command itself, such as the program
+    /// name, fixed options, and environment.
+    pub fn fixed_overhead(mut self, bytes: usize) -> Self {
+        self.fixed_overhead = bytes;
+        self
+    }
+
+    /// Sets the number of bytes each argument costs on top of its own length.
+    ///
+    /// Defaults to one byte for the NUL terminator plus the size of a pointer.
+    pub fn per_arg_overhead(mut self, bytes: usize) -> Self {
+        self.per_arg_overhead = bytes;
+        self
+    }
+
+    /// Splits `args` into the fewest contiguous batches that fit within the limit,
+    /// with byte sizes as balanced as possible.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`ArgTooLong`] error if any single argument does not fit in a batch
+    /// on its own.
+    pub fn split<'a, S: AsRef<OsStr>>(&self, args: &'a [S]) -> Result<Vec<&'a [S]>, ArgTooLong> {
+        let budget = self.limit.saturating_sub(self.fixed_overhead);
+        let costs: Vec<usize> = args
+            .iter()
+            .map(|arg| arg.as_ref().as_encoded_bytes().len() + self.per_arg_overhead)
+            .collect();
+
+        if let Some((index, &size)) = costs.iter().enumerate().find(|&(_, &cost)| cost > budget) {
+            return Err(ArgTooLong {
+                index,
+                size,
+                budget,
+            });
+        }
+
+        Ok(balanced_partition(&costs, budget)
+            .into_iter()
+            .map(|range| &args[range])
+            .collect())
+    }
+}
+
+/// Error returned by [`ArgBatches::split`] when an argument is too large to fit in any
+/// batch.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArgTooLong {
+    /// The index of the offending argument.
+    pub index: usize,
+    /// The size of the argument in bytes, including the per-argument overhead.
+    pub size: usize,
+    /// The number of bytes available for arguments in each batch.
+    pub budget: usize,
+}
+
+impl fmt::Display for ArgTooLong {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "argument {} needs {} bytes but only {} are available per batch",
+            self.index, self.size, self.budget
+        )
+    }
+}
+
+impl std::error::Error for ArgTooLong {}
+
+/// The maximum number of bind parameters in a single PostgreSQL statement.
+pub const POSTGRES_MAX_BIND_PARAMS: usize = 65535;
+
+/// Splits rows into the fewest balanced batches that keep each statement under a bind
+/// parameter limit.
+///
+/// Databases cap the number of bind parameters in a single statement (65535 for
+/// PostgreSQL, 32766 for modern SQLite). A multi-row `INSERT` binds `params_per_row`
+/// parameters for every row, so the rows must be sent in batches. Rather than filling
+/// batches to the limit and leaving a small remainder, this finds the fewest batches
+/// that fit and splits the rows evenly between them, with larger batches first.
+///
+/// If `params_per_row` is 0, every row fits in a single batch.
+///
+/// # Errors
+///
+/// Returns a [`RowTooWide`] error if a single row needs more parameters than
+/// `max_params`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::batch::{bind_param_batches, POSTGRES_MAX_BIND_PARAMS};
+///
+/// let rows: Vec<u32> = (0..10_000).collect();
+/// // 8 columns per row allows at most 8191 rows per statement.
+/// let batches: Vec<&[u32]> = bind_param_batches(&rows, 8, POSTGRES_MAX_BIND_PARAMS)
+///     .unwrap()
+///     .collect();
+///
+/// assert_eq!(batches.len(), 2);
+/// assert_eq!(batches[0].len(), 5000);
+/// assert_eq!(batches[1].len(), 5000);
+/// ```
+pub fn bind_param_batches<T>(
+    rows: &[T],
+    params_per_row: usize,
+    max_params: usize,
+) -> Result<EqualPartsIter<'_, T>, RowTooWide> {
+    let num_batches = match max_params.checked_div(params_per_row) {
+        Some(0) => {
+            return Err(RowTooWide {
+                params_per_row,
+                max_params,
+            });
+        }
+        Some(max_rows) => rows.len().div_ceil(max_rows).max(1),
+        None => 1,
+    };
+    Ok(rows.equal_parts(num_batches))
+}
+
+/// Error returned by [`bind_param_batches`] when a single row needs more bind
+/// parameters than a statement allows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowTooWide {
+    /// The number of bind parameters each row needs.
+    pub params_per_row: usize,
+    /// The maximum number of bind parameters per statement.
+    pub max_params: usize,
+}
+
+impl fmt::Display for RowTooWide {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "each row needs {} bind parameters but a statement allows only {}",
+            self.params_per_row, self.max_params
+        )
+    }
+}
+
+impl std::error::Error for RowTooWide {}
+
+/// Splits `costs` into the fewest contiguous parts whose totals are at most `cap`,
+/// minimizing the largest part total.
+///
+/// Every cost must be at most `cap`.
+fn balanced_partition(costs: &[usize], cap: usize) -> Vec<Range<usize>> {
+    min_max_ranges(costs, greedy_part_count(costs, cap as u128))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ArgBatches, ArgTooLong, RowTooWide, balanced_partition, bind_param_batches};
+
+    #[test]
+    fn partition_balances_bytes() {
+        // Greedy packing under 10 gives [6, 4] [5, 5] [1]; balancing evens it out.
+        let ranges = balanced_partition(&[6, 4, 5, 5, 1], 10);
+        assert_eq!(ranges, vec![0..1, 1..3, 3..5]);
+    }
+
+    #[test]
+    fn partition_single_part() {
+        assert_eq!(balanced_partition(&[1, 2, 3], 100), vec![0..3]);
+    }
+
+    #[test]
+    fn partition_zero_costs() {
+        assert_eq!(balanced_partition(&[0, 0], 0), vec![0..2]);
+    }
+
+    #[test]
+    fn partition_one_item_per_part() {
+        assert_eq!(balanced_partition(&[5, 5, 5], 5), vec![0..1, 1..2, 2..3]);
+    }
+
+    #[test]
+    fn batches_respect_limit() {
+        let args: Vec<String> = (0..100).map(|i| format!("file-{i}.txt")).collect();
+        let batcher = ArgBatches::new(500).fixed_overhead(50);
+        let batches = batcher.split(&args).unwrap();
+
+        assert_eq!(batches.concat(), args);
+        for batch in &batches {
+            let size: usize = batch
+                .iter()
+                .map(|arg| arg.len() + 1 + size_of::<usize>())
+                .sum();
+            assert!(size <= 450);
+        }
+    }
+
+    #[test]
+    fn empty_args() {
+        let args: [&str; 0] = [];
+        assert!(ArgBatches::default().split(&args).unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_oversized_argument() {
+        let args = ["short", "much-too-long"];
+        let err = ArgBatches::new(10)
+            .per_arg_overhead(0)
+            .split(&args)
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ArgTooLong {
+                index: 1,
+                size: 13,
+                budget: 10
+            }
+        );
+    }
+
+    #[test]
+    fn bind_batches_stay_under_limit() {
+        let rows: Vec<i32> = (0..1001).collect();
+        let batches: Vec<&[i32]> = bind_param_batches(&rows, 3, 300).unwrap().collect();
+        assert_eq!(batches.len(), 11);
+        assert!(batches.iter().all(|batch| batch.len() * 3 <= 300));
+        assert!(batches.iter().all(|batch| batch.len() >= 90));
+    }
+
+    #[test]
+    fn bind_batches_exact_fit() {
+        let rows: Vec<i32> = (0..200).collect();
+        let batches: Vec<&[i32]> = bind_param_batches(&rows, 5, 500).unwrap().collect();
+        assert_eq!(batches, vec![&rows[..100], &rows[100..]]);
+    }
+
+    #[test]
+    fn bind_batches_without_params() {
+        let rows = [1, 2, 3];
+        let batches: Vec<&[i32]> = bind_param_batches(&rows, 0, 10).unwrap().collect();
+        assert_eq!(batches, vec![&rows[..]]);
+        let empty: [i32; 0] = [];
+        assert_eq!(bind_param_batches(&empty, 4, 10).unwrap().next(), None);
+    }
+
+    #[test]
+    fn bind_batches_reject_wide_rows() {
+        let rows = [1, 2, 3];
+        assert_eq!(
+            bind_param_batches(&rows, 11, 10).unwrap_err(),
+            RowTooWide {
+                params_per_row: 11,
+                max_params: 10
+            }
+        );
+    }
+}
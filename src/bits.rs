@@ -0,0 +1,110 @@
+use bitvec::order::BitOrder;
+use bitvec::slice::BitSlice;
+use bitvec::store::BitStore;
+use bitvec::vec::BitVec;
+
+use crate::EqualParts;
+use crate::full_part_count;
+
+/// Iterator that yields approximately equal parts of a bit slice.
+///
+/// This iterator is created by calling [`equal_parts`](EqualParts::equal_parts) on a
+/// `&BitSlice` or `&BitVec`. Parts are measured in bits, so a part can start or end in
+/// the middle of a storage element. Each part is a `&BitSlice` borrowing the original.
+///
+/// # Examples
+///
+/// ```
+/// use bitvec::prelude::*;
+/// use equal_parts::EqualParts;
+///
+/// let bits = bitvec![u8, Lsb0; 1, 1, 0, 1, 0, 0, 1, 1, 1, 0];
+/// let counts: Vec<usize> = bits.equal_parts(3).map(|part| part.count_ones()).collect();
+/// assert_eq!(counts, vec![3, 1, 2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct BitEqualPartsIter<'a, T: BitStore, O: BitOrder> {
+    bits: &'a BitSlice<T, O>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<'a, T: BitStore, O: BitOrder> Iterator for BitEqualPartsIter<'a, T, O> {
+    type Item = &'a BitSlice<T, O>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.bits.is_empty() {
+            None
+        } else {
+            let split_point = self.part_size - (self.full_parts_left.min(1) ^ 1);
+            self.full_parts_left -= self.full_parts_left.min(1);
+
+            let (chunk, rest) = self.bits.split_at(split_point);
+            self.bits = rest;
+            Some(chunk)
+        }
+    }
+}
+
+impl<'a, T: BitStore, O: BitOrder> EqualParts for &'a BitSlice<T, O> {
+    type Item = &'a BitSlice<T, O>;
+    type Iter = BitEqualPartsIter<'a, T, O>;
+
+    fn equal_parts(self, num_parts: usize) -> Self::Iter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+        BitEqualPartsIter {
+            bits: self,
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+impl<'a, T: BitStore, O: BitOrder> EqualParts for &'a BitVec<T, O> {
+    type Item = &'a BitSlice<T, O>;
+    type Iter = BitEqualPartsIter<'a, T, O>;
+
+    fn equal_parts(self, num_parts: usize) -> Self::Iter {
+        self.as_bitslice().equal_parts(num_parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bitvec::prelude::*;
+
+    use crate::EqualParts;
+
+    #[test]
+    fn splits_inside_elements() {
+        let bits = bitvec![u64, Msb0; 1; 130];
+        let lens: Vec<usize> = bits.equal_parts(4).map(BitSlice::len).collect();
+        assert_eq!(lens, vec![33, 33, 32, 32]);
+    }
+
+    #[test]
+    fn parts_cover_every_bit() {
+        let bits: BitVec<u8> = (0..29).map(|i| i % 3 == 0).collect();
+        let mut joined = BitVec::<u8>::new();
+        for part in bits.as_bitslice().equal_parts(5) {
+            joined.extend_from_bitslice(part);
+        }
+        assert_eq!(joined, bits);
+    }
+
+    #[test]
+    fn fewer_bits_than_parts() {
+        let bits = bitvec![1, 0];
+        assert_eq!(bits.equal_parts(8).count(), 2);
+        assert!(BitVec::<u8>::new().equal_parts(2).next().is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = bitvec![1].equal_parts(0);
+    }
+}
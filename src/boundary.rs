@@ -0,0 +1,185 @@
+use crate::split_count;
+
+/// A trait for splitting slices into approximately equal parts whose boundaries may
+/// only fall where the caller allows.
+///
+/// Many inputs have places that must not be cut, such as the middle of a transaction,
+/// a message, or a sentence. Rather than knowing about every format, this trait asks
+/// the caller whether a split is allowed between two neighboring elements.
+pub trait SplitWhere<'a, T> {
+    /// Splits the slice into up to `num_parts` contiguous parts, moving each boundary
+    /// to the nearest position where `can_split_between` allows a split.
+    ///
+    /// The boundaries start at the offsets [`equal_parts`](crate::EqualParts::equal_parts)
+    /// would use. `can_split_between(prev, next)` is then asked about the positions
+    /// around each of them, and the boundary moves to the closest position where it
+    /// returns `true`. When two allowed positions are equally close, the earlier one
+    /// is used. A boundary that cannot move anywhere after the previous one is dropped,
+    /// so fewer than `num_parts` parts are returned when there are few allowed
+    /// positions, but no part is ever empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::boundary::SplitWhere;
+    ///
+    /// // Log lines tagged with a transaction id; a transaction must stay in one part.
+    /// let lines = [(1, "begin"), (1, "commit"), (2, "begin"), (2, "write"),
+    ///              (2, "commit"), (3, "begin"), (3, "commit"), (4, "begin")];
+    /// let parts = lines.equal_parts_split_where(2, |prev, next| prev.0 != next.0);
+    ///
+    /// assert_eq!(parts, vec![&lines[..5], &lines[5..]]);
+    /// ```
+    fn equal_parts_split_where<F>(self, num_parts: usize, can_split_between: F) -> Vec<&'a [T]>
+    where
+        F: FnMut(&T, &T) -> bool;
+}
+
+impl<'a, T> SplitWhere<'a, T> for &'a [T] {
+    fn equal_parts_split_where<F>(self, num_parts: usize, mut can_split_between: F) -> Vec<&'a [T]>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        let boundaries = snapped_boundaries(self.len(), num_parts, |position| {
+            can_split_between(&self[position - 1], &self[position])
+        });
+        split_at_boundaries(self, &boundaries)
+    }
+}
+
+impl<'a, T> SplitWhere<'a, T> for &'a Vec<T> {
+    fn equal_parts_split_where<F>(self, num_parts: usize, can_split_between: F) -> Vec<&'a [T]>
+    where
+        F: FnMut(&T, &T) -> bool,
+    {
+        self.as_slice()
+            .equal_parts_split_where(num_parts, can_split_between)
+    }
+}
+
+/// Returns the inner boundaries of an equal split of `len` elements into `num_parts`
+/// parts, each moved to the nearest position in `1..len` for which `allowed` returns
+/// `true`.
+///
+/// Ties go to the earlier position. The boundaries are strictly increasing, and a
+/// boundary with no allowed position after the previous one is dropped.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+pub(crate) fn snapped_boundaries(
+    len: usize,
+    num_parts: usize,
+    mut allowed: impl FnMut(usize) -> bool,
+) -> Vec<usize> {
+    let mut boundaries = Vec::with_capacity(num_parts.min(len).saturating_sub(1));
+    let mut previous = 0;
+    for (ideal, _) in split_count(len, num_parts).with_offsets().skip(1) {
+        let ideal = ideal.max(previous + 1);
+        let mut distance = 0;
+        loop {
+            let before = ideal
+                .checked_sub(distance)
+                .filter(|&position| position > previous && position < len);
+            let after = Some(ideal + distance).filter(|&position| position < len);
+            if before.is_none() && after.is_none() {
+                return boundaries;
+            }
+            if let Some(position) = before.filter(|&position| allowed(position)) {
+                boundaries.push(position);
+                break;
+            }
+            if let Some(position) = after.filter(|&position| distance > 0 && allowed(position)) {
+                boundaries.push(position);
+                break;
+            }
+            distance += 1;
+        }
+        previous = *boundaries.last().expect("a boundary was just pushed");
+    }
+    boundaries
+}
+
+/// Splits `data` at the given strictly increasing inner boundaries.
+pub(crate) fn split_at_boundaries<'a, T>(data: &'a [T], boundaries: &[usize]) -> Vec<&'a [T]> {
+    if data.is_empty() {
+        return Vec::new();
+    }
+    let mut parts = Vec::with_capacity(boundaries.len() + 1);
+    let mut start = 0;
+    for &boundary in boundaries {
+        parts.push(&data[start..boundary]);
+        start = boundary;
+    }
+    parts.push(&data[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SplitWhere;
+    use crate::EqualParts;
+
+    #[test]
+    fn always_allowed_matches_equal_parts() {
+        let data: Vec<u32> = (0..31).collect();
+        for num_parts in 1..40 {
+            let expected: Vec<&[u32]> = data.equal_parts(num_parts).collect();
+            assert_eq!(
+                data.equal_parts_split_where(num_parts, |_, _| true),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn boundaries_snap_to_the_nearest_allowed_position() {
+        let data: Vec<u32> = (0..20).collect();
+        // Splits are only allowed before multiples of 7, that is at 7 and 14.
+        let parts = data.equal_parts_split_where(2, |_, next| next % 7 == 0);
+        assert_eq!(parts, vec![&data[..7], &data[7..]]);
+        let parts = data.equal_parts_split_where(3, |_, next| next % 7 == 0);
+        assert_eq!(parts, vec![&data[..7], &data[7..14], &data[14..]]);
+    }
+
+    #[test]
+    fn ties_go_to_the_earlier_position() {
+        let data: Vec<u32> = (0..10).collect();
+        let parts = data.equal_parts_split_where(2, |_, next| *next == 4 || *next == 6);
+        assert_eq!(parts, vec![&data[..4], &data[4..]]);
+    }
+
+    #[test]
+    fn few_allowed_positions_give_fewer_parts() {
+        let data: Vec<u32> = (0..12).collect();
+        let parts = data.equal_parts_split_where(4, |_, next| *next == 2);
+        assert_eq!(parts, vec![&data[..2], &data[2..]]);
+        let parts = data.equal_parts_split_where(4, |_, _| false);
+        assert_eq!(parts, vec![&data[..]]);
+        assert!(
+            Vec::<u8>::new()
+                .equal_parts_split_where(3, |_, _| true)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn parts_are_never_empty() {
+        let data: Vec<u32> = (0..50).collect();
+        for num_parts in 1..60 {
+            let parts = data.equal_parts_split_where(num_parts, |prev, _| prev % 5 == 3);
+            assert!(parts.iter().all(|part| !part.is_empty()));
+            assert_eq!(parts.concat(), data);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = [1, 2].equal_parts_split_where(0, |_, _| true);
+    }
+}
@@ -0,0 +1,286 @@
+use crate::{EqualParts, EqualPartsIter};
+
+/// Returns the smallest number of parts `len` elements can be split into without any
+/// part exceeding `max_len` elements.
+///
+/// This is the part count [`BoundedEqualParts::equal_parts_max_len`] uses. It is at
+/// least 1, even when `len` is 0.
+///
+/// # Panics
+///
+/// Panics if `max_len` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::bounded::parts_for_max_len;
+///
+/// assert_eq!(parts_for_max_len(1000, 100), 10);
+/// assert_eq!(parts_for_max_len(1001, 100), 11);
+/// ```
+pub fn parts_for_max_len(len: usize, max_len: usize) -> usize {
+    assert!(max_len > 0, "Maximum part length must be greater than 0");
+
+    len.div_ceil(max_len).max(1)
+}
+
+/// Returns the number of parts, at most `num_parts`, that `len` elements can be split
+/// into without any part falling below `min_len` elements.
+///
+/// This is the part count [`BoundedEqualParts::equal_parts_min_len`] uses. It is at
+/// least 1, so a single part is used when there are fewer than `min_len` elements in
+/// total. A `min_len` of 0 imposes no limit.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::bounded::parts_for_min_len;
+///
+/// assert_eq!(parts_for_min_len(1000, 8, 64), 8);
+/// assert_eq!(parts_for_min_len(200, 8, 64), 3);
+/// assert_eq!(parts_for_min_len(10, 8, 64), 1);
+/// ```
+pub fn parts_for_min_len(len: usize, num_parts: usize, min_len: usize) -> usize {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    match len.checked_div(min_len) {
+        Some(most_parts) => num_parts.min(most_parts).max(1),
+        None => num_parts,
+    }
+}
+
+/// Returns the number of parts that makes the part length of `len` elements closest
+/// to `target_len`.
+///
+/// This is the part count [`BoundedEqualParts::balanced_chunks`] uses. It is `len /
+/// target_len` rounded to the nearest integer, and at least 1.
+///
+/// # Panics
+///
+/// Panics if `target_len` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::bounded::parts_for_target_len;
+///
+/// assert_eq!(parts_for_target_len(1003, 100), 10);
+/// assert_eq!(parts_for_target_len(1060, 100), 11);
+/// assert_eq!(parts_for_target_len(40, 100), 1);
+/// ```
+pub fn parts_for_target_len(len: usize, target_len: usize) -> usize {
+    assert!(target_len > 0, "Target part length must be greater than 0");
+
+    let rounded_up = len % target_len >= target_len.div_ceil(2);
+    (len / target_len + usize::from(rounded_up)).max(1)
+}
+
+/// A trait for splitting slices into approximately equal parts whose count is derived
+/// from limits on the part length.
+///
+/// [`equal_parts`](EqualParts::equal_parts) takes the number of parts and lets the
+/// length follow. The methods of this trait work the other way round, which suits
+/// limits like API batch sizes where the length is the constraint. The parts are
+/// always balanced like those of `equal_parts`, with larger parts first.
+pub trait BoundedEqualParts<'a, T> {
+    /// Splits the slice into the fewest approximately equal parts that have at most
+    /// `max_len` elements each.
+    ///
+    /// Unlike [`chunks`](slice::chunks), which fills every part to `max_len` and leaves
+    /// whatever is left for the last one, the elements are spread evenly over the
+    /// parts, so there is no tiny trailing part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::bounded::BoundedEqualParts;
+    ///
+    /// let data = [0u8; 21];
+    /// let lens: Vec<usize> = data.equal_parts_max_len(10).map(<[u8]>::len).collect();
+    /// assert_eq!(lens, vec![7, 7, 7]);
+    ///
+    /// // `chunks` would give 10, 10 and 1.
+    /// assert_eq!(data.chunks(10).last().unwrap().len(), 1);
+    /// ```
+    fn equal_parts_max_len(self, max_len: usize) -> EqualPartsIter<'a, T>;
+
+    /// Splits the slice into up to `num_parts` approximately equal parts, using fewer
+    /// parts when needed so that none has fewer than `min_len` elements.
+    ///
+    /// This is for work with a fixed cost per part, such as spawning a thread or
+    /// sending a request, where tiny parts cost more than they save. If the whole
+    /// slice is shorter than `min_len`, it is returned as a single part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::bounded::BoundedEqualParts;
+    ///
+    /// let data = [0u8; 100];
+    /// let lens: Vec<usize> = data.equal_parts_min_len(8, 30).map(<[u8]>::len).collect();
+    /// assert_eq!(lens, vec![34, 33, 33]);
+    /// ```
+    fn equal_parts_min_len(self, num_parts: usize, min_len: usize) -> EqualPartsIter<'a, T>;
+
+    /// Splits the slice into balanced chunks whose length is as close as possible to
+    /// `target_len`.
+    ///
+    /// This is the middle ground between [`chunks`](slice::chunks) and
+    /// [`equal_parts`](EqualParts::equal_parts): the chunk length drives the split,
+    /// but the remainder is spread over all the chunks instead of being left as a
+    /// short one at the end. Chunks can therefore be a little longer or shorter than
+    /// `target_len`, but never differ from each other by more than one element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_len` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::bounded::BoundedEqualParts;
+    ///
+    /// let data = vec![0u8; 1003];
+    /// let lens: Vec<usize> = data.balanced_chunks(100).map(<[u8]>::len).collect();
+    /// assert_eq!(lens, vec![101, 101, 101, 100, 100, 100, 100, 100, 100, 100]);
+    /// ```
+    fn balanced_chunks(self, target_len: usize) -> EqualPartsIter<'a, T>;
+}
+
+impl<'a, T> BoundedEqualParts<'a, T> for &'a [T] {
+    fn equal_parts_max_len(self, max_len: usize) -> EqualPartsIter<'a, T> {
+        self.equal_parts(parts_for_max_len(self.len(), max_len))
+    }
+
+    fn equal_parts_min_len(self, num_parts: usize, min_len: usize) -> EqualPartsIter<'a, T> {
+        self.equal_parts(parts_for_min_len(self.len(), num_parts, min_len))
+    }
+
+    fn balanced_chunks(self, target_len: usize) -> EqualPartsIter<'a, T> {
+        self.equal_parts(parts_for_target_len(self.len(), target_len))
+    }
+}
+
+impl<'a, T> BoundedEqualParts<'a, T> for &'a Vec<T> {
+    fn equal_parts_max_len(self, max_len: usize) -> EqualPartsIter<'a, T> {
+        self.as_slice().equal_parts_max_len(max_len)
+    }
+
+    fn equal_parts_min_len(self, num_parts: usize, min_len: usize) -> EqualPartsIter<'a, T> {
+        self.as_slice().equal_parts_min_len(num_parts, min_len)
+    }
+
+    fn balanced_chunks(self, target_len: usize) -> EqualPartsIter<'a, T> {
+        self.as_slice().balanced_chunks(target_len)
+    }
+}
+
+impl<'a, T, const N: usize> BoundedEqualParts<'a, T> for &'a [T; N] {
+    fn equal_parts_max_len(self, max_len: usize) -> EqualPartsIter<'a, T> {
+        self.as_slice().equal_parts_max_len(max_len)
+    }
+
+    fn equal_parts_min_len(self, num_parts: usize, min_len: usize) -> EqualPartsIter<'a, T> {
+        self.as_slice().equal_parts_min_len(num_parts, min_len)
+    }
+
+    fn balanced_chunks(self, target_len: usize) -> EqualPartsIter<'a, T> {
+        self.as_slice().balanced_chunks(target_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BoundedEqualParts, parts_for_max_len, parts_for_min_len, parts_for_target_len};
+
+    #[test]
+    fn max_len_is_respected_with_fewest_parts() {
+        let data: Vec<u32> = (0..200).collect();
+        for len in 0..data.len() {
+            for max_len in 1..40 {
+                let parts: Vec<&[u32]> = data[..len].equal_parts_max_len(max_len).collect();
+                assert!(parts.iter().all(|part| part.len() <= max_len));
+                assert_eq!(parts.len(), len.div_ceil(max_len));
+                assert_eq!(parts.concat(), &data[..len]);
+            }
+        }
+    }
+
+    #[test]
+    fn part_count_is_at_least_one() {
+        assert_eq!(parts_for_max_len(0, 5), 1);
+        assert_eq!(parts_for_max_len(5, 5), 1);
+        assert_eq!(parts_for_max_len(6, 5), 2);
+    }
+
+    #[test]
+    fn min_len_is_respected() {
+        let data: Vec<u32> = (0..200).collect();
+        for len in 0..data.len() {
+            for min_len in 1..30 {
+                let parts: Vec<&[u32]> = data[..len].equal_parts_min_len(8, min_len).collect();
+                assert!(parts.len() <= 8);
+                assert_eq!(parts.concat(), &data[..len]);
+                if parts.len() > 1 {
+                    assert!(parts.iter().all(|part| part.len() >= min_len));
+                }
+                if len >= 8 * min_len {
+                    assert_eq!(parts.len(), 8);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn zero_min_len_imposes_no_limit() {
+        assert_eq!(parts_for_min_len(5, 4, 0), 4);
+        assert_eq!(parts_for_min_len(0, 4, 3), 1);
+    }
+
+    #[test]
+    fn balanced_chunks_stay_near_target() {
+        let data: Vec<u32> = (0..500).collect();
+        for len in 1..data.len() {
+            for target_len in 1..60 {
+                let lens: Vec<usize> = data[..len]
+                    .balanced_chunks(target_len)
+                    .map(<[u32]>::len)
+                    .collect();
+                assert_eq!(lens.iter().sum::<usize>(), len);
+                assert!(lens.iter().max().unwrap() - lens.iter().min().unwrap() <= 1);
+                if len >= target_len {
+                    // Rounding to the nearest count keeps chunks within a factor of
+                    // two of the target, unlike the tail of `chunks`.
+                    assert!(lens.iter().all(|&l| l * 3 >= target_len * 2 - 1));
+                    assert!(lens.iter().all(|&l| l < target_len * 2));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn target_count_rounds_to_nearest() {
+        assert_eq!(parts_for_target_len(0, 10), 1);
+        assert_eq!(parts_for_target_len(14, 10), 1);
+        assert_eq!(parts_for_target_len(15, 10), 2);
+        assert_eq!(parts_for_target_len(usize::MAX, 1), usize::MAX);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_max_len() {
+        let _ = [1, 2].equal_parts_max_len(0);
+    }
+}
@@ -0,0 +1,279 @@
+use std::time::Duration;
+
+use crate::parallel::timed;
+use crate::ratio::ratio_sizes;
+
+/// Resolution of the integer ratios that speeds are converted to before sizing parts.
+const RATIO_SCALE: f64 = (1u32 << 20) as f64;
+
+/// Relative throughput of a set of workers, used to give faster workers larger parts.
+///
+/// On machines that mix fast and slow cores, or pools of unequal hosts, equal parts
+/// leave the fast workers idle while the slow ones finish. A `Calibration` holds one
+/// speed per worker, either recorded from an earlier run with
+/// [`from_speeds`](Calibration::from_speeds) or
+/// [`from_probe_times`](Calibration::from_probe_times), or measured on the workers
+/// themselves with [`measure_with`](Calibration::measure_with). It then splits data
+/// into one contiguous part per worker, with sizes proportional to the speeds.
+///
+/// Only the ratios between the speeds matter, so any unit can be used.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::calibrate::Calibration;
+///
+/// // Two performance cores and two efficiency cores at roughly half the speed.
+/// let calibration = Calibration::from_speeds(&[2.0, 2.0, 1.0, 1.0]);
+///
+/// let jobs: Vec<u32> = (0..30).collect();
+/// let parts = calibration.split(&jobs);
+/// assert_eq!(parts.iter().map(|part| part.len()).collect::<Vec<_>>(), vec![10, 10, 5, 5]);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Calibration {
+    speeds: Vec<f64>,
+}
+
+impl Calibration {
+    /// Creates a calibration from recorded speeds, one per worker.
+    ///
+    /// A speed of 0 gives that worker an empty part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `speeds` is empty, if any speed is negative or not finite, or if all
+    /// of them are 0.
+    pub fn from_speeds(speeds: &[f64]) -> Self {
+        assert!(
+            speeds
+                .iter()
+                .all(|speed| speed.is_finite() && *speed >= 0.0),
+            "Speeds must be finite and not negative"
+        );
+        assert!(
+            speeds.iter().any(|&speed| speed > 0.0),
+            "Speeds must contain a value greater than 0"
+        );
+        Calibration {
+            speeds: speeds.to_vec(),
+        }
+    }
+
+    /// Creates a calibration from the time each worker took to process the same probe
+    /// batch of `probe_len` elements.
+    ///
+    /// A worker that took twice as long gets half as much work. Zero durations are
+    /// treated as one nanosecond.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `probe_times` is empty or `probe_len` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use equal_parts::calibrate::Calibration;
+    ///
+    /// let times = [Duration::from_millis(40), Duration::from_millis(120)];
+    /// let calibration = Calibration::from_probe_times(100, &times);
+    /// assert_eq!(calibration.sizes(8), vec![6, 2]);
+    /// ```
+    pub fn from_probe_times(probe_len: usize, probe_times: &[Duration]) -> Self {
+        assert!(probe_len > 0, "Probe batch must not be empty");
+        let speeds: Vec<f64> = probe_times
+            .iter()
+            .map(|time| probe_len as f64 / time.max(&Duration::from_nanos(1)).as_secs_f64())
+            .collect();
+        Calibration::from_speeds(&speeds)
+    }
+
+    /// Measures the speed of `num_workers` workers by timing a pass of `f` over the
+    /// whole `probe` batch on each of them.
+    ///
+    /// Speeds only mean something for the threads that do the real work, such as the
+    /// threads of a pool pinned to performance and efficiency cores. So instead of
+    /// starting threads of its own, this calls `on_worker(i, job)` for each worker `i`
+    /// in turn, and `on_worker` must run `job` on worker `i` and return what it
+    /// returns. The job times itself, so only the time spent on the worker counts,
+    /// not the time it took to get there. The results of `f` are discarded.
+    ///
+    /// On targets without a clock, such as `wasm32-unknown-unknown`, the job cannot time
+    /// itself and returns [`Duration::ZERO`], so every worker is treated as equally
+    /// fast.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_workers` is 0 or `probe` is empty.
+    ///
+    /// # Examples
+    ///
+    /// Workers that are scoped threads, one per call:
+    ///
+    /// ```
+    /// use std::thread;
+    ///
+    /// use equal_parts::calibrate::Calibration;
+    ///
+    /// let probe: Vec<u64> = (0..1000).collect();
+    /// let calibration = Calibration::measure_with(&probe, 4, |x| x.pow(3), |_worker, job| {
+    ///     thread::scope(|scope| scope.spawn(job).join().unwrap())
+    /// });
+    ///
+    /// assert_eq!(calibration.num_workers(), 4);
+    /// assert_eq!(calibration.sizes(1000).iter().sum::<usize>(), 1000);
+    /// ```
+    ///
+    /// With one thread pool per core type, the runner would instead be
+    /// `|worker, job| pools[worker].install(job)`.
+    pub fn measure_with<T, R, F, W>(probe: &[T], num_workers: usize, f: F, mut on_worker: W) -> Self
+    where
+        T: Sync,
+        F: Fn(&T) -> R + Sync,
+        W: FnMut(usize, &(dyn Fn() -> Duration + Sync)) -> Duration,
+    {
+        assert!(num_workers > 0, "Number of parts must be greater than 0");
+        assert!(!probe.is_empty(), "Probe batch must not be empty");
+
+        let job = || {
+            let ((), elapsed) = timed(|| {
+                for item in probe {
+                    std::hint::black_box(f(item));
+                }
+            });
+            elapsed.unwrap_or(Duration::ZERO)
+        };
+        let times: Vec<Duration> = (0..num_workers)
+            .map(|worker| on_worker(worker, &job))
+            .collect();
+        Calibration::from_probe_times(probe.len(), &times)
+    }
+
+    /// Returns the number of workers, which is also the number of parts.
+    pub fn num_workers(&self) -> usize {
+        self.speeds.len()
+    }
+
+    /// Returns the speed of each worker.
+    pub fn speeds(&self) -> &[f64] {
+        &self.speeds
+    }
+
+    /// Returns the part sizes for `len` elements, one per worker.
+    ///
+    /// The sizes add up to `len` and are proportional to the speeds, following
+    /// [`ratio_sizes`] after scaling the speeds to integers.
+    pub fn sizes(&self, len: usize) -> Vec<usize> {
+        let fastest = self.speeds.iter().copied().fold(0.0, f64::max);
+        let ratios: Vec<usize> = self
+            .speeds
+            .iter()
+            .map(|speed| (speed / fastest * RATIO_SCALE).round() as usize)
+            .collect();
+        ratio_sizes(len, &ratios)
+    }
+
+    /// Splits a slice into contiguous parts, one per worker, sized by
+    /// [`sizes`](Calibration::sizes).
+    pub fn split<'a, T>(&self, data: &'a [T]) -> Vec<&'a [T]> {
+        let mut rest = data;
+        self.sizes(data.len())
+            .into_iter()
+            .map(|size| {
+                let (part, tail) = rest.split_at(size);
+                rest = tail;
+                part
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Calibration;
+    use std::time::Duration;
+
+    #[test]
+    fn sizes_follow_speeds() {
+        let calibration = Calibration::from_speeds(&[3.0, 1.5, 0.0, 1.5]);
+        assert_eq!(calibration.sizes(12), vec![6, 3, 0, 3]);
+        assert_eq!(calibration.sizes(0), vec![0, 0, 0, 0]);
+        for len in 0..100 {
+            assert_eq!(calibration.sizes(len).iter().sum::<usize>(), len);
+        }
+    }
+
+    #[test]
+    fn split_covers_the_slice() {
+        let data: Vec<u32> = (0..17).collect();
+        let parts = Calibration::from_speeds(&[1.0, 2.0, 5.0]).split(&data);
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts.concat(), data);
+    }
+
+    #[test]
+    fn slower_probe_gets_less_work() {
+        let times = [
+            Duration::from_millis(10),
+            Duration::from_millis(5),
+            Duration::from_millis(30),
+        ];
+        assert_eq!(
+            Calibration::from_probe_times(50, &times).sizes(90),
+            vec![27, 54, 9]
+        );
+
+        let instant = [Duration::from_millis(10), Duration::ZERO];
+        assert_eq!(
+            Calibration::from_probe_times(50, &instant).sizes(90),
+            vec![0, 90]
+        );
+    }
+
+    #[test]
+    fn measure_runs_the_probe_on_every_worker() {
+        let probe: Vec<u64> = (0..200).collect();
+        let mut workers = Vec::new();
+        let calibration = Calibration::measure_with(
+            &probe,
+            3,
+            |x| x * 2,
+            |worker, job| {
+                workers.push(worker);
+                job()
+            },
+        );
+        assert_eq!(workers, vec![0, 1, 2]);
+        assert_eq!(calibration.speeds().len(), 3);
+        assert!(calibration.speeds().iter().all(|&speed| speed > 0.0));
+    }
+
+    #[test]
+    fn measure_uses_the_worker_times() {
+        // Worker 1 reports twice the time of worker 0, as a slower core would.
+        let probe = [1u8; 10];
+        let calibration = Calibration::measure_with(
+            &probe,
+            2,
+            |&x| x,
+            |worker, job| {
+                job();
+                Duration::from_millis(10 * (worker as u64 + 1))
+            },
+        );
+        assert_eq!(calibration.sizes(9), vec![6, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_all_zero_speeds() {
+        let _ = Calibration::from_speeds(&[0.0, 0.0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_no_workers() {
+        let _ = Calibration::from_speeds(&[]);
+    }
+}
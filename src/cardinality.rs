@@ -0,0 +1,120 @@
+use std::collections::HashSet;
+use std::hash::Hash;
+
+/// Splits a slice into approximately equal contiguous parts that each contain at most
+/// `max_keys` distinct keys.
+///
+/// This is useful for per-key aggregation where each part keeps one accumulator per
+/// key, and the number of accumulators alive at once needs a bound. Parts are filled
+/// towards the same sizes as [`equal_parts`](crate::EqualParts::equal_parts), but a
+/// part is cut early whenever the next element would bring in key number
+/// `max_keys + 1`. The remaining elements are then rebalanced over the remaining parts.
+///
+/// Because of those early cuts, more than `num_parts` parts are returned when the keys
+/// change too often to fit in `num_parts` parts. As with `equal_parts`, no part is
+/// empty.
+///
+/// # Panics
+///
+/// Panics if `num_parts` or `max_keys` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::cardinality::key_capped_parts;
+///
+/// let sales = [("tea", 3), ("tea", 1), ("jam", 2), ("egg", 5), ("egg", 1), ("tea", 2)];
+///
+/// // Two keys per part fit within the usual equal split.
+/// let parts = key_capped_parts(&sales, 2, 2, |sale| sale.0);
+/// assert_eq!(parts, vec![&sales[..3], &sales[3..]]);
+///
+/// // With one key per part, parts are cut at every change of key.
+/// let parts = key_capped_parts(&sales, 2, 1, |sale| sale.0);
+/// assert_eq!(parts, vec![&sales[..2], &sales[2..3], &sales[3..5], &sales[5..]]);
+/// ```
+pub fn key_capped_parts<T, K, F>(data: &[T], num_parts: usize, max_keys: usize, key: F) -> Vec<&[T]>
+where
+    K: Hash + Eq,
+    F: Fn(&T) -> K,
+{
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+    assert!(
+        max_keys > 0,
+        "Maximum number of keys must be greater than 0"
+    );
+
+    let mut parts = Vec::with_capacity(num_parts.min(data.len()));
+    let mut parts_left = num_parts;
+    let mut rest = data;
+    let mut seen = HashSet::new();
+
+    while !rest.is_empty() {
+        let target = rest.len().div_ceil(parts_left.max(1));
+        seen.clear();
+        let mut end = 0;
+        while end < target {
+            let item_key = key(&rest[end]);
+            if !seen.contains(&item_key) {
+                if seen.len() == max_keys {
+                    break;
+                }
+                seen.insert(item_key);
+            }
+            end += 1;
+        }
+
+        let (part, tail) = rest.split_at(end);
+        parts.push(part);
+        rest = tail;
+        parts_left = parts_left.saturating_sub(1);
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::key_capped_parts;
+    use crate::EqualParts;
+    use std::collections::HashSet;
+
+    #[test]
+    fn matches_equal_parts_when_cap_not_hit() {
+        let data: Vec<u32> = (0..23).map(|i| i / 10).collect();
+        for num_parts in 1..10 {
+            let capped = key_capped_parts(&data, num_parts, 3, |&x| x);
+            let plain: Vec<&[u32]> = data.equal_parts(num_parts).collect();
+            assert_eq!(capped, plain);
+        }
+    }
+
+    #[test]
+    fn every_part_respects_cap() {
+        let data: Vec<u32> = (0..200).map(|i| (i * 7919) % 13).collect();
+        let parts = key_capped_parts(&data, 4, 5, |&x| x);
+        assert!(parts.len() > 4);
+        for part in &parts {
+            assert!(!part.is_empty());
+            assert!(part.iter().collect::<HashSet<_>>().len() <= 5);
+        }
+        assert_eq!(parts.concat(), data);
+    }
+
+    #[test]
+    fn rebalances_after_early_cut() {
+        let data = [1, 2, 2, 2, 2, 2, 2, 2];
+        let parts = key_capped_parts(&data, 2, 1, |&x| x);
+        assert_eq!(parts, vec![&data[..1], &data[1..]]);
+    }
+
+    #[test]
+    fn empty_data() {
+        assert!(key_capped_parts(&[] as &[u8], 3, 1, |&x| x).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_keys() {
+        let _ = key_capped_parts(&[1], 1, 0, |&x| x);
+    }
+}
@@ -0,0 +1,262 @@
+use std::fmt;
+
+use crate::{EqualParts, EqualPartsError, EqualPartsIter};
+
+/// A trait for splitting slices into approximately equal parts with stricter checks
+/// than [`EqualParts`].
+///
+/// [`equal_parts`](EqualParts::equal_parts) quietly adapts to its input, for example
+/// by yielding fewer parts than requested when there are too few elements. The methods
+/// of this trait check their preconditions up front instead and return an error when
+/// they do not hold, for callers that treat such inputs as a configuration bug. This
+/// includes zero parts, which is reported as [`EqualPartsError::ZeroParts`] rather than
+/// a panic.
+pub trait CheckedEqualParts<'a, T> {
+    /// Splits the slice into exactly `num_parts` non-empty parts, or returns an error
+    /// if there are fewer elements than parts.
+    ///
+    /// On success, the parts are the same as those of
+    /// [`equal_parts`](EqualParts::equal_parts).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EqualPartsError::TooFewElements`] if the slice has fewer than
+    /// `num_parts` elements, and [`EqualPartsError::ZeroParts`] if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::EqualPartsError;
+    /// use equal_parts::checked::CheckedEqualParts;
+    ///
+    /// let data = [1, 2, 3, 4, 5];
+    /// assert_eq!(data.equal_parts_nonempty(2).unwrap().count(), 2);
+    ///
+    /// let Err(EqualPartsError::TooFewElements(err)) = data.equal_parts_nonempty(8) else {
+    ///     panic!("expected too few elements");
+    /// };
+    /// assert_eq!((err.len, err.num_parts), (5, 8));
+    /// assert_eq!(data.equal_parts_nonempty(0).err(), Some(EqualPartsError::ZeroParts));
+    /// ```
+    fn equal_parts_nonempty(
+        self,
+        num_parts: usize,
+    ) -> Result<EqualPartsIter<'a, T>, EqualPartsError>;
+
+    /// Splits the slice into `num_parts` parts of identical length, or returns an error
+    /// if the length is not a multiple of `num_parts`.
+    ///
+    /// An empty slice divides into any number of parts, and yields none.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EqualPartsError::NotDivisible`] if the length of the slice is not a
+    /// multiple of `num_parts`, and [`EqualPartsError::ZeroParts`] if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::EqualPartsError;
+    /// use equal_parts::checked::CheckedEqualParts;
+    ///
+    /// let records = *b"AAAABBBBCCCC";
+    /// let parts: Vec<&[u8]> = records.equal_parts_exact(3).unwrap().collect();
+    /// assert_eq!(parts, vec![b"AAAA", b"BBBB", b"CCCC"]);
+    ///
+    /// let Err(EqualPartsError::NotDivisible(err)) = records.equal_parts_exact(5) else {
+    ///     panic!("expected a remainder");
+    /// };
+    /// assert_eq!((err.len, err.num_parts), (12, 5));
+    /// ```
+    fn equal_parts_exact(self, num_parts: usize) -> Result<EqualPartsIter<'a, T>, EqualPartsError>;
+}
+
+/// Error returned, as [`EqualPartsError::TooFewElements`], by
+/// [`CheckedEqualParts::equal_parts_nonempty`] when there are fewer elements than parts,
+/// so some parts would be empty.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TooFewElements {
+    /// The number of elements that were to be split.
+    pub len: usize,
+    /// The number of parts that was requested.
+    pub num_parts: usize,
+}
+
+impl fmt::Display for TooFewElements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "cannot split {} elements into {} non-empty parts",
+            self.len, self.num_parts
+        )
+    }
+}
+
+impl std::error::Error for TooFewElements {}
+
+/// Error returned, as [`EqualPartsError::NotDivisible`], by
+/// [`CheckedEqualParts::equal_parts_exact`] when the elements cannot be split into parts
+/// of identical length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotDivisible {
+    /// The number of elements that were to be split.
+    pub len: usize,
+    /// The number of parts that was requested.
+    pub num_parts: usize,
+}
+
+impl fmt::Display for NotDivisible {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} elements do not divide evenly into {} parts",
+            self.len, self.num_parts
+        )
+    }
+}
+
+impl std::error::Error for NotDivisible {}
+
+impl<'a, T> CheckedEqualParts<'a, T> for &'a [T] {
+    fn equal_parts_nonempty(
+        self,
+        num_parts: usize,
+    ) -> Result<EqualPartsIter<'a, T>, EqualPartsError> {
+        if self.len() < num_parts {
+            return Err(TooFewElements {
+                len: self.len(),
+                num_parts,
+            }
+            .into());
+        }
+        self.try_equal_parts(num_parts)
+    }
+
+    fn equal_parts_exact(self, num_parts: usize) -> Result<EqualPartsIter<'a, T>, EqualPartsError> {
+        if num_parts == 0 {
+            return Err(EqualPartsError::ZeroParts);
+        }
+
+        if !self.len().is_multiple_of(num_parts) {
+            return Err(NotDivisible {
+                len: self.len(),
+                num_parts,
+            }
+            .into());
+        }
+        self.try_equal_parts(num_parts)
+    }
+}
+
+impl<'a, T> CheckedEqualParts<'a, T> for &'a Vec<T> {
+    fn equal_parts_nonempty(
+        self,
+        num_parts: usize,
+    ) -> Result<EqualPartsIter<'a, T>, EqualPartsError> {
+        self.as_slice().equal_parts_nonempty(num_parts)
+    }
+
+    fn equal_parts_exact(self, num_parts: usize) -> Result<EqualPartsIter<'a, T>, EqualPartsError> {
+        self.as_slice().equal_parts_exact(num_parts)
+    }
+}
+
+impl<'a, T, const N: usize> CheckedEqualParts<'a, T> for &'a [T; N] {
+    fn equal_parts_nonempty(
+        self,
+        num_parts: usize,
+    ) -> Result<EqualPartsIter<'a, T>, EqualPartsError> {
+        self.as_slice().equal_parts_nonempty(num_parts)
+    }
+
+    fn equal_parts_exact(self, num_parts: usize) -> Result<EqualPartsIter<'a, T>, EqualPartsError> {
+        self.as_slice().equal_parts_exact(num_parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CheckedEqualParts, NotDivisible, TooFewElements};
+    use crate::EqualPartsError;
+
+    #[test]
+    fn nonempty_yields_every_part() {
+        let data: Vec<u8> = (0..10).collect();
+        for num_parts in 1..=10 {
+            let parts: Vec<&[u8]> = data.equal_parts_nonempty(num_parts).unwrap().collect();
+            assert_eq!(parts.len(), num_parts);
+            assert!(parts.iter().all(|part| !part.is_empty()));
+        }
+    }
+
+    #[test]
+    fn nonempty_rejects_too_few_elements() {
+        let data = [1, 2, 3];
+        let err = data.equal_parts_nonempty(4).unwrap_err();
+        assert_eq!(
+            err,
+            EqualPartsError::TooFewElements(TooFewElements {
+                len: 3,
+                num_parts: 4
+            })
+        );
+        assert_eq!(
+            err.to_string(),
+            "cannot split 3 elements into 4 non-empty parts"
+        );
+
+        let empty: &[i32] = &[];
+        assert!(empty.equal_parts_nonempty(1).is_err());
+    }
+
+    #[test]
+    fn exact_parts_have_identical_lengths() {
+        let data: Vec<u16> = (0..24).collect();
+        for num_parts in [1, 2, 3, 4, 6, 8, 12, 24] {
+            let lens: Vec<usize> = data
+                .equal_parts_exact(num_parts)
+                .unwrap()
+                .map(<[u16]>::len)
+                .collect();
+            assert_eq!(lens, vec![24 / num_parts; num_parts]);
+        }
+    }
+
+    #[test]
+    fn exact_rejects_remainders() {
+        let data = [0u8; 10];
+        assert_eq!(
+            data.equal_parts_exact(4).unwrap_err(),
+            EqualPartsError::NotDivisible(NotDivisible {
+                len: 10,
+                num_parts: 4
+            })
+        );
+        assert!(data.equal_parts_exact(20).is_err());
+
+        let empty: &[u8] = &[];
+        assert_eq!(empty.equal_parts_exact(3).unwrap().count(), 0);
+    }
+
+    #[test]
+    fn zero_parts_is_an_error() {
+        assert_eq!(
+            [1].equal_parts_nonempty(0).unwrap_err(),
+            EqualPartsError::ZeroParts
+        );
+        assert_eq!(
+            [1].equal_parts_exact(0).unwrap_err(),
+            EqualPartsError::ZeroParts
+        );
+
+        let empty: &[u8] = &[];
+        assert_eq!(
+            empty.equal_parts_nonempty(0).unwrap_err(),
+            EqualPartsError::ZeroParts
+        );
+        assert_eq!(
+            empty.equal_parts_exact(0).unwrap_err(),
+            EqualPartsError::ZeroParts
+        );
+    }
+}
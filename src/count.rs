@@ -0,0 +1,154 @@
+use crate::full_part_count;
+
+/// Splits a bare count into approximately equal summands.
+///
+/// Sometimes there is no collection to split, just a number of iterations to spread
+/// across workers. This function yields the size of each part using the same rules as
+/// [`equal_parts`](crate::EqualParts::equal_parts): sizes differ by at most one, larger
+/// parts come first, and only non-zero parts are yielded, so there are fewer parts
+/// than requested when `total` is less than `num_parts`.
+///
+/// Use [`with_offsets`](SplitCount::with_offsets) to also get where each part starts.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::split_count;
+///
+/// // Run 10 iterations across 4 workers.
+/// let counts: Vec<usize> = split_count(10, 4).collect();
+/// assert_eq!(counts, vec![3, 3, 2, 2]);
+///
+/// let offsets: Vec<(usize, usize)> = split_count(10, 4).with_offsets().collect();
+/// assert_eq!(offsets, vec![(0, 3), (3, 3), (6, 2), (8, 2)]);
+/// ```
+pub fn split_count(total: usize, num_parts: usize) -> SplitCount {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    SplitCount {
+        part_size: total.div_ceil(num_parts),
+        full_parts_left: full_part_count(total, num_parts),
+        parts_left: num_parts.min(total),
+    }
+}
+
+/// Iterator over the part sizes of a count.
+///
+/// This iterator is created by [`split_count`].
+#[derive(Debug, Clone)]
+pub struct SplitCount {
+    part_size: usize,
+    full_parts_left: usize,
+    parts_left: usize,
+}
+
+impl SplitCount {
+    /// Turns this iterator into one that yields `(offset, count)` pairs, where `offset`
+    /// is the total of all previous parts.
+    pub fn with_offsets(self) -> SplitCountOffsets {
+        SplitCountOffsets {
+            counts: self,
+            offset: 0,
+        }
+    }
+}
+
+impl Iterator for SplitCount {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.parts_left == 0 {
+            return None;
+        }
+
+        let count = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+        self.parts_left -= 1;
+        Some(count)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.parts_left, Some(self.parts_left))
+    }
+}
+
+impl ExactSizeIterator for SplitCount {}
+
+/// Iterator over the offsets and sizes of the parts of a count.
+///
+/// This iterator is created by [`SplitCount::with_offsets`].
+#[derive(Debug, Clone)]
+pub struct SplitCountOffsets {
+    counts: SplitCount,
+    offset: usize,
+}
+
+impl Iterator for SplitCountOffsets {
+    type Item = (usize, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let count = self.counts.next()?;
+        let offset = self.offset;
+        self.offset += count;
+        Some((offset, count))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.counts.size_hint()
+    }
+}
+
+impl ExactSizeIterator for SplitCountOffsets {}
+
+#[cfg(test)]
+mod tests {
+    use super::split_count;
+
+    #[test]
+    fn even_split() {
+        assert_eq!(split_count(9, 3).collect::<Vec<_>>(), vec![3, 3, 3]);
+    }
+
+    #[test]
+    fn uneven_split() {
+        assert_eq!(split_count(7, 3).collect::<Vec<_>>(), vec![3, 2, 2]);
+    }
+
+    #[test]
+    fn fewer_than_parts() {
+        assert_eq!(split_count(2, 5).collect::<Vec<_>>(), vec![1, 1]);
+        assert_eq!(split_count(0, 5).next(), None);
+    }
+
+    #[test]
+    fn exact_size() {
+        let mut counts = split_count(10, 4);
+        assert_eq!(counts.len(), 4);
+        counts.next();
+        assert_eq!(counts.len(), 3);
+        assert_eq!(split_count(2, 5).with_offsets().len(), 2);
+    }
+
+    #[test]
+    fn offsets_sum_to_total() {
+        let parts: Vec<_> = split_count(10_000_000, 8).with_offsets().collect();
+        let (last_offset, last_count) = parts[7];
+        assert_eq!(last_offset + last_count, 10_000_000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = split_count(10, 0);
+    }
+
+    #[test]
+    fn totals_near_max() {
+        let counts: Vec<usize> = split_count(usize::MAX, 2).collect();
+        assert_eq!(counts, vec![usize::MAX / 2 + 1, usize::MAX / 2]);
+    }
+}
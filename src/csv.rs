@@ -0,0 +1,162 @@
+use memchr::memchr2;
+
+use crate::boundary::split_at_boundaries;
+use crate::split_count;
+use crate::text::split_str_at;
+
+/// A trait for splitting CSV data into approximately equal byte parts that hold whole
+/// records.
+///
+/// A quoted CSV field may contain line breaks, so snapping to the next newline, as
+/// [`equal_parts_at_lines`](crate::lines::LineParts::equal_parts_at_lines) does, can
+/// cut a record in two. This trait follows RFC 4180 quoting instead: a double quote
+/// opens or closes a quoted field, and a doubled quote inside one is an escaped
+/// quote. Only line breaks outside quoted fields end a record.
+pub trait CsvParts: Sized {
+    /// Splits CSV data into up to `num_parts` parts of approximately equal byte length,
+    /// each made of whole records.
+    ///
+    /// The boundaries start at the byte offsets
+    /// [`equal_parts`](crate::EqualParts::equal_parts) would use, and each one moves
+    /// forward to the start of the next record. The quoting state cannot be known in
+    /// the middle of a file, so the data is scanned once from the start. Boundaries
+    /// that would make a part empty, or that run past the last record break, are
+    /// dropped, so no part is empty.
+    ///
+    /// A header row is not treated specially: it stays at the start of the first part.
+    /// Malformed data with an unclosed quote is taken to be quoted until the end, so
+    /// everything from that quote on ends up in one part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::csv::CsvParts;
+    ///
+    /// let csv = "id,note\n1,\"first line\nsecond line\"\n2,plain\n3,\"say \"\"hi\"\"\"\n";
+    /// let parts = csv.equal_csv_parts(2);
+    ///
+    /// assert_eq!(parts, vec![
+    ///     "id,note\n1,\"first line\nsecond line\"\n",
+    ///     "2,plain\n3,\"say \"\"hi\"\"\"\n",
+    /// ]);
+    /// ```
+    fn equal_csv_parts(self, num_parts: usize) -> Vec<Self>;
+}
+
+impl CsvParts for &[u8] {
+    fn equal_csv_parts(self, num_parts: usize) -> Vec<Self> {
+        split_at_boundaries(self, &record_boundaries(self, num_parts))
+    }
+}
+
+impl CsvParts for &str {
+    fn equal_csv_parts(self, num_parts: usize) -> Vec<Self> {
+        // Every boundary follows a `\n`, so it is always on a char boundary.
+        split_str_at(self, &record_boundaries(self.as_bytes(), num_parts))
+    }
+}
+
+/// Returns the inner boundaries of an equal byte split of `data`, each moved forward
+/// to the next record start.
+fn record_boundaries(data: &[u8], num_parts: usize) -> Vec<usize> {
+    let mut targets = split_count(data.len(), num_parts)
+        .with_offsets()
+        .skip(1)
+        .map(|(offset, _)| offset);
+    let mut boundaries = Vec::with_capacity(targets.len());
+    let Some(mut target) = targets.next() else {
+        return boundaries;
+    };
+
+    let mut in_quotes = false;
+    let mut position = 0;
+    while let Some(found) = memchr2(b'"', b'\n', &data[position..]) {
+        let index = position + found;
+        position = index + 1;
+        if data[index] == b'"' {
+            in_quotes = !in_quotes;
+            continue;
+        }
+        if in_quotes || position < target {
+            continue;
+        }
+        if position >= data.len() {
+            break;
+        }
+
+        // This record start is the boundary for every target it reaches.
+        boundaries.push(position);
+        while target <= position {
+            match targets.next() {
+                Some(next) => target = next,
+                None => return boundaries,
+            }
+        }
+    }
+    boundaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::CsvParts;
+
+    #[test]
+    fn never_splits_inside_quoted_fields() {
+        let mut csv = String::from("id,text\n");
+        for i in 0..100 {
+            if i % 3 == 0 {
+                csv.push_str(&format!("{i},\"multi\nline \"\"{i}\"\"\n\"\n"));
+            } else {
+                csv.push_str(&format!("{i},plain\n"));
+            }
+        }
+
+        for num_parts in 1..40 {
+            let parts = csv.as_str().equal_csv_parts(num_parts);
+            assert!(parts.len() <= num_parts);
+            assert_eq!(parts.concat(), csv);
+            for part in &parts {
+                assert!(part.ends_with('\n'));
+                // Whole records have balanced quotes.
+                assert_eq!(part.matches('"').count() % 2, 0);
+            }
+            for part in &parts[1..] {
+                assert!(part.chars().next().unwrap().is_ascii_digit());
+            }
+        }
+    }
+
+    #[test]
+    fn plain_csv_matches_line_snapping() {
+        let csv = "a,1\nb,2\nc,3\nd,4\ne,5\nf,6\n";
+        let data: &[u8] = csv.as_bytes();
+        let parts = data.equal_csv_parts(3);
+        assert_eq!(
+            parts,
+            vec![&b"a,1\nb,2\n"[..], b"c,3\nd,4\n", b"e,5\nf,6\n"]
+        );
+    }
+
+    #[test]
+    fn unclosed_quote_keeps_the_rest_together() {
+        let csv = "a\nb\n\"c\nd\ne\nf\n";
+        assert_eq!(csv.equal_csv_parts(4), vec!["a\nb\n", "\"c\nd\ne\nf\n"]);
+    }
+
+    #[test]
+    fn crlf_and_empty_input() {
+        let csv = "x,\"1\r\n2\"\r\ny,3\r\n";
+        assert_eq!(csv.equal_csv_parts(2), vec!["x,\"1\r\n2\"\r\n", "y,3\r\n"]);
+        assert!("".equal_csv_parts(3).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = "a\n".equal_csv_parts(0);
+    }
+}
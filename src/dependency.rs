@@ -0,0 +1,199 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fmt;
+
+use crate::split_count;
+
+/// Splits dependent items into approximately equal parts that respect their
+/// dependencies.
+///
+/// There are `num_items` items, identified by index. Each edge `(a, b)` means item `b`
+/// depends on item `a`, so `b` must be placed in the same part as `a` or in a later
+/// part. The parts can then be run as sequential waves, each of which only depends on
+/// the waves before it.
+///
+/// The items are put in a topological order, preferring lower indices whenever there
+/// is a choice, and that order is split with the usual largest-first rule. Part sizes
+/// therefore differ by at most one, and fewer than `num_parts` parts are returned when
+/// there are fewer items than parts.
+///
+/// # Errors
+///
+/// Returns a [`DependencyCycle`] if the edges contain a cycle, since no ordering can
+/// then satisfy them.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0 or if an edge refers to an item index that is not less
+/// than `num_items`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::dependency::dependency_parts;
+///
+/// // 3 depends on 0, and 1 depends on 3.
+/// let edges = [(0, 3), (3, 1)];
+/// let parts = dependency_parts(4, &edges, 2).unwrap();
+/// assert_eq!(parts, vec![vec![0, 2], vec![3, 1]]);
+/// ```
+pub fn dependency_parts(
+    num_items: usize,
+    edges: &[(usize, usize)],
+    num_parts: usize,
+) -> Result<Vec<Vec<usize>>, DependencyCycle> {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    let mut dependents = vec![Vec::new(); num_items];
+    let mut in_degree = vec![0usize; num_items];
+    for &(from, to) in edges {
+        assert!(
+            from < num_items && to < num_items,
+            "Edge refers to an item that does not exist"
+        );
+        dependents[from].push(to);
+        in_degree[to] += 1;
+    }
+
+    let mut ready: BinaryHeap<Reverse<usize>> = (0..num_items)
+        .filter(|&item| in_degree[item] == 0)
+        .map(Reverse)
+        .collect();
+    let mut order = Vec::with_capacity(num_items);
+    while let Some(Reverse(item)) = ready.pop() {
+        order.push(item);
+        for &dependent in &dependents[item] {
+            in_degree[dependent] -= 1;
+            if in_degree[dependent] == 0 {
+                ready.push(Reverse(dependent));
+            }
+        }
+    }
+
+    if order.len() < num_items {
+        return Err(DependencyCycle::from_remaining(
+            num_items, edges, &in_degree,
+        ));
+    }
+
+    let mut order = order.into_iter();
+    Ok(split_count(num_items, num_parts)
+        .map(|count| order.by_ref().take(count).collect())
+        .collect())
+}
+
+/// Error returned by [`dependency_parts`] when the dependencies contain a cycle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DependencyCycle {
+    /// The edges that lie on a cycle or on a path between cycles.
+    pub edges: Vec<(usize, usize)>,
+}
+
+impl DependencyCycle {
+    /// Narrows the items left over by the topological sort down to those on or between
+    /// cycles, and collects the edges connecting them.
+    fn from_remaining(num_items: usize, edges: &[(usize, usize)], in_degree: &[usize]) -> Self {
+        // Items downstream of a cycle are left over too; peel them off from the end.
+        let mut remaining: Vec<bool> = in_degree.iter().map(|&degree| degree > 0).collect();
+        let mut out_degree = vec![0usize; num_items];
+        let mut dependencies = vec![Vec::new(); num_items];
+        for &(from, to) in edges {
+            if remaining[from] && remaining[to] {
+                out_degree[from] += 1;
+                dependencies[to].push(from);
+            }
+        }
+
+        let mut leaves: Vec<usize> = (0..num_items)
+            .filter(|&item| remaining[item] && out_degree[item] == 0)
+            .collect();
+        while let Some(item) = leaves.pop() {
+            remaining[item] = false;
+            for &dependency in &dependencies[item] {
+                out_degree[dependency] -= 1;
+                if out_degree[dependency] == 0 {
+                    leaves.push(dependency);
+                }
+            }
+        }
+
+        DependencyCycle {
+            edges: edges
+                .iter()
+                .copied()
+                .filter(|&(from, to)| remaining[from] && remaining[to])
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for DependencyCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "dependencies contain a cycle through edges {:?}",
+            self.edges
+        )
+    }
+}
+
+impl std::error::Error for DependencyCycle {}
+
+#[cfg(test)]
+mod tests {
+    use super::{DependencyCycle, dependency_parts};
+
+    fn part_of(parts: &[Vec<usize>], item: usize) -> usize {
+        parts.iter().position(|part| part.contains(&item)).unwrap()
+    }
+
+    #[test]
+    fn no_dependencies_keeps_order() {
+        let parts = dependency_parts(7, &[], 3).unwrap();
+        assert_eq!(parts, vec![vec![0, 1, 2], vec![3, 4], vec![5, 6]]);
+    }
+
+    #[test]
+    fn dependencies_never_point_backwards() {
+        let edges = [(9, 0), (8, 1), (7, 2), (0, 5), (5, 3)];
+        let parts = dependency_parts(10, &edges, 4).unwrap();
+        assert_eq!(
+            parts.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![3, 3, 2, 2]
+        );
+        for (from, to) in edges {
+            assert!(part_of(&parts, from) <= part_of(&parts, to));
+        }
+    }
+
+    #[test]
+    fn fewer_items_than_parts() {
+        let parts = dependency_parts(2, &[(1, 0)], 4).unwrap();
+        assert_eq!(parts, vec![vec![1], vec![0]]);
+    }
+
+    #[test]
+    fn reports_cycle_edges() {
+        // 0 -> 1 -> 2 -> 0 is a cycle; 2 -> 3 only leads out of it.
+        let edges = [(0, 1), (1, 2), (2, 0), (2, 3), (4, 0)];
+        let err = dependency_parts(5, &edges, 2).unwrap_err();
+        assert_eq!(
+            err,
+            DependencyCycle {
+                edges: vec![(0, 1), (1, 2), (2, 0)]
+            }
+        );
+    }
+
+    #[test]
+    fn reports_self_loop() {
+        let err = dependency_parts(2, &[(1, 1)], 2).unwrap_err();
+        assert_eq!(err.edges, vec![(1, 1)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_unknown_item() {
+        let _ = dependency_parts(2, &[(0, 2)], 2);
+    }
+}
@@ -0,0 +1,204 @@
+use std::collections::VecDeque;
+use std::iter::Chain;
+use std::slice;
+
+use crate::EqualParts;
+use crate::full_part_count;
+
+/// A logically contiguous part of a [`VecDeque`], stored as up to two slices.
+///
+/// A `VecDeque` is a ring buffer, so a run of its elements may wrap around the end of
+/// the buffer. The elements of the part are those of [`front`](Self::front) followed by
+/// those of [`back`](Self::back); `back` is empty unless the part wraps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DequePart<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+}
+
+impl<'a, T> DequePart<'a, T> {
+    /// Returns the first run of elements of this part.
+    pub fn front(&self) -> &'a [T] {
+        self.front
+    }
+
+    /// Returns the elements of this part that wrap around to the start of the buffer.
+    pub fn back(&self) -> &'a [T] {
+        self.back
+    }
+
+    /// Returns both slices of this part, like [`VecDeque::as_slices`].
+    pub fn as_slices(&self) -> (&'a [T], &'a [T]) {
+        (self.front, self.back)
+    }
+
+    /// Returns the number of elements in this part.
+    pub fn len(&self) -> usize {
+        self.front.len() + self.back.len()
+    }
+
+    /// Returns `true` if this part has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the element at `index` within this part, if any.
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        match index.checked_sub(self.front.len()) {
+            None => self.front.get(index),
+            Some(index) => self.back.get(index),
+        }
+    }
+
+    /// Returns an iterator over the elements of this part, in order.
+    pub fn iter(&self) -> Chain<slice::Iter<'a, T>, slice::Iter<'a, T>> {
+        self.front.iter().chain(self.back)
+    }
+
+    /// Copies the elements of this part into a new `Vec`.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        [self.front, self.back].concat()
+    }
+}
+
+impl<'a, T> IntoIterator for DequePart<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator that yields approximately equal parts of a `VecDeque`.
+///
+/// This iterator is created by calling [`equal_parts`](EqualParts::equal_parts) on a
+/// `&VecDeque<T>`. Each part is a [`DequePart`] holding the elements in deque order,
+/// even when they wrap around the ring buffer.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+///
+/// use equal_parts::EqualParts;
+///
+/// let mut deque = VecDeque::from([3, 4, 5]);
+/// deque.push_front(2);
+/// deque.push_front(1);
+///
+/// let parts: Vec<Vec<i32>> = deque.equal_parts(2).map(|part| part.to_vec()).collect();
+/// assert_eq!(parts, vec![vec![1, 2, 3], vec![4, 5]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct DequeEqualPartsIter<'a, T> {
+    front: &'a [T],
+    back: &'a [T],
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<'a, T> Iterator for DequeEqualPartsIter<'a, T> {
+    type Item = DequePart<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front.is_empty() {
+            None
+        } else {
+            let split_point = self.part_size - (self.full_parts_left.min(1) ^ 1);
+            self.full_parts_left -= self.full_parts_left.min(1);
+
+            if split_point < self.front.len() {
+                let (chunk, rest) = self.front.split_at(split_point);
+                self.front = rest;
+                Some(DequePart {
+                    front: chunk,
+                    back: &[],
+                })
+            } else {
+                let (wrapped, rest) = self.back.split_at(split_point - self.front.len());
+                let part = DequePart {
+                    front: self.front,
+                    back: wrapped,
+                };
+                self.front = rest;
+                self.back = &[];
+                Some(part)
+            }
+        }
+    }
+}
+
+impl<'a, T> EqualParts for &'a VecDeque<T> {
+    type Item = DequePart<'a, T>;
+    type Iter = DequeEqualPartsIter<'a, T>;
+
+    fn equal_parts(self, num_parts: usize) -> Self::Iter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let (front, back) = self.as_slices();
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+        DequeEqualPartsIter {
+            front,
+            back,
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::EqualParts;
+    use std::collections::VecDeque;
+
+    /// Builds a deque holding `0..len` whose buffer wraps after `front_len` elements.
+    fn wrapped(len: usize, front_len: usize) -> VecDeque<usize> {
+        let mut deque = VecDeque::with_capacity(len);
+        for i in (0..front_len).rev() {
+            deque.push_front(i);
+        }
+        deque.extend(front_len..len);
+        deque
+    }
+
+    #[test]
+    fn parts_follow_deque_order() {
+        let deque = wrapped(10, 4);
+        assert_eq!(deque.as_slices().0, &[0, 1, 2, 3]);
+        let data: Vec<usize> = (0..10).collect();
+        for num_parts in 1..12 {
+            let ours: Vec<Vec<usize>> = deque.equal_parts(num_parts).map(|p| p.to_vec()).collect();
+            let expected: Vec<Vec<usize>> =
+                data.equal_parts(num_parts).map(<[usize]>::to_vec).collect();
+            assert_eq!(ours, expected);
+        }
+    }
+
+    #[test]
+    fn part_access() {
+        let deque = wrapped(6, 2);
+        let part = deque.equal_parts(2).next().unwrap();
+        assert_eq!(part.len(), 3);
+        assert_eq!(part.get(2), Some(&2));
+        assert_eq!(part.get(3), None);
+        assert_eq!(part.into_iter().sum::<usize>(), 3);
+    }
+
+    #[test]
+    fn empty_deque() {
+        let deque: VecDeque<u8> = VecDeque::new();
+        assert_eq!(deque.equal_parts(3).next(), None);
+    }
+
+    #[test]
+    fn zero_sized_deque_near_max_len() {
+        let deque = VecDeque::from(vec![(); usize::MAX]);
+        let lens: Vec<usize> = deque.equal_parts(2).map(|part| part.len()).collect();
+        assert_eq!(lens, vec![usize::MAX / 2 + 1, usize::MAX / 2]);
+    }
+}
@@ -0,0 +1,170 @@
+use std::ops::Range;
+
+/// Byte ranges of a file or buffer split on block boundaries.
+///
+/// Created by [`block_aligned_parts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockAlignedParts {
+    /// Ranges made of whole blocks, each starting and ending on a block boundary.
+    pub parts: Vec<Range<u64>>,
+    /// The final partial block, if the length is not a multiple of the block size.
+    ///
+    /// This range starts on a block boundary but is shorter than a block, so it
+    /// usually needs to be read or written without `O_DIRECT`.
+    pub tail: Option<Range<u64>>,
+}
+
+/// Splits `len` bytes into approximately equal parts whose boundaries are all aligned
+/// to `block_size`.
+///
+/// Direct I/O (such as `O_DIRECT` on Linux) requires offsets and lengths that are
+/// multiples of the device's logical block size, typically 512 or 4096 bytes. This
+/// function balances the parts in whole blocks, using the same largest-first rule as
+/// [`equal_parts`](crate::EqualParts::equal_parts), so part sizes differ by at most one
+/// block. Any final partial block is returned separately as the
+/// [`tail`](BlockAlignedParts::tail) rather than being attached to the last part.
+///
+/// # Panics
+///
+/// Panics if `num_parts` or `block_size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::direct_io::block_aligned_parts;
+///
+/// // 5 full 4 KiB blocks plus 100 bytes, split for 2 readers.
+/// let split = block_aligned_parts(5 * 4096 + 100, 4096, 2);
+///
+/// assert_eq!(split.parts, vec![0..3 * 4096, 3 * 4096..5 * 4096]);
+/// assert_eq!(split.tail, Some(5 * 4096..5 * 4096 + 100));
+/// ```
+pub fn block_aligned_parts(len: u64, block_size: u64, num_parts: usize) -> BlockAlignedParts {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+    assert!(block_size > 0, "Block size must be greater than 0");
+
+    let blocks = len / block_size;
+    let num_parts = num_parts as u64;
+    let part_size = blocks.div_ceil(num_parts);
+    // Equivalent to `num_parts - (part_size * num_parts - blocks)`, without
+    // overflowing for block counts near `u64::MAX`.
+    let full_parts = match blocks % num_parts {
+        0 => num_parts,
+        remainder => remainder,
+    };
+
+    let mut parts = Vec::with_capacity(num_parts.min(blocks) as usize);
+    let mut start = 0;
+    while start < blocks {
+        let size = if (parts.len() as u64) < full_parts {
+            part_size
+        } else {
+            part_size - 1
+        };
+        parts.push(start * block_size..(start + size) * block_size);
+        start += size;
+    }
+
+    let aligned_len = blocks * block_size;
+    BlockAlignedParts {
+        parts,
+        tail: (aligned_len < len).then_some(aligned_len..len),
+    }
+}
+
+/// Splits a byte buffer into approximately equal block-aligned parts and a final
+/// partial block.
+///
+/// This is [`block_aligned_parts`] applied to a buffer in memory. Note that direct I/O
+/// also requires the buffer's address to be aligned, which this function does not
+/// check: the parts are only aligned relative to the start of `data`.
+///
+/// # Panics
+///
+/// Panics if `num_parts` or `block_size` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::direct_io::block_aligned_slices;
+///
+/// let data = [0u8; 10];
+/// let (parts, tail) = block_aligned_slices(&data, 4, 3);
+/// assert_eq!(parts.iter().map(|part| part.len()).collect::<Vec<_>>(), vec![4, 4]);
+/// assert_eq!(tail.len(), 2);
+/// ```
+pub fn block_aligned_slices(
+    data: &[u8],
+    block_size: usize,
+    num_parts: usize,
+) -> (Vec<&[u8]>, &[u8]) {
+    let split = block_aligned_parts(data.len() as u64, block_size as u64, num_parts);
+    let parts = split
+        .parts
+        .into_iter()
+        .map(|range| &data[range.start as usize..range.end as usize])
+        .collect();
+    let tail = match split.tail {
+        Some(range) => &data[range.start as usize..],
+        None => &data[data.len()..],
+    };
+    (parts, tail)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BlockAlignedParts, block_aligned_parts, block_aligned_slices};
+
+    #[test]
+    fn exact_multiple_has_no_tail() {
+        let split = block_aligned_parts(8 * 512, 512, 4);
+        assert_eq!(
+            split,
+            BlockAlignedParts {
+                parts: vec![0..1024, 1024..2048, 2048..3072, 3072..4096],
+                tail: None,
+            }
+        );
+    }
+
+    #[test]
+    fn uneven_blocks() {
+        let split = block_aligned_parts(7 * 10, 10, 3);
+        assert_eq!(split.parts, vec![0..30, 30..50, 50..70]);
+        assert!(
+            split
+                .parts
+                .iter()
+                .all(|part| part.start % 10 == 0 && part.end % 10 == 0)
+        );
+    }
+
+    #[test]
+    fn smaller_than_a_block() {
+        let split = block_aligned_parts(100, 4096, 4);
+        assert!(split.parts.is_empty());
+        assert_eq!(split.tail, Some(0..100));
+    }
+
+    #[test]
+    fn fewer_blocks_than_parts() {
+        let split = block_aligned_parts(2 * 4096 + 1, 4096, 8);
+        assert_eq!(split.parts, vec![0..4096, 4096..8192]);
+        assert_eq!(split.tail, Some(8192..8193));
+    }
+
+    #[test]
+    fn slices_cover_buffer() {
+        let data: Vec<u8> = (0..=255).collect();
+        let (parts, tail) = block_aligned_slices(&data, 16, 5);
+        assert_eq!(parts.len(), 5);
+        assert_eq!([parts.concat(), tail.to_vec()].concat(), data);
+        assert!(tail.is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_block_size() {
+        let _ = block_aligned_parts(100, 0, 2);
+    }
+}
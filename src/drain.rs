@@ -0,0 +1,184 @@
+use std::vec;
+
+use crate::full_part_count;
+
+/// A trait for draining a `Vec` in approximately equal parts through one reused buffer.
+///
+/// [`into_equal_parts`](crate::IntoEqualParts::into_equal_parts) allocates a new `Vec`
+/// for every part. When parts are processed one at a time, that is wasted work: this
+/// trait instead moves each part into a single buffer that is cleared and refilled for
+/// the next one, so only one allocation is made however many parts there are.
+///
+/// Since each part borrows the shared buffer, the returned [`DrainParts`] is a lending
+/// iterator. Use [`next_part`](DrainParts::next_part) in a `while let` loop, or
+/// [`for_each_part`](DrainParts::for_each_part).
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::drain::DrainEqualParts;
+///
+/// let mut data: Vec<u32> = (1..=10).collect();
+/// let mut parts = data.drain_equal_parts(4);
+///
+/// let mut sums = Vec::new();
+/// while let Some(part) = parts.next_part() {
+///     sums.push(part.iter().sum::<u32>());
+/// }
+/// drop(parts);
+///
+/// assert_eq!(sums, vec![6, 15, 15, 19]);
+/// assert!(data.is_empty());
+/// ```
+pub trait DrainEqualParts<T> {
+    /// Drains the collection in approximately equal parts.
+    ///
+    /// The parts have the same sizes as those of
+    /// [`equal_parts`](crate::EqualParts::equal_parts), with larger parts first. The
+    /// collection is left empty but keeps its capacity. Elements of parts that are
+    /// never reached are dropped along with the iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    fn drain_equal_parts(&mut self, num_parts: usize) -> DrainParts<'_, T>;
+}
+
+impl<T> DrainEqualParts<T> for Vec<T> {
+    fn drain_equal_parts(&mut self, num_parts: usize) -> DrainParts<'_, T> {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+        DrainParts {
+            buffer: Vec::with_capacity(part_size),
+            source: self.drain(..),
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+/// Lending iterator over the parts of a drained `Vec`, all stored in one buffer.
+///
+/// This iterator is created by [`DrainEqualParts::drain_equal_parts`].
+#[derive(Debug)]
+pub struct DrainParts<'a, T> {
+    source: vec::Drain<'a, T>,
+    buffer: Vec<T>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<T> DrainParts<'_, T> {
+    /// Moves the next part into the buffer and returns it, or returns `None` once every
+    /// element has been drained.
+    ///
+    /// Whatever is left in the buffer from the previous part is dropped first. The
+    /// buffer is returned mutably, so a part can be sorted or consumed in place, for
+    /// example with [`Vec::drain`].
+    pub fn next_part(&mut self) -> Option<&mut Vec<T>> {
+        self.buffer.clear();
+        if self.source.len() == 0 {
+            return None;
+        }
+
+        let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        self.buffer.extend(self.source.by_ref().take(chunk_size));
+        Some(&mut self.buffer)
+    }
+
+    /// Calls `f` on each remaining part in turn.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::drain::DrainEqualParts;
+    ///
+    /// let mut data = vec!["b", "a", "d", "c", "e"];
+    /// let mut sorted = Vec::new();
+    /// data.drain_equal_parts(2).for_each_part(|part| {
+    ///     part.sort();
+    ///     sorted.push(part.concat());
+    /// });
+    /// assert_eq!(sorted, vec!["abd", "ce"]);
+    /// ```
+    pub fn for_each_part(mut self, mut f: impl FnMut(&mut Vec<T>)) {
+        while let Some(part) = self.next_part() {
+            f(part);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::rc::Rc;
+
+    use super::DrainEqualParts;
+    use crate::EqualParts;
+
+    #[test]
+    fn same_parts_as_equal_parts() {
+        let mut data: Vec<u32> = (0..23).collect();
+        let expected: Vec<Vec<u32>> = data.equal_parts(6).map(<[u32]>::to_vec).collect();
+        let mut parts = data.drain_equal_parts(6);
+        let mut actual = Vec::new();
+        while let Some(part) = parts.next_part() {
+            actual.push(part.clone());
+        }
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn reuses_one_buffer() {
+        let mut data: Vec<u8> = vec![0; 100];
+        let mut parts = data.drain_equal_parts(7);
+        let first = parts.next_part().unwrap().as_ptr();
+        while let Some(part) = parts.next_part() {
+            assert_eq!(part.as_ptr(), first);
+        }
+    }
+
+    #[test]
+    fn source_keeps_capacity() {
+        let mut data: Vec<u8> = Vec::with_capacity(64);
+        data.extend(0..10);
+        data.drain_equal_parts(3).for_each_part(|_| {});
+        assert!(data.is_empty());
+        assert_eq!(data.capacity(), 64);
+    }
+
+    #[test]
+    fn unreached_parts_are_dropped() {
+        let marker = Rc::new(());
+        let mut data = vec![Rc::clone(&marker); 9];
+        let mut parts = data.drain_equal_parts(3);
+        assert_eq!(parts.next_part().map(|part| part.len()), Some(3));
+        drop(parts);
+        assert_eq!(Rc::strong_count(&marker), 1);
+    }
+
+    #[test]
+    fn empty_vec() {
+        let mut data: Vec<i32> = Vec::new();
+        assert!(data.drain_equal_parts(3).next_part().is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = vec![1].drain_equal_parts(0);
+    }
+
+    #[test]
+    fn zero_sized_vec_near_max_len() {
+        let mut data = vec![(); usize::MAX];
+        let parts = data.drain_equal_parts(2);
+        assert_eq!(
+            (parts.part_size, parts.full_parts_left),
+            (usize::MAX / 2 + 1, 1)
+        );
+    }
+}
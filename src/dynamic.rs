@@ -0,0 +1,78 @@
+use crate::EqualParts;
+
+/// An object-safe companion to [`EqualParts`] for slice-backed collections.
+///
+/// `EqualParts` returns a concrete iterator type, which keeps it zero-cost but means
+/// it cannot be used through a trait object. This trait boxes the iterator instead,
+/// so plugin-style code that only knows it holds "some collection of `T`" can store a
+/// `&dyn DynEqualParts<T>` or `Box<dyn DynEqualParts<T>>` and still split it.
+///
+/// It is implemented for every type that implements `AsRef<[T]>`, such as `Vec<T>`,
+/// arrays, `Box<[T]>` and `Arc<[T]>`, and yields the same `&[T]` parts as
+/// [`equal_parts`](EqualParts::equal_parts) does for the slice.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use equal_parts::DynEqualParts;
+///
+/// fn part_lens(data: &dyn DynEqualParts<u32>) -> Vec<usize> {
+///     data.equal_parts_boxed(3).map(<[u32]>::len).collect()
+/// }
+///
+/// let sources: Vec<Box<dyn DynEqualParts<u32>>> = vec![
+///     Box::new(vec![1, 2, 3, 4, 5, 6, 7]),
+///     Box::new([1, 2, 3, 4]),
+///     Box::new(Arc::<[u32]>::from([1, 2])),
+/// ];
+/// let lens: Vec<Vec<usize>> = sources.iter().map(|source| part_lens(&**source)).collect();
+/// assert_eq!(lens, vec![vec![3, 2, 2], vec![2, 1, 1], vec![1, 1]]);
+/// ```
+pub trait DynEqualParts<T> {
+    /// Splits the collection into approximately equal parts, returning a boxed
+    /// iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    fn equal_parts_boxed(&self, num_parts: usize) -> Box<dyn Iterator<Item = &[T]> + '_>;
+}
+
+impl<T, C: AsRef<[T]> + ?Sized> DynEqualParts<T> for C {
+    fn equal_parts_boxed(&self, num_parts: usize) -> Box<dyn Iterator<Item = &[T]> + '_> {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        Box::new(self.as_ref().equal_parts(num_parts))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynEqualParts;
+    use crate::EqualParts;
+
+    #[test]
+    fn same_parts_as_static_dispatch() {
+        let data: Vec<i32> = (0..17).collect();
+        let dynamic: &dyn DynEqualParts<i32> = &data;
+        let boxed: Vec<&[i32]> = dynamic.equal_parts_boxed(5).collect();
+        let expected: Vec<&[i32]> = data.equal_parts(5).collect();
+        assert_eq!(boxed, expected);
+    }
+
+    #[test]
+    fn works_on_unsized_slices() {
+        let data: &[u8] = &[1, 2, 3];
+        let parts: Vec<&[u8]> = data.equal_parts_boxed(2).collect();
+        assert_eq!(parts, vec![&[1, 2][..], &[3]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let data: &dyn DynEqualParts<u8> = &[1u8, 2];
+        let _ = data.equal_parts_boxed(0);
+    }
+}
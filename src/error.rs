@@ -0,0 +1,173 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::checked::{NotDivisible, TooFewElements};
+use crate::from_parts::LengthMismatch;
+use crate::records::PartialRecord;
+
+/// Error returned by the non-panicking splitting methods, such as
+/// [`try_equal_parts`](crate::EqualParts::try_equal_parts), when a split is not
+/// possible with the given configuration.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::{EqualParts, EqualPartsError};
+///
+/// fn split(data: &[u8], workers: usize) -> Result<Vec<&[u8]>, EqualPartsError> {
+///     Ok(data.try_equal_parts(workers)?.collect())
+/// }
+///
+/// assert_eq!(split(b"abcd", 2).unwrap().len(), 2);
+/// assert_eq!(split(b"abcd", 0), Err(EqualPartsError::ZeroParts));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EqualPartsError {
+    /// Zero parts were requested.
+    ZeroParts,
+    /// There were fewer elements than parts, and empty parts were not allowed.
+    TooFewElements(TooFewElements),
+    /// The elements could not be split into parts of identical length.
+    NotDivisible(NotDivisible),
+    /// A buffer of fixed-size records ended with a partial record.
+    PartialRecord(PartialRecord),
+    /// Parts were reassembled into a collection of the wrong length.
+    LengthMismatch(LengthMismatch),
+    /// The largest part does not fit in the fixed capacity of the parts.
+    CapacityExceeded(CapacityExceeded),
+}
+
+impl fmt::Display for EqualPartsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EqualPartsError::ZeroParts => f.write_str("number of parts must be greater than 0"),
+            EqualPartsError::TooFewElements(err) => err.fmt(f),
+            EqualPartsError::NotDivisible(err) => err.fmt(f),
+            EqualPartsError::PartialRecord(err) => err.fmt(f),
+            EqualPartsError::LengthMismatch(err) => err.fmt(f),
+            EqualPartsError::CapacityExceeded(err) => err.fmt(f),
+        }
+    }
+}
+
+impl Error for EqualPartsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            EqualPartsError::ZeroParts => None,
+            EqualPartsError::TooFewElements(err) => Some(err),
+            EqualPartsError::NotDivisible(err) => Some(err),
+            EqualPartsError::PartialRecord(err) => Some(err),
+            EqualPartsError::LengthMismatch(err) => Some(err),
+            EqualPartsError::CapacityExceeded(err) => Some(err),
+        }
+    }
+}
+
+impl From<TooFewElements> for EqualPartsError {
+    fn from(err: TooFewElements) -> Self {
+        EqualPartsError::TooFewElements(err)
+    }
+}
+
+impl From<NotDivisible> for EqualPartsError {
+    fn from(err: NotDivisible) -> Self {
+        EqualPartsError::NotDivisible(err)
+    }
+}
+
+impl From<PartialRecord> for EqualPartsError {
+    fn from(err: PartialRecord) -> Self {
+        EqualPartsError::PartialRecord(err)
+    }
+}
+
+impl From<LengthMismatch> for EqualPartsError {
+    fn from(err: LengthMismatch) -> Self {
+        EqualPartsError::LengthMismatch(err)
+    }
+}
+
+impl From<CapacityExceeded> for EqualPartsError {
+    fn from(err: CapacityExceeded) -> Self {
+        EqualPartsError::CapacityExceeded(err)
+    }
+}
+
+/// Error returned when the parts of a split are collected into containers of a fixed
+/// capacity, and the largest part does not fit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityExceeded {
+    /// The number of elements in the largest part.
+    pub part_size: usize,
+    /// The capacity of each part.
+    pub capacity: usize,
+}
+
+impl fmt::Display for CapacityExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "parts of {} elements do not fit in a capacity of {}",
+            self.part_size, self.capacity
+        )
+    }
+}
+
+impl Error for CapacityExceeded {}
+
+#[cfg(test)]
+mod tests {
+    use super::EqualPartsError;
+    use crate::checked::CheckedEqualParts;
+    use crate::{EqualParts, FromParts, IntoEqualParts};
+
+    #[test]
+    fn try_equal_parts_matches_equal_parts() {
+        let data = [1, 2, 3, 4, 5];
+        let tried: Vec<&[i32]> = data.as_slice().try_equal_parts(2).unwrap().collect();
+        let expected: Vec<&[i32]> = data.equal_parts(2).collect();
+        assert_eq!(tried, expected);
+    }
+
+    #[test]
+    fn zero_parts_is_an_error() {
+        assert_eq!(
+            "abc".try_equal_parts(0).err(),
+            Some(EqualPartsError::ZeroParts)
+        );
+        assert_eq!(
+            vec![1, 2].try_into_equal_parts(0).err(),
+            Some(EqualPartsError::ZeroParts)
+        );
+        assert_eq!(
+            EqualPartsError::ZeroParts.to_string(),
+            "number of parts must be greater than 0"
+        );
+    }
+
+    #[test]
+    fn checked_errors_convert() {
+        fn exact(data: &[u8], num_parts: usize) -> Result<usize, EqualPartsError> {
+            Ok(data.equal_parts_exact(num_parts)?.count())
+        }
+        let err = exact(&[0; 5], 2).unwrap_err();
+        assert!(matches!(err, EqualPartsError::NotDivisible(_)));
+        assert!(std::error::Error::source(&err).is_some());
+    }
+
+    #[test]
+    fn length_mismatch_converts() {
+        fn roundtrip(data: Vec<u8>, expected: usize) -> Result<Vec<u8>, EqualPartsError> {
+            let parts: Vec<Vec<u8>> = data.try_into_equal_parts(3)?.collect();
+            Ok(Vec::try_from_equal_parts(parts, expected)?)
+        }
+        assert_eq!(roundtrip(vec![1, 2, 3, 4], 4), Ok(vec![1, 2, 3, 4]));
+        let err = roundtrip(vec![1, 2, 3, 4], 5).unwrap_err();
+        assert!(matches!(
+            err,
+            EqualPartsError::LengthMismatch(mismatch) if mismatch.expected == 5 && mismatch.actual == 4
+        ));
+        assert!(std::error::Error::source(&err).is_some());
+    }
+}
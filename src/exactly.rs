@@ -0,0 +1,128 @@
+/// A trait for splitting slices into exactly the requested number of parts.
+///
+/// [`equal_parts`](crate::EqualParts::equal_parts) never yields empty parts, so it
+/// yields fewer parts than requested when there are fewer elements than parts. This
+/// trait pads the sequence with empty parts at the end instead, so there is always
+/// one part per slot, which suits work that is pre-assigned to a fixed set of workers.
+///
+/// When there are at least as many elements as parts, the parts are identical to
+/// those of `equal_parts`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::exactly::EqualPartsExactly;
+///
+/// let data = [1, 2];
+/// let parts: Vec<&[i32]> = data.equal_parts_exactly(4).collect();
+/// assert_eq!(parts, vec![&[1][..], &[2], &[], &[]]);
+/// ```
+pub trait EqualPartsExactly<'a, T> {
+    /// Splits the collection into exactly `num_parts` approximately equal parts,
+    /// padding with empty parts if there are fewer elements than parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    fn equal_parts_exactly(self, num_parts: usize) -> ExactlyParts<'a, T>;
+}
+
+/// Iterator that yields exactly the requested number of approximately equal parts of
+/// a slice.
+///
+/// This iterator is created by [`EqualPartsExactly::equal_parts_exactly`].
+#[derive(Debug, Clone)]
+pub struct ExactlyParts<'a, T> {
+    data: &'a [T],
+    part_size: usize,
+    full_parts_left: usize,
+    parts_left: usize,
+}
+
+impl<'a, T> Iterator for ExactlyParts<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.parts_left == 0 {
+            return None;
+        }
+
+        // Once the data runs out, the same rule gives parts of size zero.
+        let split_point = self
+            .part_size
+            .saturating_sub(self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+        self.parts_left -= 1;
+
+        let (chunk, rest) = self.data.split_at(split_point);
+        self.data = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.parts_left, Some(self.parts_left))
+    }
+}
+
+impl<T> ExactSizeIterator for ExactlyParts<'_, T> {}
+
+impl<'a, T> EqualPartsExactly<'a, T> for &'a [T] {
+    fn equal_parts_exactly(self, num_parts: usize) -> ExactlyParts<'a, T> {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        ExactlyParts {
+            data: self,
+            part_size: self.len().div_ceil(num_parts),
+            full_parts_left: crate::full_part_count(self.len(), num_parts).min(self.len()),
+            parts_left: num_parts,
+        }
+    }
+}
+
+impl<'a, T> EqualPartsExactly<'a, T> for &'a Vec<T> {
+    fn equal_parts_exactly(self, num_parts: usize) -> ExactlyParts<'a, T> {
+        self.as_slice().equal_parts_exactly(num_parts)
+    }
+}
+
+impl<'a, T, const N: usize> EqualPartsExactly<'a, T> for &'a [T; N] {
+    fn equal_parts_exactly(self, num_parts: usize) -> ExactlyParts<'a, T> {
+        self.as_slice().equal_parts_exactly(num_parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EqualPartsExactly;
+    use crate::EqualParts;
+
+    #[test]
+    fn always_yields_num_parts() {
+        let data: Vec<u8> = (0..20).collect();
+        for len in 0..data.len() {
+            for num_parts in 1..25 {
+                let parts: Vec<&[u8]> = data[..len].equal_parts_exactly(num_parts).collect();
+                assert_eq!(parts.len(), num_parts);
+                assert_eq!(parts.concat(), &data[..len]);
+
+                let non_empty: Vec<&[u8]> = data[..len].equal_parts(num_parts).collect();
+                assert_eq!(&parts[..non_empty.len()], non_empty.as_slice());
+                assert!(parts[non_empty.len()..].iter().all(|part| part.is_empty()));
+            }
+        }
+    }
+
+    #[test]
+    fn empty_slice_gives_empty_parts() {
+        let data: Vec<i32> = Vec::new();
+        let parts = data.equal_parts_exactly(3);
+        assert_eq!(parts.len(), 3);
+        assert!(parts.into_iter().all(<[i32]>::is_empty));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = [1].equal_parts_exactly(0);
+    }
+}
@@ -0,0 +1,188 @@
+use crate::split_count;
+
+/// Splits a FASTA buffer into approximately equal byte parts that each contain whole
+/// records.
+///
+/// The buffer is first divided at the byte offsets
+/// [`equal_parts`](crate::EqualParts::equal_parts) would use, then each boundary is
+/// moved forward to the next record start, which is a line beginning with `>`. Parts
+/// can therefore be parsed independently, for example on separate threads.
+///
+/// Records are never split, so a part can be larger than its share of bytes, and
+/// fewer than `num_parts` parts are returned when there are fewer records than parts.
+/// No part is empty. Anything before the first header stays at the start of the first
+/// part.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::fastx::fasta_parts;
+///
+/// let fasta = b">seq1\nACGT\nAC\n>seq2\nGGGG\n>seq3\nTTTT\n>seq4\nCC\n";
+/// let parts = fasta_parts(fasta, 2);
+///
+/// assert_eq!(parts.len(), 2);
+/// assert_eq!(parts[0], b">seq1\nACGT\nAC\n>seq2\nGGGG\n");
+/// assert_eq!(parts[1], b">seq3\nTTTT\n>seq4\nCC\n");
+/// ```
+pub fn fasta_parts(data: &[u8], num_parts: usize) -> Vec<&[u8]> {
+    record_aligned_parts(data, num_parts, |line_start| {
+        data[line_start..].first() == Some(&b'>')
+    })
+}
+
+/// Splits a FASTQ buffer into approximately equal byte parts that each contain whole
+/// records.
+///
+/// This works like [`fasta_parts`], but snaps boundaries to the start of four-line
+/// FASTQ records. Since quality lines may also begin with `@`, a line is only taken as
+/// a record start if it begins with `@`, the line two below it begins with `+`, and
+/// the sequence and quality lines have the same length. Multi-line FASTQ, where
+/// sequences are wrapped over several lines, is not supported.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::fastx::fastq_parts;
+///
+/// // The quality line of the first record starts with '@'.
+/// let fastq = b"@r1\nACGT\n+\n@@@@\n@r2\nGGCC\n+\nIIII\n@r3\nTTAA\n+\n@III\n";
+/// let parts = fastq_parts(fastq, 3);
+///
+/// assert_eq!(parts.len(), 3);
+/// assert_eq!(parts[1], b"@r2\nGGCC\n+\nIIII\n");
+/// ```
+pub fn fastq_parts(data: &[u8], num_parts: usize) -> Vec<&[u8]> {
+    record_aligned_parts(data, num_parts, |line_start| {
+        let mut lines = data[line_start..].split(|&byte| byte == b'\n');
+        let (Some(header), Some(sequence), Some(plus), Some(quality)) =
+            (lines.next(), lines.next(), lines.next(), lines.next())
+        else {
+            return false;
+        };
+        header.first() == Some(&b'@')
+            && plus.first() == Some(&b'+')
+            && trim_cr(sequence).len() == trim_cr(quality).len()
+    })
+}
+
+/// Splits `data` near the equal byte offsets, moving each boundary forward to the
+/// next line start for which `is_record_start` returns `true`.
+fn record_aligned_parts(
+    data: &[u8],
+    num_parts: usize,
+    is_record_start: impl Fn(usize) -> bool,
+) -> Vec<&[u8]> {
+    let mut parts = Vec::with_capacity(num_parts.min(data.len()));
+    let mut start = 0;
+    for (offset, _) in split_count(data.len(), num_parts).with_offsets().skip(1) {
+        if offset <= start {
+            continue;
+        }
+        let mut boundary = next_line_start(data, offset);
+        while boundary < data.len() && !is_record_start(boundary) {
+            boundary = next_line_start(data, boundary + 1);
+        }
+        if boundary < data.len() {
+            parts.push(&data[start..boundary]);
+            start = boundary;
+        }
+    }
+    if start < data.len() {
+        parts.push(&data[start..]);
+    }
+    parts
+}
+
+/// Returns the first line start at or after `position`, or `data.len()` if there is
+/// none.
+fn next_line_start(data: &[u8], position: usize) -> usize {
+    if position == 0 || data.get(position - 1) == Some(&b'\n') {
+        return position.min(data.len());
+    }
+    match data[position..].iter().position(|&byte| byte == b'\n') {
+        Some(newline) => position + newline + 1,
+        None => data.len(),
+    }
+}
+
+/// Removes a trailing carriage return left over from a CRLF line ending.
+fn trim_cr(line: &[u8]) -> &[u8] {
+    line.strip_suffix(b"\r").unwrap_or(line)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fasta_parts, fastq_parts};
+
+    fn fasta_records(count: usize) -> Vec<u8> {
+        let mut data = Vec::new();
+        for i in 0..count {
+            data.extend_from_slice(format!(">seq{i} description\n").as_bytes());
+            data.extend_from_slice(&b"ACGTACGTAC\n".repeat(i % 4 + 1));
+        }
+        data
+    }
+
+    #[test]
+    fn fasta_parts_hold_whole_records() {
+        let data = fasta_records(50);
+        let parts = fasta_parts(&data, 6);
+        assert_eq!(parts.len(), 6);
+        assert_eq!(parts.concat(), data);
+        for part in &parts {
+            assert!(part.starts_with(b">"));
+            assert!(part.ends_with(b"\n"));
+        }
+    }
+
+    #[test]
+    fn fasta_fewer_records_than_parts() {
+        let data = fasta_records(2);
+        assert_eq!(fasta_parts(&data, 8).len(), 2);
+        assert!(fasta_parts(b"", 3).is_empty());
+    }
+
+    #[test]
+    fn fasta_keeps_leading_text_in_first_part() {
+        let data = b"; comment\n>a\nAC\n>b\nGT\n";
+        let parts = fasta_parts(data, 2);
+        assert_eq!(parts, vec![&b"; comment\n>a\nAC\n"[..], b">b\nGT\n"]);
+    }
+
+    #[test]
+    fn fastq_skips_quality_lines_starting_with_at() {
+        let mut data = Vec::new();
+        for i in 0..40 {
+            data.extend_from_slice(format!("@read{i}\nACGTAC\n+\n@@@@@@\n").as_bytes());
+        }
+        let parts = fastq_parts(&data, 7);
+        assert_eq!(parts.len(), 7);
+        assert_eq!(parts.concat(), data);
+        for part in &parts {
+            assert!(part.starts_with(b"@read"));
+            assert_eq!(part.split(|&b| b == b'\n').count() % 4, 1);
+        }
+    }
+
+    #[test]
+    fn fastq_crlf() {
+        let data = b"@a\r\nAC\r\n+\r\n@@\r\n@b\r\nGT\r\n+\r\nII\r\n";
+        let parts = fastq_parts(data, 2);
+        assert_eq!(parts, vec![&data[..15], &data[15..]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = fasta_parts(b">a\nA\n", 0);
+    }
+}
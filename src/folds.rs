@@ -0,0 +1,358 @@
+use std::collections::VecDeque;
+use std::iter::Chain;
+use std::slice;
+
+use crate::{EqualParts, EqualPartsIter};
+
+/// The training data of a fold: every element outside the fold's test part.
+///
+/// The test part of a k-fold split sits somewhere in the middle of the data, so the
+/// training data is made of the elements before it and the elements after it. Both
+/// halves are borrowed, and [`iter`](Train::iter) walks them as one sequence without
+/// copying.
+#[derive(Debug)]
+pub struct Train<'a, T> {
+    before: &'a [T],
+    after: &'a [T],
+}
+
+impl<T> Clone for Train<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Train<'_, T> {}
+
+impl<'a, T> Train<'a, T> {
+    /// Returns the elements before and after the test part, in that order.
+    pub fn halves(&self) -> (&'a [T], &'a [T]) {
+        (self.before, self.after)
+    }
+
+    /// Returns the number of training elements.
+    pub fn len(&self) -> usize {
+        self.before.len() + self.after.len()
+    }
+
+    /// Returns `true` if there are no training elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns an iterator over the training elements, in input order.
+    pub fn iter(&self) -> Chain<slice::Iter<'a, T>, slice::Iter<'a, T>> {
+        self.before.iter().chain(self.after)
+    }
+
+    /// Copies the training elements into a new `Vec`.
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        [self.before, self.after].concat()
+    }
+}
+
+impl<'a, T> IntoIterator for Train<'a, T> {
+    type Item = &'a T;
+    type IntoIter = Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the folds of a k-fold cross-validation split.
+///
+/// This iterator is created by calling [`k_folds`](KFolds::k_folds) on a slice.
+#[derive(Debug, Clone)]
+pub struct KFoldsIter<'a, T> {
+    data: &'a [T],
+    parts: EqualPartsIter<'a, T>,
+    offset: usize,
+}
+
+impl<'a, T> Iterator for KFoldsIter<'a, T> {
+    type Item = (Train<'a, T>, &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let test = self.parts.next()?;
+        let train = Train {
+            before: &self.data[..self.offset],
+            after: &self.data[self.offset + test.len()..],
+        };
+        self.offset += test.len();
+        Some((train, test))
+    }
+}
+
+/// Iterator over the folds of a time-series cross-validation split.
+///
+/// This iterator is created by calling [`expanding_folds`](KFolds::expanding_folds)
+/// or [`rolling_folds`](KFolds::rolling_folds) on a slice. Each fold trains on a
+/// contiguous run of parts and tests on the part right after it.
+#[derive(Debug, Clone)]
+pub struct TimeSeriesFoldsIter<'a, T> {
+    data: &'a [T],
+    parts: EqualPartsIter<'a, T>,
+    offset: usize,
+    /// The number of training parts, or `None` to train on every earlier part.
+    window: Option<usize>,
+    /// The start offsets of the last `window` parts, when rolling.
+    starts: VecDeque<usize>,
+    parts_seen: usize,
+}
+
+impl<'a, T> Iterator for TimeSeriesFoldsIter<'a, T> {
+    type Item = (&'a [T], &'a [T]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let test = self.parts.next()?;
+            let start = self.offset;
+            self.offset += test.len();
+
+            let ready = self.parts_seen >= self.window.unwrap_or(1);
+            self.parts_seen += 1;
+            let train_start = match self.window {
+                None => 0,
+                Some(window) => {
+                    self.starts.push_back(start);
+                    if self.starts.len() > window {
+                        self.starts.pop_front()
+                    } else {
+                        None
+                    }
+                    .unwrap_or(0)
+                }
+            };
+
+            if ready {
+                return Some((&self.data[train_start..start], test));
+            }
+        }
+    }
+}
+
+/// A trait for cross-validation splits of ordered data.
+pub trait KFolds<'a, T> {
+    /// Returns an iterator over the `k` folds of a k-fold cross-validation split.
+    ///
+    /// The data is split into `k` parts exactly like
+    /// [`equal_parts`](EqualParts::equal_parts). Fold `i` tests on part `i` and trains
+    /// on all of the other parts, so every element is tested exactly once. The data is
+    /// not shuffled; shuffle it first if its order carries meaning that should not
+    /// leak into the folds.
+    ///
+    /// If there are fewer elements than `k`, there is one fold per element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::folds::KFolds;
+    ///
+    /// let data = [0, 1, 2, 3, 4, 5, 6];
+    /// let folds: Vec<(Vec<i32>, &[i32])> = data
+    ///     .k_folds(3)
+    ///     .map(|(train, test)| (train.to_vec(), test))
+    ///     .collect();
+    ///
+    /// assert_eq!(folds[0], (vec![3, 4, 5, 6], &[0, 1, 2][..]));
+    /// assert_eq!(folds[1], (vec![0, 1, 2, 5, 6], &[3, 4][..]));
+    /// assert_eq!(folds[2], (vec![0, 1, 2, 3, 4], &[5, 6][..]));
+    /// ```
+    fn k_folds(self, k: usize) -> KFoldsIter<'a, T>;
+
+    /// Returns an iterator over expanding-window folds for time-series data.
+    ///
+    /// The data is split into `num_parts` parts like
+    /// [`equal_parts`](EqualParts::equal_parts). Fold `i` trains on parts `0..i` and
+    /// tests on part `i`, for every `i` from 1, so a model is never tested on data
+    /// older than what it was trained on. There are `num_parts - 1` folds, or one less
+    /// than the number of elements if that is smaller.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::folds::KFolds;
+    ///
+    /// let days: Vec<u32> = (1..=8).collect();
+    /// let folds: Vec<_> = days.expanding_folds(4).collect();
+    ///
+    /// assert_eq!(folds.len(), 3);
+    /// assert_eq!(folds[0], (&days[..2], &days[2..4]));
+    /// assert_eq!(folds[2], (&days[..6], &days[6..]));
+    /// ```
+    fn expanding_folds(self, num_parts: usize) -> TimeSeriesFoldsIter<'a, T>;
+
+    /// Returns an iterator over rolling-window folds for time-series data.
+    ///
+    /// This is like [`expanding_folds`](KFolds::expanding_folds), except that each
+    /// fold trains only on the `window` parts right before its test part, so old data
+    /// drops out as the window moves forward. Fold `i` trains on parts
+    /// `i - window..i` and tests on part `i`, for every `i` from `window`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` or `window` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::folds::KFolds;
+    ///
+    /// let days: Vec<u32> = (1..=10).collect();
+    /// let folds: Vec<_> = days.rolling_folds(5, 2).collect();
+    ///
+    /// assert_eq!(folds.len(), 3);
+    /// assert_eq!(folds[0], (&days[..4], &days[4..6]));
+    /// assert_eq!(folds[1], (&days[2..6], &days[6..8]));
+    /// assert_eq!(folds[2], (&days[4..8], &days[8..]));
+    /// ```
+    fn rolling_folds(self, num_parts: usize, window: usize) -> TimeSeriesFoldsIter<'a, T>;
+}
+
+impl<'a, T> KFolds<'a, T> for &'a [T] {
+    fn k_folds(self, k: usize) -> KFoldsIter<'a, T> {
+        KFoldsIter {
+            data: self,
+            parts: self.equal_parts(k),
+            offset: 0,
+        }
+    }
+
+    fn expanding_folds(self, num_parts: usize) -> TimeSeriesFoldsIter<'a, T> {
+        TimeSeriesFoldsIter {
+            data: self,
+            parts: self.equal_parts(num_parts),
+            offset: 0,
+            window: None,
+            starts: VecDeque::new(),
+            parts_seen: 0,
+        }
+    }
+
+    fn rolling_folds(self, num_parts: usize, window: usize) -> TimeSeriesFoldsIter<'a, T> {
+        assert!(window > 0, "Window must be greater than 0");
+
+        TimeSeriesFoldsIter {
+            data: self,
+            parts: self.equal_parts(num_parts),
+            offset: 0,
+            window: Some(window),
+            starts: VecDeque::with_capacity(window.min(num_parts) + 1),
+            parts_seen: 0,
+        }
+    }
+}
+
+impl<'a, T> KFolds<'a, T> for &'a Vec<T> {
+    fn k_folds(self, k: usize) -> KFoldsIter<'a, T> {
+        self.as_slice().k_folds(k)
+    }
+
+    fn expanding_folds(self, num_parts: usize) -> TimeSeriesFoldsIter<'a, T> {
+        self.as_slice().expanding_folds(num_parts)
+    }
+
+    fn rolling_folds(self, num_parts: usize, window: usize) -> TimeSeriesFoldsIter<'a, T> {
+        self.as_slice().rolling_folds(num_parts, window)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KFolds;
+    use crate::EqualParts;
+
+    #[test]
+    fn every_element_is_tested_once() {
+        let data: Vec<u32> = (0..23).collect();
+        for k in 1..30 {
+            let mut tested = Vec::new();
+            for (train, test) in data.k_folds(k) {
+                assert_eq!(train.len() + test.len(), data.len());
+                let mut all: Vec<u32> = train.iter().chain(test).copied().collect();
+                all.sort_unstable();
+                assert_eq!(all, data);
+                tested.extend_from_slice(test);
+            }
+            assert_eq!(tested, data);
+            assert_eq!(data.k_folds(k).count(), k.min(23));
+        }
+    }
+
+    #[test]
+    fn train_halves_surround_the_test_part() {
+        let data = [1, 2, 3, 4];
+        let (train, test) = data.k_folds(4).nth(1).unwrap();
+        assert_eq!(test, &[2]);
+        assert_eq!(train.halves(), (&[1][..], &[3, 4][..]));
+        assert_eq!(
+            train.into_iter().copied().collect::<Vec<_>>(),
+            vec![1, 3, 4]
+        );
+    }
+
+    #[test]
+    fn single_fold_has_no_training_data() {
+        let (train, test) = [1, 2, 3].k_folds(1).next().unwrap();
+        assert!(train.is_empty());
+        assert_eq!(test, &[1, 2, 3]);
+        assert_eq!(
+            Vec::<u8>::new().k_folds(5).next().map(|(_, test)| test),
+            None
+        );
+    }
+
+    #[test]
+    fn expanding_folds_train_on_everything_before() {
+        let data: Vec<u32> = (0..17).collect();
+        for num_parts in 1..20 {
+            let parts: Vec<&[u32]> = data.equal_parts(num_parts).collect();
+            let folds: Vec<_> = data.expanding_folds(num_parts).collect();
+            assert_eq!(folds.len(), parts.len() - 1);
+            for (i, (train, test)) in folds.into_iter().enumerate() {
+                assert_eq!(train, parts[..=i].concat());
+                assert_eq!(test, parts[i + 1]);
+            }
+        }
+    }
+
+    #[test]
+    fn rolling_folds_train_on_the_window() {
+        let data: Vec<u32> = (0..17).collect();
+        for num_parts in 1..20 {
+            let parts: Vec<&[u32]> = data.equal_parts(num_parts).collect();
+            for window in 1..6 {
+                let folds: Vec<_> = data.rolling_folds(num_parts, window).collect();
+                assert_eq!(folds.len(), parts.len().saturating_sub(window));
+                for (i, (train, test)) in folds.into_iter().enumerate() {
+                    assert_eq!(train, parts[i..i + window].concat());
+                    assert_eq!(test, parts[i + window]);
+                }
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn rolling_folds_panic_with_zero_window() {
+        let _ = [1, 2, 3].rolling_folds(3, 0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_folds() {
+        let _ = [1, 2].k_folds(0);
+    }
+}
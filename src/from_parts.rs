@@ -0,0 +1,253 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::hash::{BuildHasher, Hash};
+
+/// A trait for reassembling owned parts back into a single collection.
+///
+/// This is the inverse of [`IntoEqualParts`](crate::IntoEqualParts): where that trait
+/// consumes a collection and yields owned parts, `FromParts` consumes an iterator of
+/// parts and rebuilds the collection. Ordered collections are rebuilt by concatenating
+/// the parts in the order they are yielded.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::{FromParts, IntoEqualParts};
+///
+/// let data = vec![1, 2, 3, 4, 5, 6, 7];
+/// let processed = data.into_equal_parts(3).map(|part| {
+///     part.into_iter().map(|x| x * 10).collect::<Vec<_>>()
+/// });
+///
+/// let rebuilt = Vec::try_from_equal_parts(processed, 7).unwrap();
+/// assert_eq!(rebuilt, vec![10, 20, 30, 40, 50, 60, 70]);
+/// ```
+pub trait FromParts: Sized {
+    /// The type of the parts consumed by [`from_equal_parts`](Self::from_equal_parts).
+    type Part;
+
+    /// Reassembles the collection from its parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::FromParts;
+    ///
+    /// let parts = vec![vec![1, 2, 3], vec![4, 5], vec![6, 7]];
+    /// assert_eq!(Vec::from_equal_parts(parts), vec![1, 2, 3, 4, 5, 6, 7]);
+    /// ```
+    fn from_equal_parts<I>(parts: I) -> Self
+    where
+        I: IntoIterator<Item = Self::Part>;
+
+    /// Returns the number of elements in the reassembled collection.
+    ///
+    /// For strings this is the number of chars, matching how strings are split.
+    fn element_count(&self) -> usize;
+
+    /// Reassembles the collection from its parts, verifying that it ends up with
+    /// exactly `expected_len` elements.
+    ///
+    /// This catches parts that were dropped, duplicated, or (for maps) that contain
+    /// overlapping keys, any of which would make a split→process→rebuild round-trip
+    /// asymmetrical.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`LengthMismatch`] if the rebuilt collection does not contain exactly
+    /// `expected_len` elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use equal_parts::FromParts;
+    ///
+    /// // The same key appears in two parts, so one entry is lost on reassembly.
+    /// let parts = vec![HashMap::from([(1, 'a')]), HashMap::from([(1, 'b')])];
+    /// let err = HashMap::try_from_equal_parts(parts, 2).unwrap_err();
+    /// assert_eq!(err.expected, 2);
+    /// assert_eq!(err.actual, 1);
+    /// ```
+    fn try_from_equal_parts<I>(parts: I, expected_len: usize) -> Result<Self, LengthMismatch>
+    where
+        I: IntoIterator<Item = Self::Part>,
+    {
+        let collection = Self::from_equal_parts(parts);
+        let actual = collection.element_count();
+        if actual == expected_len {
+            Ok(collection)
+        } else {
+            Err(LengthMismatch {
+                expected: expected_len,
+                actual,
+            })
+        }
+    }
+}
+
+/// Error returned by [`FromParts::try_from_equal_parts`] when the reassembled collection
+/// does not have the expected number of elements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LengthMismatch {
+    /// The number of elements the collection was expected to contain.
+    pub expected: usize,
+    /// The number of elements the reassembled collection actually contains.
+    pub actual: usize,
+}
+
+impl fmt::Display for LengthMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "reassembled collection has {} elements, expected {}",
+            self.actual, self.expected
+        )
+    }
+}
+
+impl std::error::Error for LengthMismatch {}
+
+impl<T> FromParts for Vec<T> {
+    type Part = Vec<T>;
+
+    fn from_equal_parts<I>(parts: I) -> Self
+    where
+        I: IntoIterator<Item = Self::Part>,
+    {
+        let mut parts = parts.into_iter();
+        // Reuse the first part's allocation rather than starting from scratch.
+        let mut collection = parts.next().unwrap_or_default();
+        for part in parts {
+            collection.extend(part);
+        }
+        collection
+    }
+
+    fn element_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl FromParts for String {
+    type Part = String;
+
+    fn from_equal_parts<I>(parts: I) -> Self
+    where
+        I: IntoIterator<Item = Self::Part>,
+    {
+        let mut parts = parts.into_iter();
+        let mut collection = parts.next().unwrap_or_default();
+        for part in parts {
+            collection.push_str(&part);
+        }
+        collection
+    }
+
+    fn element_count(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl<K, V, S> FromParts for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Default,
+{
+    type Part = HashMap<K, V, S>;
+
+    fn from_equal_parts<I>(parts: I) -> Self
+    where
+        I: IntoIterator<Item = Self::Part>,
+    {
+        let mut parts = parts.into_iter();
+        let mut collection = parts.next().unwrap_or_default();
+        for part in parts {
+            collection.extend(part);
+        }
+        collection
+    }
+
+    fn element_count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<K: Ord, V> FromParts for BTreeMap<K, V> {
+    type Part = BTreeMap<K, V>;
+
+    fn from_equal_parts<I>(parts: I) -> Self
+    where
+        I: IntoIterator<Item = Self::Part>,
+    {
+        let mut collection = BTreeMap::new();
+        for mut part in parts {
+            collection.append(&mut part);
+        }
+        collection
+    }
+
+    fn element_count(&self) -> usize {
+        self.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{FromParts, LengthMismatch};
+    use crate::IntoEqualParts;
+    use std::collections::{BTreeMap, HashMap};
+
+    #[test]
+    fn vec_round_trip() {
+        let data: Vec<i32> = (0..10).collect();
+        let parts = data.clone().into_equal_parts(4);
+        assert_eq!(Vec::try_from_equal_parts(parts, 10), Ok(data));
+    }
+
+    #[test]
+    fn vec_from_no_parts() {
+        let parts: Vec<Vec<i32>> = vec![];
+        assert_eq!(Vec::from_equal_parts(parts), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn vec_detects_missing_part() {
+        let data: Vec<i32> = (0..10).collect();
+        let parts = data.into_equal_parts(4).skip(1);
+        assert_eq!(
+            Vec::try_from_equal_parts(parts, 10),
+            Err(LengthMismatch {
+                expected: 10,
+                actual: 7
+            })
+        );
+    }
+
+    #[test]
+    fn string_counts_chars() {
+        let parts = vec!["héllo".to_string(), " wörld".to_string()];
+        let rebuilt = String::try_from_equal_parts(parts, 11).unwrap();
+        assert_eq!(rebuilt, "héllo wörld");
+    }
+
+    #[test]
+    fn hash_map_from_parts() {
+        let parts = vec![
+            HashMap::from([(1, 'a'), (2, 'b')]),
+            HashMap::from([(3, 'c')]),
+        ];
+        let rebuilt: HashMap<i32, char> = HashMap::try_from_equal_parts(parts, 3).unwrap();
+        assert_eq!(rebuilt, HashMap::from([(1, 'a'), (2, 'b'), (3, 'c')]));
+    }
+
+    #[test]
+    fn btree_map_detects_overlapping_keys() {
+        let parts = vec![
+            BTreeMap::from([(1, 'a'), (2, 'b')]),
+            BTreeMap::from([(2, 'c'), (3, 'd')]),
+        ];
+        let err = BTreeMap::try_from_equal_parts(parts, 4).unwrap_err();
+        assert_eq!(err.actual, 3);
+    }
+}
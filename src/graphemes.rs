@@ -0,0 +1,91 @@
+use unicode_segmentation::UnicodeSegmentation;
+
+use crate::split_count;
+use crate::text::split_str_at;
+
+/// A trait for splitting strings into parts with approximately equal numbers of
+/// user-perceived characters.
+///
+/// A single visible character can be made of several `char`s: a letter followed by
+/// combining accents, a flag made of two regional indicators, or an emoji sequence
+/// joined with zero-width joiners. Splitting at `char` boundaries can tear these
+/// apart. This trait counts and splits by extended grapheme clusters instead, as
+/// defined by Unicode Standard Annex #29.
+pub trait GraphemeParts<'a> {
+    /// Splits the string into up to `num_parts` parts with approximately equal numbers
+    /// of extended grapheme clusters, never splitting a cluster.
+    ///
+    /// Cluster counts differ by at most one, the parts with more clusters come first,
+    /// and there is one part per cluster if there are fewer clusters than
+    /// `num_parts`. Counting the clusters takes one pass over the string up front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::graphemes::GraphemeParts;
+    ///
+    /// // A family emoji (7 chars), "e" with a combining accent (2 chars), and a flag
+    /// // (2 chars) are one grapheme each.
+    /// let text = "👨‍👩‍👧‍👦e\u{301}🇯🇵ab";
+    /// let parts = text.equal_grapheme_parts(3);
+    ///
+    /// assert_eq!(parts, vec!["👨‍👩‍👧‍👦e\u{301}", "🇯🇵a", "b"]);
+    /// ```
+    fn equal_grapheme_parts(self, num_parts: usize) -> Vec<&'a str>;
+}
+
+impl<'a> GraphemeParts<'a> for &'a str {
+    fn equal_grapheme_parts(self, num_parts: usize) -> Vec<&'a str> {
+        let starts: Vec<usize> = self
+            .grapheme_indices(true)
+            .map(|(index, _)| index)
+            .collect();
+        let boundaries: Vec<usize> = split_count(starts.len(), num_parts)
+            .with_offsets()
+            .skip(1)
+            .map(|(grapheme, _)| starts[grapheme])
+            .collect();
+        split_str_at(self, &boundaries)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GraphemeParts;
+    use crate::EqualParts;
+    use unicode_segmentation::UnicodeSegmentation;
+
+    #[test]
+    fn clusters_are_never_split() {
+        let text = "a\u{308}o\u{308}u\u{308}🏳️‍🌈🇩🇪🇫🇷x".repeat(5);
+        let clusters = text.graphemes(true).count();
+        for num_parts in 1..40 {
+            let parts = text.equal_grapheme_parts(num_parts);
+            assert_eq!(parts.concat(), text);
+            let counts: Vec<usize> = parts
+                .iter()
+                .map(|part| part.graphemes(true).count())
+                .collect();
+            let expected: Vec<usize> = crate::split_count(clusters, num_parts).collect();
+            assert_eq!(counts, expected);
+        }
+    }
+
+    #[test]
+    fn ascii_matches_char_parts() {
+        let parts = "abcdefg".equal_grapheme_parts(3);
+        let expected: Vec<&str> = "abcdefg".equal_parts(3).collect();
+        assert_eq!(parts, expected);
+        assert!("".equal_grapheme_parts(2).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = "abc".equal_grapheme_parts(0);
+    }
+}
@@ -0,0 +1,505 @@
+use std::ops::Range;
+
+use crate::aligned::{AlignedEqualParts, AlignedParts};
+use crate::full_part_count;
+use crate::split_count;
+
+/// Splits a row-major matrix into approximately equal vertical bands of columns.
+///
+/// `data` is interpreted as a matrix whose rows are `row_len` elements long. The
+/// columns are divided into `num_parts` contiguous bands following the same
+/// largest-first rule as [`equal_parts`](crate::EqualParts::equal_parts). Since the
+/// columns of a band are not contiguous in memory, each band is yielded as a
+/// [`ColumnBand`] view that iterates over its segment of every row.
+///
+/// # Panics
+///
+/// Panics if `num_parts` or `row_len` is 0, or if the length of `data` is not a
+/// multiple of `row_len`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::grid::column_bands;
+///
+/// // A 2x5 matrix.
+/// let data = [
+///     1, 2, 3, 4, 5,
+///     6, 7, 8, 9, 10,
+/// ];
+/// let mut bands = column_bands(&data, 5, 2);
+///
+/// let left = bands.next().unwrap();
+/// assert_eq!(left.columns(), 0..3);
+/// assert_eq!(left.rows().collect::<Vec<_>>(), vec![&[1, 2, 3], &[6, 7, 8]]);
+///
+/// let right = bands.next().unwrap();
+/// assert_eq!(right.columns(), 3..5);
+/// assert_eq!(right.rows().collect::<Vec<_>>(), vec![&[4, 5], &[9, 10]]);
+///
+/// assert!(bands.next().is_none());
+/// ```
+pub fn column_bands<T>(data: &[T], row_len: usize, num_parts: usize) -> ColumnBands<'_, T> {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+    assert!(row_len > 0, "Row length must be greater than 0");
+    assert!(
+        data.len().is_multiple_of(row_len),
+        "Data length must be a multiple of the row length"
+    );
+
+    let part_size = row_len.div_ceil(num_parts);
+    ColumnBands {
+        data,
+        row_len,
+        next_column: 0,
+        part_size,
+        full_parts_left: full_part_count(row_len, num_parts),
+    }
+}
+
+/// Iterator over the column bands of a row-major matrix.
+///
+/// This iterator is created by [`column_bands`].
+#[derive(Debug, Clone)]
+pub struct ColumnBands<'a, T> {
+    data: &'a [T],
+    row_len: usize,
+    next_column: usize,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<'a, T> Iterator for ColumnBands<'a, T> {
+    type Item = ColumnBand<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_column == self.row_len {
+            return None;
+        }
+
+        let width = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let columns = self.next_column..self.next_column + width;
+        self.next_column = columns.end;
+        Some(ColumnBand {
+            data: self.data,
+            row_len: self.row_len,
+            columns,
+        })
+    }
+}
+
+/// A strided view of a vertical band of columns in a row-major matrix.
+///
+/// Created by [`column_bands`], and by [`tile_views`] for the rows of a single tile.
+#[derive(Debug, Clone)]
+pub struct ColumnBand<'a, T> {
+    data: &'a [T],
+    row_len: usize,
+    columns: Range<usize>,
+}
+
+impl<'a, T> ColumnBand<'a, T> {
+    /// Returns the range of matrix columns covered by this band.
+    pub fn columns(&self) -> Range<usize> {
+        self.columns.clone()
+    }
+
+    /// Returns the number of columns in this band.
+    pub fn width(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns the number of rows in this band, which is the number of rows in the
+    /// matrix.
+    pub fn height(&self) -> usize {
+        self.data.len() / self.row_len
+    }
+
+    /// Returns an iterator over this band's segment of each row, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + use<'a, T> {
+        let columns = self.columns.clone();
+        self.data
+            .chunks_exact(self.row_len)
+            .map(move |row| &row[columns.clone()])
+    }
+
+    /// Returns an iterator over the elements of column `index` of this band, from top
+    /// to bottom. The index is relative to the start of the band.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is not less than the band's width.
+    pub fn column(&self, index: usize) -> impl Iterator<Item = &'a T> + use<'a, T> {
+        assert!(index < self.width(), "Column index out of range");
+        // An empty matrix still has columns, but no elements to start from.
+        let start = (self.columns.start + index).min(self.data.len());
+        self.data[start..].iter().step_by(self.row_len)
+    }
+}
+
+/// A rectangular block of a 2D index space.
+///
+/// Created by [`tiles`] and [`tile_views`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Tile {
+    /// The rows covered by the tile.
+    pub rows: Range<usize>,
+    /// The columns covered by the tile.
+    pub cols: Range<usize>,
+}
+
+impl Tile {
+    /// Returns the number of rows in the tile.
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// Returns the number of columns in the tile.
+    pub fn width(&self) -> usize {
+        self.cols.len()
+    }
+
+    /// Returns the number of cells in the tile.
+    pub fn len(&self) -> usize {
+        self.height() * self.width()
+    }
+
+    /// Returns `true` if the tile has no cells.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Splits a `rows × cols` index space into a grid of up to `row_parts × col_parts`
+/// tiles.
+///
+/// The rows are divided into `row_parts` bands and the columns into `col_parts`
+/// bands, each following the same largest-first rule as
+/// [`equal_parts`](crate::EqualParts::equal_parts), so tile heights differ by at most
+/// one row and tile widths by at most one column. If there are fewer rows than
+/// `row_parts` or fewer columns than `col_parts`, there is one band per row or
+/// column, and an index space with no rows or no columns has no tiles. The tiles are
+/// yielded in row-major order: all tiles of the first row band from left to right,
+/// then the next row band.
+///
+/// # Panics
+///
+/// Panics if `row_parts` or `col_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::grid::{tiles, Tile};
+///
+/// let grid: Vec<Tile> = tiles(5, 7, 2, 3).collect();
+///
+/// assert_eq!(grid.len(), 6);
+/// assert_eq!(grid[0], Tile { rows: 0..3, cols: 0..3 });
+/// assert_eq!(grid[2], Tile { rows: 0..3, cols: 5..7 });
+/// assert_eq!(grid[5], Tile { rows: 3..5, cols: 5..7 });
+/// ```
+pub fn tiles(rows: usize, cols: usize, row_parts: usize, col_parts: usize) -> Tiles {
+    let bands = |len, num_parts| -> Vec<Range<usize>> {
+        split_count(len, num_parts)
+            .with_offsets()
+            .map(|(start, count)| start..start + count)
+            .collect()
+    };
+    Tiles {
+        row_bands: bands(rows, row_parts),
+        col_bands: bands(cols, col_parts),
+        next: 0,
+    }
+}
+
+/// Iterator over the tiles of a 2D index space.
+///
+/// This iterator is created by [`tiles`].
+#[derive(Debug, Clone)]
+pub struct Tiles {
+    row_bands: Vec<Range<usize>>,
+    col_bands: Vec<Range<usize>>,
+    next: usize,
+}
+
+impl Iterator for Tiles {
+    type Item = Tile;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.col_bands.is_empty() {
+            return None;
+        }
+        let rows = self
+            .row_bands
+            .get(self.next / self.col_bands.len())?
+            .clone();
+        let cols = self.col_bands[self.next % self.col_bands.len()].clone();
+        self.next += 1;
+        Some(Tile { rows, cols })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.row_bands.len() * self.col_bands.len() - self.next;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Tiles {}
+
+/// Splits a row-major matrix into a grid of up to `row_parts × col_parts` tiles,
+/// yielding each tile with a view of its elements.
+///
+/// `data` is interpreted as a matrix whose rows are `row_len` elements long, and the
+/// tiles are those of [`tiles`] for its dimensions. A tile's elements are not
+/// contiguous in memory, so each view is a [`ColumnBand`] covering the tile's columns
+/// in the tile's rows only.
+///
+/// # Panics
+///
+/// Panics if `row_parts`, `col_parts` or `row_len` is 0, or if the length of `data` is
+/// not a multiple of `row_len`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::grid::tile_views;
+///
+/// // A 4x4 matrix split into 2x2 tiles.
+/// let data: Vec<u32> = (0..16).collect();
+/// let sums: Vec<u32> = tile_views(&data, 4, 2, 2)
+///     .map(|(_, view)| view.rows().flatten().sum())
+///     .collect();
+///
+/// assert_eq!(sums, vec![0 + 1 + 4 + 5, 2 + 3 + 6 + 7, 8 + 9 + 12 + 13, 10 + 11 + 14 + 15]);
+/// ```
+pub fn tile_views<T>(
+    data: &[T],
+    row_len: usize,
+    row_parts: usize,
+    col_parts: usize,
+) -> TileViews<'_, T> {
+    assert!(row_len > 0, "Row length must be greater than 0");
+    assert!(
+        data.len().is_multiple_of(row_len),
+        "Data length must be a multiple of the row length"
+    );
+
+    TileViews {
+        data,
+        row_len,
+        tiles: tiles(data.len() / row_len, row_len, row_parts, col_parts),
+    }
+}
+
+/// Iterator over the tiles of a row-major matrix and views of their elements.
+///
+/// This iterator is created by [`tile_views`].
+#[derive(Debug, Clone)]
+pub struct TileViews<'a, T> {
+    data: &'a [T],
+    row_len: usize,
+    tiles: Tiles,
+}
+
+impl<'a, T> Iterator for TileViews<'a, T> {
+    type Item = (Tile, ColumnBand<'a, T>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let tile = self.tiles.next()?;
+        let view = ColumnBand {
+            data: &self.data[tile.rows.start * self.row_len..tile.rows.end * self.row_len],
+            row_len: self.row_len,
+            columns: tile.cols.clone(),
+        };
+        Some((tile, view))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.tiles.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for TileViews<'_, T> {}
+
+/// A trait for splitting row-major matrices into approximately equal bands of whole
+/// rows.
+pub trait RowParts<'a, T> {
+    /// Splits a row-major matrix into up to `num_parts` contiguous parts of whole rows.
+    ///
+    /// `self` is interpreted as a matrix whose rows are `row_len` elements long. The
+    /// rows are divided following the same largest-first rule as
+    /// [`equal_parts`](crate::EqualParts::equal_parts), so the row counts of any two
+    /// parts differ by at most one and no row is ever cut in two. If there are fewer
+    /// rows than `num_parts`, each row is its own part. Unlike [`column_bands`], the
+    /// parts are contiguous, so they are plain slices.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` or `row_len` is 0, or if the length of the slice is not a
+    /// multiple of `row_len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::grid::RowParts;
+    ///
+    /// // A 5x3 matrix.
+    /// let data: Vec<u32> = (0..15).collect();
+    /// let parts: Vec<&[u32]> = data.equal_row_parts(2, 3).collect();
+    ///
+    /// assert_eq!(parts, vec![&data[..9], &data[9..]]);
+    /// ```
+    fn equal_row_parts(self, num_parts: usize, row_len: usize) -> AlignedParts<'a, T>;
+}
+
+impl<'a, T> RowParts<'a, T> for &'a [T] {
+    fn equal_row_parts(self, num_parts: usize, row_len: usize) -> AlignedParts<'a, T> {
+        assert!(row_len > 0, "Row length must be greater than 0");
+        assert!(
+            self.len().is_multiple_of(row_len),
+            "Data length must be a multiple of the row length"
+        );
+
+        self.equal_parts_aligned(num_parts, row_len)
+    }
+}
+
+impl<'a, T> RowParts<'a, T> for &'a Vec<T> {
+    fn equal_row_parts(self, num_parts: usize, row_len: usize) -> AlignedParts<'a, T> {
+        self.as_slice().equal_row_parts(num_parts, row_len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{RowParts, column_bands, tile_views, tiles};
+
+    #[test]
+    fn uneven_bands() {
+        let data: Vec<i32> = (0..14).collect();
+        let widths: Vec<usize> = column_bands(&data, 7, 3).map(|band| band.width()).collect();
+        assert_eq!(widths, vec![3, 2, 2]);
+    }
+
+    #[test]
+    fn band_columns() {
+        let data: Vec<i32> = (0..12).collect();
+        let bands: Vec<_> = column_bands(&data, 4, 2).collect();
+        assert_eq!(bands[1].height(), 3);
+        assert_eq!(
+            bands[1].column(0).copied().collect::<Vec<_>>(),
+            vec![2, 6, 10]
+        );
+        assert_eq!(
+            bands[1].column(1).copied().collect::<Vec<_>>(),
+            vec![3, 7, 11]
+        );
+    }
+
+    #[test]
+    fn more_parts_than_columns() {
+        let data = [1, 2, 3, 4];
+        let bands: Vec<_> = column_bands(&data, 2, 5).collect();
+        assert_eq!(bands.len(), 2);
+        assert_eq!(bands[0].rows().collect::<Vec<_>>(), vec![&[1], &[3]]);
+        assert_eq!(bands[1].rows().collect::<Vec<_>>(), vec![&[2], &[4]]);
+    }
+
+    #[test]
+    fn empty_matrix_has_bands_of_no_rows() {
+        let data: [i32; 0] = [];
+        let bands: Vec<_> = column_bands(&data, 3, 3).collect();
+        assert_eq!(bands.len(), 3);
+        assert!(bands.iter().all(|band| band.rows().next().is_none()));
+        assert!(bands[2].column(0).next().is_none());
+    }
+
+    #[test]
+    fn tiles_cover_the_space_once() {
+        for (rows, cols) in [(5, 7), (1, 9), (13, 2), (4, 4)] {
+            for row_parts in 1..6 {
+                for col_parts in 1..6 {
+                    let grid: Vec<_> = tiles(rows, cols, row_parts, col_parts).collect();
+                    assert_eq!(grid.len(), row_parts.min(rows) * col_parts.min(cols));
+
+                    let mut seen = vec![0; rows * cols];
+                    for tile in &grid {
+                        assert!(!tile.is_empty());
+                        for row in tile.rows.clone() {
+                            for col in tile.cols.clone() {
+                                seen[row * cols + col] += 1;
+                            }
+                        }
+                    }
+                    assert!(seen.iter().all(|&count| count == 1));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn tiles_of_empty_space() {
+        assert_eq!(tiles(0, 5, 2, 2).next(), None);
+        assert_eq!(tiles(5, 0, 2, 2).len(), 0);
+    }
+
+    #[test]
+    fn tile_views_hold_tile_elements() {
+        let data: Vec<usize> = (0..35).collect();
+        for (tile, view) in tile_views(&data, 7, 2, 3) {
+            assert_eq!(view.height(), tile.height());
+            assert_eq!(view.width(), tile.width());
+            let expected: Vec<usize> = tile
+                .rows
+                .clone()
+                .flat_map(|row| tile.cols.clone().map(move |col| row * 7 + col))
+                .collect();
+            assert_eq!(view.rows().flatten().copied().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn row_parts_hold_whole_balanced_rows() {
+        let data: Vec<usize> = (0..7 * 4).collect();
+        for num_parts in 1..10 {
+            let parts: Vec<&[usize]> = data.equal_row_parts(num_parts, 4).collect();
+            assert_eq!(parts.len(), num_parts.min(7));
+            assert_eq!(parts.concat(), data);
+
+            let rows: Vec<usize> = parts.iter().map(|part| part.len() / 4).collect();
+            assert!(parts.iter().all(|part| part.len() % 4 == 0));
+            assert!(rows.windows(2).all(|pair| pair[0] >= pair[1]));
+            assert!(rows[0] - rows[rows.len() - 1] <= 1);
+        }
+        assert_eq!(Vec::<u8>::new().equal_row_parts(3, 4).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn row_parts_panic_on_ragged_data() {
+        let data = [0u8; 10];
+        let _ = data.as_slice().equal_row_parts(2, 4);
+    }
+
+    #[test]
+    #[should_panic]
+    fn tiles_panic_with_zero_parts() {
+        let _ = tiles(4, 4, 0, 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_ragged_data() {
+        let data = [1, 2, 3];
+        let _ = column_bands(&data, 2, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let data = [1, 2, 3, 4];
+        let _ = column_bands(&data, 2, 0);
+    }
+}
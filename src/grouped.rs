@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::weighted::lpt_assignments;
+
+/// A trait for splitting collections into balanced parts without separating
+/// elements that share a key.
+///
+/// Some data can only be processed correctly when related elements stay together,
+/// such as all of a customer's records or all of a session's events. The methods of
+/// this trait take a `key` function and treat every set of elements with equal keys
+/// as a single indivisible group. The groups are then spread over the parts so that
+/// the numbers of elements per part are as balanced as the group sizes allow.
+///
+/// The elements of a group do not have to be next to each other. If the data is
+/// already sorted by key and contiguous parts are needed, split it with
+/// [`equal_parts_split_where`](crate::boundary::SplitWhere::equal_parts_split_where)
+/// instead, allowing splits only between different keys.
+pub trait GroupedEqualParts<'a, T> {
+    /// Assigns every group of elements with equal keys to one of up to `num_parts`
+    /// parts, and returns the element indices of each part.
+    ///
+    /// Groups are placed largest first onto the part with the fewest elements so far
+    /// (the Longest-Processing-Time-first rule), so a part is never more than 4/3 as
+    /// large as the best possible largest part. There are `num_parts` parts, or one
+    /// per group if there are fewer groups than that, and no part is empty. The
+    /// indices in each part are in ascending order, and equally sized groups are
+    /// placed in the order their keys first appear, so the result is deterministic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::grouped::GroupedEqualParts;
+    ///
+    /// let customers = ["ann", "bob", "ann", "cy", "bob", "ann", "dee"];
+    /// let parts = customers.grouped_assignments(2, |&name| name);
+    ///
+    /// assert_eq!(parts, vec![vec![0, 2, 5, 6], vec![1, 3, 4]]);
+    /// ```
+    fn grouped_assignments<K, F>(self, num_parts: usize, key: F) -> Vec<Vec<usize>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&T) -> K;
+
+    /// Splits the collection into up to `num_parts` balanced parts, never placing
+    /// elements with equal keys in different parts.
+    ///
+    /// This is [`grouped_assignments`](GroupedEqualParts::grouped_assignments) with
+    /// each index replaced by a reference to its element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::grouped::GroupedEqualParts;
+    ///
+    /// // (customer id, amount)
+    /// let rows = [(7, 10), (3, 5), (7, 2), (9, 1), (3, 8), (5, 4)];
+    /// let parts = rows.equal_parts_grouped_by(3, |&(customer, _)| customer);
+    ///
+    /// assert_eq!(parts.len(), 3);
+    /// for part in &parts {
+    ///     for (customer, _) in part.iter() {
+    ///         // Every row of a customer is in the same part.
+    ///         let part_rows = part.iter().filter(|(c, _)| c == customer).count();
+    ///         let all_rows = rows.iter().filter(|(c, _)| c == customer).count();
+    ///         assert_eq!(part_rows, all_rows);
+    ///     }
+    /// }
+    /// ```
+    fn equal_parts_grouped_by<K, F>(self, num_parts: usize, key: F) -> Vec<Vec<&'a T>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&T) -> K;
+}
+
+impl<'a, T> GroupedEqualParts<'a, T> for &'a [T] {
+    fn grouped_assignments<K, F>(self, num_parts: usize, mut key: F) -> Vec<Vec<usize>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&T) -> K,
+    {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        let mut group_of: HashMap<K, usize> = HashMap::new();
+        for (index, item) in self.iter().enumerate() {
+            let group = *group_of.entry(key(item)).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[group].push(index);
+        }
+
+        let sizes: Vec<u64> = groups.iter().map(|group| group.len() as u64).collect();
+        let mut parts: Vec<Vec<usize>> = lpt_assignments(&sizes, num_parts.min(groups.len()))
+            .into_iter()
+            .map(|part| {
+                part.into_iter()
+                    .flat_map(|group| std::mem::take(&mut groups[group]))
+                    .collect()
+            })
+            .collect();
+        for part in &mut parts {
+            part.sort_unstable();
+        }
+        parts
+    }
+
+    fn equal_parts_grouped_by<K, F>(self, num_parts: usize, key: F) -> Vec<Vec<&'a T>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&T) -> K,
+    {
+        self.grouped_assignments(num_parts, key)
+            .into_iter()
+            .map(|indices| indices.into_iter().map(|index| &self[index]).collect())
+            .collect()
+    }
+}
+
+impl<'a, T> GroupedEqualParts<'a, T> for &'a Vec<T> {
+    fn grouped_assignments<K, F>(self, num_parts: usize, key: F) -> Vec<Vec<usize>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&T) -> K,
+    {
+        self.as_slice().grouped_assignments(num_parts, key)
+    }
+
+    fn equal_parts_grouped_by<K, F>(self, num_parts: usize, key: F) -> Vec<Vec<&'a T>>
+    where
+        K: Eq + Hash,
+        F: FnMut(&T) -> K,
+    {
+        self.as_slice().equal_parts_grouped_by(num_parts, key)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GroupedEqualParts;
+
+    #[test]
+    fn groups_are_never_split() {
+        let keys: Vec<u32> = (0..300u32).map(|i| i * i % 41).collect();
+        for num_parts in 1..12 {
+            let parts = keys.grouped_assignments(num_parts, |&key| key);
+            assert_eq!(parts.len(), num_parts);
+            assert!(parts.iter().all(|part| !part.is_empty()));
+
+            for key in 0..41 {
+                let holding = parts
+                    .iter()
+                    .filter(|part| part.iter().any(|&i| keys[i] == key))
+                    .count();
+                assert!(holding <= 1);
+            }
+
+            let mut all = parts.concat();
+            all.sort_unstable();
+            assert_eq!(all, (0..300).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn unique_keys_balance_like_equal_parts() {
+        let data: Vec<u32> = (0..10).collect();
+        let lens: Vec<usize> = data
+            .equal_parts_grouped_by(4, |&x| x)
+            .iter()
+            .map(Vec::len)
+            .collect();
+        assert_eq!(lens, vec![3, 3, 2, 2]);
+    }
+
+    #[test]
+    fn fewer_groups_than_parts() {
+        let data = [1, 1, 2, 2, 1];
+        let parts = data.grouped_assignments(5, |&x| x);
+        assert_eq!(parts, vec![vec![0, 1, 4], vec![2, 3]]);
+        assert!(Vec::<u8>::new().grouped_assignments(3, |&x| x).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = [1].grouped_assignments(0, |&x| x);
+    }
+}
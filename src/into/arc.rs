@@ -0,0 +1,167 @@
+use std::fmt;
+use std::ops::{Deref, Range};
+use std::sync::Arc;
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+
+/// An owned part of an `Arc<[T]>`: a clone of the `Arc` plus the range it covers.
+///
+/// Creating a part copies no elements, and a part is `'static` and `Send` whenever `T`
+/// is `Send + Sync`, so parts can be moved into spawned threads or async tasks. The
+/// shared slice is freed once the last part referring to it is dropped.
+///
+/// `ArcPart` dereferences to the `&[T]` it covers.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+/// use std::thread;
+///
+/// use equal_parts::IntoEqualParts;
+///
+/// let data: Arc<[u64]> = (1..=100).collect();
+/// let handles: Vec<_> = data
+///     .into_equal_parts(4)
+///     .map(|part| thread::spawn(move || part.iter().sum::<u64>()))
+///     .collect();
+///
+/// let total: u64 = handles.into_iter().map(|handle| handle.join().unwrap()).sum();
+/// assert_eq!(total, 5050);
+/// ```
+#[derive(Clone)]
+pub struct ArcPart<T> {
+    data: Arc<[T]>,
+    range: Range<usize>,
+}
+
+impl<T> ArcPart<T> {
+    /// Returns the range of the shared slice that this part covers.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the whole shared slice this part was taken from.
+    pub fn shared(&self) -> &Arc<[T]> {
+        &self.data
+    }
+}
+
+impl<T> Deref for ArcPart<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        &self.data[self.range.clone()]
+    }
+}
+
+impl<T> AsRef<[T]> for ArcPart<T> {
+    fn as_ref(&self) -> &[T] {
+        self
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for ArcPart<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcPart")
+            .field("range", &self.range)
+            .field("elements", &&**self)
+            .finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for ArcPart<T> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Eq> Eq for ArcPart<T> {}
+
+/// Iterator that yields approximately equal owned parts of an `Arc<[T]>`.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on an `Arc<[T]>`. Each part is an [`ArcPart`] sharing the original allocation.
+#[derive(Debug, Clone)]
+pub struct IntoArcPartsIter<T> {
+    data: Arc<[T]>,
+    start: usize,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<T> Iterator for IntoArcPartsIter<T> {
+    type Item = ArcPart<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.data.len() {
+            return None;
+        }
+
+        let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let range = self.start..self.start + chunk_size;
+        self.start = range.end;
+        Some(ArcPart {
+            data: Arc::clone(&self.data),
+            range,
+        })
+    }
+}
+
+impl<T> IntoEqualParts for Arc<[T]> {
+    type Item = ArcPart<T>;
+    type IntoIter = IntoArcPartsIter<T>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+
+        IntoArcPartsIter {
+            data: self,
+            start: 0,
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntoEqualParts;
+    use std::sync::Arc;
+
+    #[test]
+    fn into_arc_parts_share_data() {
+        let data: Arc<[i32]> = Arc::from(vec![1, 2, 3, 4, 5, 6, 7]);
+        let parts: Vec<_> = Arc::clone(&data).into_equal_parts(3).collect();
+        assert_eq!(Arc::strong_count(&data), 4);
+
+        assert_eq!(&*parts[0], &[1, 2, 3]);
+        assert_eq!(&*parts[1], &[4, 5]);
+        assert_eq!(parts[2].range(), 5..7);
+        assert!(Arc::ptr_eq(parts[2].shared(), &data));
+
+        drop(parts);
+        assert_eq!(Arc::strong_count(&data), 1);
+    }
+
+    #[test]
+    fn into_arc_not_enough_parts() {
+        let data: Arc<[u8]> = Arc::from([1, 2]);
+        assert_eq!(data.into_equal_parts(3).count(), 2);
+        let empty: Arc<[u8]> = Arc::from([]);
+        assert_eq!(empty.into_equal_parts(3).next(), None);
+    }
+
+    #[test]
+    fn into_zero_sized_arc_near_max_len() {
+        let data: Arc<[()]> = Arc::from(vec![(); usize::MAX]);
+        let lens: Vec<usize> = data.into_equal_parts(2).map(|part| part.len()).collect();
+        assert_eq!(lens, vec![usize::MAX / 2 + 1, usize::MAX / 2]);
+    }
+}
@@ -0,0 +1,100 @@
+use std::array;
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+
+/// Iterator that yields approximately equal owned parts of an array.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on an array by value. Elements are moved straight out of the array into each
+/// `Vec<T>` part, without first collecting the array into a `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::IntoEqualParts;
+///
+/// let data = [String::from("a"), String::from("b"), String::from("c")];
+/// let parts: Vec<Vec<String>> = data.into_equal_parts(2).collect();
+/// assert_eq!(parts, vec![vec!["a", "b"], vec!["c"]]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoArrayPartsIter<T, const N: usize> {
+    data: array::IntoIter<T, N>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<T, const N: usize> Iterator for IntoArrayPartsIter<T, N> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() == 0 {
+            return None;
+        }
+
+        let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let mut part = Vec::with_capacity(chunk_size);
+        part.extend(self.data.by_ref().take(chunk_size));
+        Some(part)
+    }
+}
+
+impl<T, const N: usize> IntoEqualParts for [T; N] {
+    type Item = Vec<T>;
+    type IntoIter = IntoArrayPartsIter<T, N>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = N.div_ceil(num_parts);
+
+        IntoArrayPartsIter {
+            data: self.into_iter(),
+            part_size,
+            full_parts_left: full_part_count(N, num_parts),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntoEqualParts;
+
+    #[test]
+    fn into_array_parts() {
+        let parts: Vec<Vec<i32>> = [1, 2, 3, 4, 5, 6, 7].into_equal_parts(3).collect();
+        assert_eq!(parts, vec![vec![1, 2, 3], vec![4, 5], vec![6, 7]]);
+    }
+
+    #[test]
+    fn into_array_not_enough_parts() {
+        let parts: Vec<Vec<u8>> = [1, 2].into_equal_parts(4).collect();
+        assert_eq!(parts, vec![vec![1], vec![2]]);
+    }
+
+    #[test]
+    fn into_empty_array() {
+        let data: [u8; 0] = [];
+        assert_eq!(data.into_equal_parts(2).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn into_array_panics_with_zero_parts() {
+        let _ = [1, 2, 3].into_equal_parts(0);
+    }
+
+    #[test]
+    fn into_zero_sized_array_near_max_len() {
+        // Only the plan is checked, since moving the parts element by element would
+        // take too long.
+        let parts = [(); usize::MAX].into_equal_parts(2);
+        assert_eq!(
+            (parts.part_size, parts.full_parts_left),
+            (usize::MAX / 2 + 1, 1)
+        );
+    }
+}
@@ -0,0 +1,200 @@
+use arrayvec::ArrayVec;
+
+use crate::EqualPartsError;
+use crate::error::CapacityExceeded;
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+use crate::{EqualParts, EqualPartsIter};
+
+/// Iterator that yields approximately equal owned parts of an `ArrayVec`.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on an [`ArrayVec`]. Each part is an `ArrayVec` with the same capacity as the
+/// original. No part can be longer than the original, so every part is guaranteed to
+/// fit, and nothing is ever allocated on the heap.
+///
+/// When created by [`into_equal_parts_cap`](IntoArrayVecCapParts::into_equal_parts_cap),
+/// the parts instead have capacity `P`, which was checked to fit the largest part.
+///
+/// # Examples
+///
+/// ```
+/// use arrayvec::ArrayVec;
+/// use equal_parts::IntoEqualParts;
+///
+/// let data: ArrayVec<u16, 8> = (1..=7).collect();
+/// let parts: Vec<ArrayVec<u16, 8>> = data.into_equal_parts(3).collect();
+///
+/// assert_eq!(parts[0].as_slice(), &[1, 2, 3]);
+/// assert_eq!(parts[1].as_slice(), &[4, 5]);
+/// assert_eq!(parts[2].as_slice(), &[6, 7]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoArrayVecPartsIter<T, const CAP: usize, const P: usize = CAP> {
+    data: arrayvec::IntoIter<T, CAP>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<T, const CAP: usize, const P: usize> Iterator for IntoArrayVecPartsIter<T, CAP, P> {
+    type Item = ArrayVec<T, P>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() == 0 {
+            None
+        } else {
+            let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+            self.full_parts_left -= self.full_parts_left.min(1);
+            Some(self.data.by_ref().take(chunk_size).collect())
+        }
+    }
+}
+
+impl<T, const CAP: usize> IntoEqualParts for ArrayVec<T, CAP> {
+    type Item = ArrayVec<T, CAP>;
+    type IntoIter = IntoArrayVecPartsIter<T, CAP>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+        IntoArrayVecPartsIter {
+            data: self.into_iter(),
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+/// A trait for splitting an [`ArrayVec`] into parts with a smaller capacity.
+///
+/// Parts from [`into_equal_parts`](IntoEqualParts::into_equal_parts) keep the capacity of
+/// the original, so splitting an `ArrayVec<T, 1024>` into 16 parts yields 16 buffers of
+/// 1024 slots each. Choosing the part capacity keeps the parts as small as they need to
+/// be.
+pub trait IntoArrayVecCapParts<T, const CAP: usize> {
+    /// Splits the `ArrayVec` into approximately equal parts, each an `ArrayVec` of
+    /// capacity `P`.
+    ///
+    /// The parts are the same as those of
+    /// [`into_equal_parts`](IntoEqualParts::into_equal_parts).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EqualPartsError::CapacityExceeded`] if the largest part has more than
+    /// `P` elements, and [`EqualPartsError::ZeroParts`] if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayvec::ArrayVec;
+    /// use equal_parts::EqualPartsError;
+    /// use equal_parts::into::arrayvec::IntoArrayVecCapParts;
+    ///
+    /// let data: ArrayVec<u16, 64> = (1..=7).collect();
+    /// let parts: Vec<ArrayVec<u16, 3>> = data.clone().into_equal_parts_cap::<3>(3).unwrap().collect();
+    /// assert_eq!(parts[0].as_slice(), &[1, 2, 3]);
+    /// assert_eq!(parts[2].as_slice(), &[6, 7]);
+    ///
+    /// let err = data.into_equal_parts_cap::<2>(3).unwrap_err();
+    /// assert!(matches!(err, EqualPartsError::CapacityExceeded(_)));
+    /// ```
+    fn into_equal_parts_cap<const P: usize>(
+        self,
+        num_parts: usize,
+    ) -> Result<IntoArrayVecPartsIter<T, CAP, P>, EqualPartsError>;
+}
+
+impl<T, const CAP: usize> IntoArrayVecCapParts<T, CAP> for ArrayVec<T, CAP> {
+    fn into_equal_parts_cap<const P: usize>(
+        self,
+        num_parts: usize,
+    ) -> Result<IntoArrayVecPartsIter<T, CAP, P>, EqualPartsError> {
+        if num_parts == 0 {
+            return Err(EqualPartsError::ZeroParts);
+        }
+
+        let part_size = self.len().div_ceil(num_parts);
+        if part_size > P {
+            return Err(CapacityExceeded {
+                part_size,
+                capacity: P,
+            }
+            .into());
+        }
+        let full_parts_left = full_part_count(self.len(), num_parts);
+        Ok(IntoArrayVecPartsIter {
+            data: self.into_iter(),
+            part_size,
+            full_parts_left,
+        })
+    }
+}
+
+impl<'a, T, const CAP: usize> EqualParts for &'a ArrayVec<T, CAP> {
+    type Item = &'a [T];
+    type Iter = EqualPartsIter<'a, T>;
+
+    fn equal_parts(self, num_parts: usize) -> Self::Iter {
+        self.as_slice().equal_parts(num_parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use arrayvec::ArrayVec;
+
+    use super::IntoArrayVecCapParts;
+    use crate::error::CapacityExceeded;
+    use crate::{EqualParts, EqualPartsError, IntoEqualParts};
+
+    #[test]
+    fn into_arrayvec_parts() {
+        let data: ArrayVec<i32, 5> = (1..=5).collect();
+        let parts: Vec<Vec<i32>> = data.into_equal_parts(2).map(|part| part.to_vec()).collect();
+        assert_eq!(parts, vec![vec![1, 2, 3], vec![4, 5]]);
+    }
+
+    #[test]
+    fn borrowed_arrayvec_parts() {
+        let data: ArrayVec<i32, 4> = (1..=4).collect();
+        let parts: Vec<&[i32]> = (&data).equal_parts(2).collect();
+        assert_eq!(parts, vec![&[1, 2][..], &[3, 4]]);
+    }
+
+    #[test]
+    fn into_empty_arrayvec() {
+        let data: ArrayVec<u8, 0> = ArrayVec::new();
+        assert!(data.into_equal_parts(3).next().is_none());
+    }
+
+    #[test]
+    fn into_smaller_capacity_parts() {
+        let data: ArrayVec<i32, 100> = (1..=10).collect();
+        let parts: Vec<ArrayVec<i32, 4>> = data.into_equal_parts_cap::<4>(3).unwrap().collect();
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0].as_slice(), &[1, 2, 3, 4]);
+        assert_eq!(parts[1].as_slice(), &[5, 6, 7]);
+        assert_eq!(parts[2].as_slice(), &[8, 9, 10]);
+
+        let empty: ArrayVec<i32, 8> = ArrayVec::new();
+        assert!(empty.into_equal_parts_cap::<0>(2).unwrap().next().is_none());
+    }
+
+    #[test]
+    fn capacity_too_small_is_an_error() {
+        let data: ArrayVec<u8, 16> = (0..10).collect();
+        assert_eq!(
+            data.clone().into_equal_parts_cap::<3>(3).err(),
+            Some(EqualPartsError::CapacityExceeded(CapacityExceeded {
+                part_size: 4,
+                capacity: 3
+            }))
+        );
+        assert_eq!(
+            data.into_equal_parts_cap::<8>(0).err(),
+            Some(EqualPartsError::ZeroParts)
+        );
+    }
+}
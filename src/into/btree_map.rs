@@ -0,0 +1,96 @@
+use std::collections::BTreeMap;
+use std::mem;
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+
+/// Iterator that yields approximately equal owned parts of a BTreeMap.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a BTreeMap. Each part is itself a `BTreeMap` covering a contiguous range of keys,
+/// and the parts come in ascending key order. Parts are cut off with
+/// [`BTreeMap::split_off`] at each boundary key, which is why the keys must be `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeMap;
+///
+/// use equal_parts::IntoEqualParts;
+///
+/// let data: BTreeMap<u32, char> = (0..5).zip('a'..).collect();
+/// let parts: Vec<BTreeMap<u32, char>> = data.into_equal_parts(2).collect();
+///
+/// assert_eq!(parts[0], BTreeMap::from([(0, 'a'), (1, 'b'), (2, 'c')]));
+/// assert_eq!(parts[1], BTreeMap::from([(3, 'd'), (4, 'e')]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoBTreeMapPartsIter<K, V> {
+    data: BTreeMap<K, V>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<K: Ord + Clone, V> Iterator for IntoBTreeMapPartsIter<K, V> {
+    type Item = BTreeMap<K, V>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let Some(boundary) = self.data.keys().nth(chunk_size).cloned() else {
+            return Some(mem::take(&mut self.data));
+        };
+        let rest = self.data.split_off(&boundary);
+        Some(mem::replace(&mut self.data, rest))
+    }
+}
+
+impl<K: Ord + Clone, V> IntoEqualParts for BTreeMap<K, V> {
+    type Item = BTreeMap<K, V>;
+    type IntoIter = IntoBTreeMapPartsIter<K, V>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+
+        IntoBTreeMapPartsIter {
+            data: self,
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntoEqualParts;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn into_btree_map_contiguous_ranges() {
+        let data: BTreeMap<String, usize> = (0..10).map(|i| (format!("key{i:02}"), i)).collect();
+        let parts: Vec<BTreeMap<String, usize>> = data.into_equal_parts(4).collect();
+        let values: Vec<Vec<usize>> = parts
+            .iter()
+            .map(|part| part.values().copied().collect())
+            .collect();
+        assert_eq!(
+            values,
+            vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7], vec![8, 9]]
+        );
+    }
+
+    #[test]
+    fn into_btree_map_not_enough_parts() {
+        let data = BTreeMap::from([(1, ()), (2, ())]);
+        assert_eq!(data.into_equal_parts(4).count(), 2);
+        assert_eq!(BTreeMap::<u8, ()>::new().into_equal_parts(2).next(), None);
+    }
+}
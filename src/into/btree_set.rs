@@ -0,0 +1,100 @@
+use std::collections::BTreeSet;
+use std::mem;
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+
+/// Iterator that yields approximately equal owned parts of a BTreeSet.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a BTreeSet. Each part is itself a `BTreeSet` covering a contiguous range of
+/// values, and the parts come in ascending order. Parts are cut off with
+/// [`BTreeSet::split_off`] at each boundary value, which is why the values must be
+/// `Clone`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeSet;
+///
+/// use equal_parts::IntoEqualParts;
+///
+/// let data = BTreeSet::from([50, 10, 40, 20, 30]);
+/// let parts: Vec<BTreeSet<i32>> = data.into_equal_parts(2).collect();
+///
+/// assert_eq!(parts[0], BTreeSet::from([10, 20, 30]));
+/// assert_eq!(parts[1], BTreeSet::from([40, 50]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoBTreeSetPartsIter<T> {
+    data: BTreeSet<T>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<T: Ord + Clone> Iterator for IntoBTreeSetPartsIter<T> {
+    type Item = BTreeSet<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let Some(boundary) = self.data.iter().nth(chunk_size).cloned() else {
+            return Some(mem::take(&mut self.data));
+        };
+        let rest = self.data.split_off(&boundary);
+        Some(mem::replace(&mut self.data, rest))
+    }
+}
+
+impl<T: Ord + Clone> IntoEqualParts for BTreeSet<T> {
+    type Item = BTreeSet<T>;
+    type IntoIter = IntoBTreeSetPartsIter<T>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+
+        IntoBTreeSetPartsIter {
+            data: self,
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntoEqualParts;
+    use std::collections::BTreeSet;
+
+    #[test]
+    fn into_btree_set_ordered_ranges() {
+        let data: BTreeSet<u32> = (0..20).rev().collect();
+        let parts: Vec<Vec<u32>> = data
+            .into_equal_parts(3)
+            .map(|part| part.into_iter().collect())
+            .collect();
+        assert_eq!(
+            parts,
+            vec![
+                (0..7).collect::<Vec<_>>(),
+                (7..14).collect(),
+                (14..20).collect()
+            ]
+        );
+    }
+
+    #[test]
+    fn into_btree_set_not_enough_parts() {
+        let data = BTreeSet::from(['x', 'y']);
+        assert_eq!(data.into_equal_parts(3).count(), 2);
+        assert_eq!(BTreeSet::<u8>::new().into_equal_parts(3).next(), None);
+    }
+}
@@ -0,0 +1,105 @@
+use bytes::{Bytes, BytesMut};
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+
+/// Iterator that yields approximately equal parts of a `Bytes` or `BytesMut` buffer.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a [`Bytes`] or [`BytesMut`]. Each part is split off the front of the buffer with
+/// `split_to`, so no bytes are copied: `Bytes` parts share the original allocation
+/// through its reference count, and `BytesMut` parts are disjoint views of it that can
+/// each be written to independently.
+///
+/// # Examples
+///
+/// ```
+/// use bytes::Bytes;
+/// use equal_parts::IntoEqualParts;
+///
+/// let payload = Bytes::from_static(b"hello world");
+/// let parts: Vec<Bytes> = payload.into_equal_parts(3).collect();
+/// assert_eq!(parts, vec![&b"hell"[..], b"o wo", b"rld"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoBytesPartsIter<B> {
+    data: B,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+macro_rules! impl_bytes_parts {
+    ($($buffer:ty),*) => {
+        $(
+            impl Iterator for IntoBytesPartsIter<$buffer> {
+                type Item = $buffer;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    if self.data.is_empty() {
+                        None
+                    } else {
+                        let split_point = self.part_size - (self.full_parts_left.min(1) ^ 1);
+                        self.full_parts_left -= self.full_parts_left.min(1);
+                        Some(self.data.split_to(split_point))
+                    }
+                }
+            }
+
+            impl IntoEqualParts for $buffer {
+                type Item = $buffer;
+                type IntoIter = IntoBytesPartsIter<$buffer>;
+
+                fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+                    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+                    let part_size = self.len().div_ceil(num_parts);
+                    let full_parts_left = full_part_count(self.len(), num_parts);
+                    IntoBytesPartsIter {
+                        data: self,
+                        part_size,
+                        full_parts_left,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_bytes_parts!(Bytes, BytesMut);
+
+#[cfg(test)]
+mod tests {
+    use bytes::{BufMut, Bytes, BytesMut};
+
+    use crate::IntoEqualParts;
+
+    #[test]
+    fn bytes_parts_share_allocation() {
+        let data = Bytes::from(vec![7u8; 10]);
+        let start = data.as_ptr();
+        let parts: Vec<Bytes> = data.into_equal_parts(3).collect();
+        assert_eq!(
+            parts.iter().map(Bytes::len).collect::<Vec<_>>(),
+            vec![4, 3, 3]
+        );
+        assert_eq!(parts[0].as_ptr(), start);
+        assert_eq!(parts[1].as_ptr(), start.wrapping_add(4));
+    }
+
+    #[test]
+    fn bytes_mut_parts_are_writable() {
+        let mut data = BytesMut::with_capacity(5);
+        data.put_slice(b"abcde");
+        let mut parts: Vec<BytesMut> = data.into_equal_parts(2).collect();
+        parts[1][0] = b'X';
+        assert_eq!(parts[0], b"abc"[..]);
+        assert_eq!(parts[1], b"Xe"[..]);
+    }
+
+    #[test]
+    fn bytes_not_enough_parts() {
+        assert_eq!(Bytes::from_static(b"ab").into_equal_parts(5).count(), 2);
+        assert_eq!(Bytes::new().into_equal_parts(2).next(), None);
+        assert_eq!(BytesMut::new().into_equal_parts(2).next(), None);
+    }
+}
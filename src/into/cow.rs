@@ -0,0 +1,116 @@
+use std::borrow::Cow;
+
+use crate::into::into_equal_parts::IntoEqualParts;
+use crate::into::vec::IntoEqualPartsIter;
+use crate::{EqualParts, EqualPartsIter};
+
+/// Iterator that yields approximately equal parts of a `Cow<[T]>`, keeping its variant.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a `Cow<[T]>`. A borrowed `Cow` yields borrowed parts without copying, and an
+/// owned `Cow` yields owned parts.
+///
+/// # Examples
+///
+/// ```
+/// use std::borrow::Cow;
+///
+/// use equal_parts::IntoEqualParts;
+///
+/// let data = [1, 2, 3, 4, 5];
+/// let borrowed: Vec<Cow<[i32]>> = Cow::Borrowed(&data[..]).into_equal_parts(2).collect();
+/// assert!(matches!(borrowed[0], Cow::Borrowed(&[1, 2, 3])));
+///
+/// let owned: Vec<Cow<[i32]>> = Cow::<[i32]>::Owned(data.to_vec()).into_equal_parts(2).collect();
+/// assert!(matches!(&owned[1], Cow::Owned(part) if *part == [4, 5]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoCowPartsIter<'a, T: Clone> {
+    inner: CowParts<'a, T>,
+}
+
+#[derive(Debug, Clone)]
+enum CowParts<'a, T> {
+    Borrowed(EqualPartsIter<'a, T>),
+    Owned(IntoEqualPartsIter<T>),
+}
+
+impl<'a, T: Clone> Iterator for IntoCowPartsIter<'a, T> {
+    type Item = Cow<'a, [T]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            CowParts::Borrowed(parts) => parts.next().map(Cow::Borrowed),
+            CowParts::Owned(parts) => parts.next().map(Cow::Owned),
+        }
+    }
+}
+
+impl<'a, T: Clone> IntoEqualParts for Cow<'a, [T]> {
+    type Item = Cow<'a, [T]>;
+    type IntoIter = IntoCowPartsIter<'a, T>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let inner = match self {
+            Cow::Borrowed(data) => CowParts::Borrowed(data.equal_parts(num_parts)),
+            Cow::Owned(data) => CowParts::Owned(data.into_equal_parts(num_parts)),
+        };
+        IntoCowPartsIter { inner }
+    }
+}
+
+impl<'a, T: Clone> EqualParts for &'a Cow<'_, [T]> {
+    type Item = &'a [T];
+    type Iter = EqualPartsIter<'a, T>;
+
+    fn equal_parts(self, num_parts: usize) -> Self::Iter {
+        (**self).equal_parts(num_parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EqualParts, IntoEqualParts};
+    use std::borrow::Cow;
+
+    #[test]
+    fn into_cow_keeps_variant() {
+        let data = vec![1, 2, 3, 4, 5, 6, 7];
+        let borrowed: Vec<Cow<[i32]>> =
+            Cow::Borrowed(data.as_slice()).into_equal_parts(3).collect();
+        assert!(borrowed.iter().all(|part| matches!(part, Cow::Borrowed(_))));
+
+        let owned: Vec<Cow<[i32]>> = Cow::<[i32]>::Owned(data.clone())
+            .into_equal_parts(3)
+            .collect();
+        assert!(owned.iter().all(|part| matches!(part, Cow::Owned(_))));
+        assert_eq!(borrowed, owned);
+    }
+
+    #[test]
+    fn borrowed_parts_of_cow() {
+        let cow: Cow<[u8]> = Cow::Owned(vec![1, 2, 3]);
+        let parts: Vec<&[u8]> = cow.equal_parts(2).collect();
+        assert_eq!(parts, vec![&[1, 2][..], &[3]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn into_cow_panics_with_zero_parts() {
+        let _ = Cow::Borrowed(&[1, 2][..]).into_equal_parts(0);
+    }
+
+    #[test]
+    fn cloned_parts_resume_at_the_same_part() {
+        let data = [1, 2, 3, 4, 5];
+        for cow in [Cow::Borrowed(&data[..]), Cow::Owned(data.to_vec())] {
+            let mut parts = cow.into_equal_parts(3);
+            parts.next();
+            let mut copy = parts.clone();
+            assert_eq!(copy.next().as_deref(), Some(&[3, 4][..]));
+            assert_eq!(parts.next().as_deref(), Some(&[3, 4][..]));
+        }
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+use std::collections::hash_map;
+use std::hash::{BuildHasher, Hash};
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+
+/// Iterator that yields approximately equal owned parts of a HashMap.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a HashMap. Entries are moved straight from the map into each smaller map, which
+/// uses a clone of the original map's hasher. Which entries end up in which part
+/// depends on the map's iteration order, so only the sizes of the parts are
+/// predictable.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashMap;
+///
+/// use equal_parts::IntoEqualParts;
+///
+/// let data: HashMap<u32, &str> = (0..10).map(|i| (i, "value")).collect();
+/// let parts: Vec<HashMap<u32, &str>> = data.into_equal_parts(4).collect();
+///
+/// let sizes: Vec<usize> = parts.iter().map(HashMap::len).collect();
+/// assert_eq!(sizes, vec![3, 3, 2, 2]);
+/// ```
+#[derive(Debug)]
+pub struct IntoHashMapPartsIter<K, V, S> {
+    data: hash_map::IntoIter<K, V>,
+    hasher: S,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<K, V, S> Iterator for IntoHashMapPartsIter<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Clone,
+{
+    type Item = HashMap<K, V, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() == 0 {
+            return None;
+        }
+
+        let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let mut part = HashMap::with_capacity_and_hasher(chunk_size, self.hasher.clone());
+        part.extend(self.data.by_ref().take(chunk_size));
+        Some(part)
+    }
+}
+
+impl<K, V, S> IntoEqualParts for HashMap<K, V, S>
+where
+    K: Eq + Hash,
+    S: BuildHasher + Clone,
+{
+    type Item = HashMap<K, V, S>;
+    type IntoIter = IntoHashMapPartsIter<K, V, S>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+
+        IntoHashMapPartsIter {
+            hasher: self.hasher().clone(),
+            data: self.into_iter(),
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntoEqualParts;
+    use std::collections::HashMap;
+
+    #[test]
+    fn into_map_parts_keep_every_entry() {
+        let data: HashMap<u32, u32> = (0..100).map(|i| (i, i * i)).collect();
+        let parts: Vec<HashMap<u32, u32>> = data.clone().into_equal_parts(7).collect();
+        let sizes: Vec<usize> = parts.iter().map(HashMap::len).collect();
+        assert_eq!(sizes, vec![15, 15, 14, 14, 14, 14, 14]);
+
+        let merged: HashMap<u32, u32> = parts.into_iter().flatten().collect();
+        assert_eq!(merged, data);
+    }
+
+    #[test]
+    fn into_map_not_enough_parts() {
+        let data = HashMap::from([(1, 'a'), (2, 'b')]);
+        assert_eq!(data.into_equal_parts(3).count(), 2);
+        assert_eq!(HashMap::<u8, u8>::new().into_equal_parts(3).next(), None);
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashSet;
+use std::collections::hash_set;
+use std::hash::{BuildHasher, Hash};
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+
+/// Iterator that yields approximately equal owned parts of a HashSet.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a HashSet. Elements are moved straight from the set into each smaller set, which
+/// uses a clone of the original set's hasher. Which elements end up in which part
+/// depends on the set's iteration order, so only the sizes of the parts are
+/// predictable.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::HashSet;
+///
+/// use equal_parts::IntoEqualParts;
+///
+/// let data: HashSet<u32> = (0..7).collect();
+/// let parts: Vec<HashSet<u32>> = data.into_equal_parts(3).collect();
+///
+/// let sizes: Vec<usize> = parts.iter().map(HashSet::len).collect();
+/// assert_eq!(sizes, vec![3, 2, 2]);
+/// ```
+#[derive(Debug)]
+pub struct IntoHashSetPartsIter<T, S> {
+    data: hash_set::IntoIter<T>,
+    hasher: S,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<T, S> Iterator for IntoHashSetPartsIter<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Clone,
+{
+    type Item = HashSet<T, S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() == 0 {
+            return None;
+        }
+
+        let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let mut part = HashSet::with_capacity_and_hasher(chunk_size, self.hasher.clone());
+        part.extend(self.data.by_ref().take(chunk_size));
+        Some(part)
+    }
+}
+
+impl<T, S> IntoEqualParts for HashSet<T, S>
+where
+    T: Eq + Hash,
+    S: BuildHasher + Clone,
+{
+    type Item = HashSet<T, S>;
+    type IntoIter = IntoHashSetPartsIter<T, S>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+
+        IntoHashSetPartsIter {
+            hasher: self.hasher().clone(),
+            data: self.into_iter(),
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntoEqualParts;
+    use std::collections::HashSet;
+
+    #[test]
+    fn into_set_parts_are_disjoint() {
+        let data: HashSet<u32> = (0..50).collect();
+        let parts: Vec<HashSet<u32>> = data.clone().into_equal_parts(6).collect();
+        assert_eq!(parts.iter().map(HashSet::len).sum::<usize>(), 50);
+        assert!(parts.iter().all(|part| part.len() == 9 || part.len() == 8));
+
+        let merged: HashSet<u32> = parts.into_iter().flatten().collect();
+        assert_eq!(merged, data);
+    }
+
+    #[test]
+    fn into_set_not_enough_parts() {
+        let data = HashSet::from(["a", "b"]);
+        assert_eq!(data.into_equal_parts(3).count(), 2);
+        assert_eq!(HashSet::<u8>::new().into_equal_parts(3).next(), None);
+    }
+}
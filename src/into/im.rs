@@ -0,0 +1,92 @@
+use im::Vector;
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+
+/// Iterator that yields approximately equal owned parts of an `im::Vector`.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a persistent [`Vector`]. Each part is split off the front with
+/// [`Vector::split_at`], which takes O(log n) time and shares the vector's internal
+/// nodes instead of copying elements. Producing all the parts is therefore cheap even
+/// for very large vectors, and the original can be kept by cloning it first.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::IntoEqualParts;
+/// use im::Vector;
+///
+/// let data: Vector<u32> = (1..=7).collect();
+/// let parts: Vec<Vector<u32>> = data.clone().into_equal_parts(3).collect();
+///
+/// assert_eq!(parts[0], Vector::from(vec![1, 2, 3]));
+/// assert_eq!(parts[1], Vector::from(vec![4, 5]));
+/// assert_eq!(parts[2], Vector::from(vec![6, 7]));
+/// assert_eq!(data.len(), 7);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoImVectorPartsIter<T: Clone> {
+    data: Vector<T>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<T: Clone> Iterator for IntoImVectorPartsIter<T> {
+    type Item = Vector<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            None
+        } else {
+            let split_point = self.part_size - (self.full_parts_left.min(1) ^ 1);
+            self.full_parts_left -= self.full_parts_left.min(1);
+
+            let (chunk, rest) = std::mem::take(&mut self.data).split_at(split_point);
+            self.data = rest;
+            Some(chunk)
+        }
+    }
+}
+
+impl<T: Clone> IntoEqualParts for Vector<T> {
+    type Item = Vector<T>;
+    type IntoIter = IntoImVectorPartsIter<T>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+        IntoImVectorPartsIter {
+            data: self,
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use im::Vector;
+
+    use crate::{EqualParts, IntoEqualParts};
+
+    #[test]
+    fn same_sizes_as_slices() {
+        let data: Vec<u32> = (0..1000).collect();
+        let vector: Vector<u32> = data.iter().copied().collect();
+        let parts: Vec<Vec<u32>> = vector
+            .into_equal_parts(7)
+            .map(|part| part.into_iter().collect())
+            .collect();
+        let expected: Vec<Vec<u32>> = data.equal_parts(7).map(<[u32]>::to_vec).collect();
+        assert_eq!(parts, expected);
+    }
+
+    #[test]
+    fn into_empty_vector() {
+        assert!(Vector::<u8>::new().into_equal_parts(3).next().is_none());
+        assert_eq!(Vector::unit(1).into_equal_parts(3).count(), 1);
+    }
+}
@@ -1,3 +1,7 @@
+use std::num::NonZeroUsize;
+
+use crate::EqualPartsError;
+
 /// A trait for splitting owned collections into approximately equal owned parts.
 ///
 /// This trait is similar to [`EqualParts`] but consumes the collection and produces
@@ -67,4 +71,58 @@ pub trait IntoEqualParts {
     /// assert_eq!(parts.next(), None);
     /// ```
     fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter;
+
+    /// Splits the collection into approximately equal owned parts, or returns an error
+    /// instead of panicking if the split is not possible.
+    ///
+    /// This is the non-panicking form of [`into_equal_parts`](Self::into_equal_parts),
+    /// which behaves like `try_into_equal_parts(num_parts).unwrap()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EqualPartsError::ZeroParts`] if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::{EqualPartsError, IntoEqualParts};
+    ///
+    /// let parts: Vec<Vec<i32>> = vec![1, 2, 3].try_into_equal_parts(2).unwrap().collect();
+    /// assert_eq!(parts, vec![vec![1, 2], vec![3]]);
+    /// assert_eq!(
+    ///     vec![1, 2, 3].try_into_equal_parts(0).err(),
+    ///     Some(EqualPartsError::ZeroParts)
+    /// );
+    /// ```
+    fn try_into_equal_parts(self, num_parts: usize) -> Result<Self::IntoIter, EqualPartsError>
+    where
+        Self: Sized,
+    {
+        if num_parts == 0 {
+            return Err(EqualPartsError::ZeroParts);
+        }
+        Ok(self.into_equal_parts(num_parts))
+    }
+
+    /// Splits the collection into approximately equal owned parts, taking the number
+    /// of parts as a [`NonZeroUsize`] so that it cannot panic on zero parts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use equal_parts::IntoEqualParts;
+    ///
+    /// let parts: Vec<Vec<i32>> = vec![1, 2, 3, 4]
+    ///     .into_equal_parts_nonzero(NonZeroUsize::MIN.saturating_add(1))
+    ///     .collect();
+    /// assert_eq!(parts, vec![vec![1, 2], vec![3, 4]]);
+    /// ```
+    fn into_equal_parts_nonzero(self, num_parts: NonZeroUsize) -> Self::IntoIter
+    where
+        Self: Sized,
+    {
+        self.into_equal_parts(num_parts.get())
+    }
 }
@@ -0,0 +1,93 @@
+use std::collections::LinkedList;
+use std::mem;
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+
+/// Iterator that yields approximately equal owned parts of a LinkedList.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a LinkedList. Parts are cut off with [`LinkedList::split_off`], which walks to
+/// the split point but relinks nodes instead of moving or reallocating any elements.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::LinkedList;
+///
+/// use equal_parts::IntoEqualParts;
+///
+/// let data: LinkedList<i32> = (1..=5).collect();
+/// let parts: Vec<LinkedList<i32>> = data.into_equal_parts(2).collect();
+///
+/// assert_eq!(parts[0], LinkedList::from([1, 2, 3]));
+/// assert_eq!(parts[1], LinkedList::from([4, 5]));
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoLinkedListPartsIter<T> {
+    data: LinkedList<T>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<T> Iterator for IntoLinkedListPartsIter<T> {
+    type Item = LinkedList<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let rest = self.data.split_off(chunk_size);
+        Some(mem::replace(&mut self.data, rest))
+    }
+}
+
+impl<T> IntoEqualParts for LinkedList<T> {
+    type Item = LinkedList<T>;
+    type IntoIter = IntoLinkedListPartsIter<T>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+
+        IntoLinkedListPartsIter {
+            data: self,
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntoEqualParts;
+    use std::collections::LinkedList;
+
+    #[test]
+    fn into_list_parts() {
+        let data: LinkedList<i32> = (1..=7).collect();
+        let parts: Vec<Vec<i32>> = data
+            .into_equal_parts(3)
+            .map(|part| part.into_iter().collect())
+            .collect();
+        assert_eq!(parts, vec![vec![1, 2, 3], vec![4, 5], vec![6, 7]]);
+    }
+
+    #[test]
+    fn into_list_not_enough_parts() {
+        let data: LinkedList<u8> = [1, 2].into();
+        assert_eq!(data.into_equal_parts(5).count(), 2);
+    }
+
+    #[test]
+    fn into_empty_list() {
+        let data: LinkedList<u8> = LinkedList::new();
+        assert_eq!(data.into_equal_parts(2).next(), None);
+    }
+}
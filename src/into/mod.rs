@@ -1,2 +1,24 @@
+pub mod arc;
+pub mod array;
+#[cfg(feature = "arrayvec")]
+pub mod arrayvec;
+pub mod btree_map;
+pub mod btree_set;
+#[cfg(feature = "bytes")]
+pub mod bytes;
+pub mod cow;
+pub mod hash_map;
+pub mod hash_set;
+#[cfg(feature = "im")]
+pub mod im;
 pub mod into_equal_parts;
+pub mod linked_list;
+#[cfg(feature = "arrow")]
+pub mod record_batch;
+#[cfg(feature = "smallvec")]
+pub mod smallvec;
+pub mod string;
+#[cfg(feature = "tinyvec")]
+pub mod tinyvec;
 pub mod vec;
+pub mod vec_deque;
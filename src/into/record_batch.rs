@@ -0,0 +1,115 @@
+use arrow_array::RecordBatch;
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+
+/// Iterator that yields approximately equal row slices of an Arrow `RecordBatch`.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a [`RecordBatch`]. Each part is produced with [`RecordBatch::slice`], which
+/// shares the column buffers of the original batch, so no values are copied. The parts
+/// have the same schema as the original and balanced row counts, larger parts first.
+///
+/// Splitting a borrowed batch works the same way after a cheap `clone`.
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::Arc;
+///
+/// use arrow_array::{ArrayRef, Int32Array, RecordBatch};
+/// use equal_parts::IntoEqualParts;
+///
+/// let ids: ArrayRef = Arc::new(Int32Array::from_iter_values(0..10));
+/// let batch = RecordBatch::try_from_iter([("id", ids)]).unwrap();
+///
+/// let rows: Vec<usize> = batch.into_equal_parts(4).map(|part| part.num_rows()).collect();
+/// assert_eq!(rows, vec![3, 3, 2, 2]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoRecordBatchPartsIter {
+    batch: RecordBatch,
+    offset: usize,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl Iterator for IntoRecordBatchPartsIter {
+    type Item = RecordBatch;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.offset == self.batch.num_rows() {
+            None
+        } else {
+            let rows = self.part_size - (self.full_parts_left.min(1) ^ 1);
+            self.full_parts_left -= self.full_parts_left.min(1);
+
+            let part = self.batch.slice(self.offset, rows);
+            self.offset += rows;
+            Some(part)
+        }
+    }
+}
+
+impl IntoEqualParts for RecordBatch {
+    type Item = RecordBatch;
+    type IntoIter = IntoRecordBatchPartsIter;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.num_rows().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.num_rows(), num_parts);
+        IntoRecordBatchPartsIter {
+            batch: self,
+            offset: 0,
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use arrow_array::cast::AsArray;
+    use arrow_array::types::Int64Type;
+    use arrow_array::{ArrayRef, Int64Array, RecordBatch, StringArray};
+
+    use crate::IntoEqualParts;
+
+    fn batch(rows: i64) -> RecordBatch {
+        let ids: ArrayRef = Arc::new(Int64Array::from_iter_values(0..rows));
+        let names: ArrayRef = Arc::new(StringArray::from_iter_values(
+            (0..rows).map(|i| format!("row{i}")),
+        ));
+        RecordBatch::try_from_iter([("id", ids), ("name", names)]).unwrap()
+    }
+
+    #[test]
+    fn parts_are_contiguous_slices() {
+        let parts: Vec<RecordBatch> = batch(7).into_equal_parts(3).collect();
+        let firsts: Vec<i64> = parts
+            .iter()
+            .map(|part| part.column(0).as_primitive::<Int64Type>().value(0))
+            .collect();
+        assert_eq!(firsts, vec![0, 3, 5]);
+        assert!(parts.iter().all(|part| part.num_columns() == 2));
+    }
+
+    #[test]
+    fn parts_share_buffers() {
+        let original = batch(8);
+        let parts: Vec<RecordBatch> = original.clone().into_equal_parts(2).collect();
+        let values = original.column(0).as_primitive::<Int64Type>().values();
+        let second = parts[1].column(0).as_primitive::<Int64Type>().values();
+        assert_eq!(second.as_ptr(), values[4..].as_ptr());
+    }
+
+    #[test]
+    fn empty_batch() {
+        assert!(batch(0).into_equal_parts(3).next().is_none());
+        assert_eq!(batch(2).into_equal_parts(5).count(), 2);
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt;
+
+use smallvec::{Array, SmallVec};
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+use crate::{EqualParts, EqualPartsIter};
+
+/// Iterator that yields approximately equal owned parts of a `SmallVec`.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a [`SmallVec`]. Each part is itself a `SmallVec` with the same inline capacity,
+/// so parts that fit inline stay on the stack even if the original had spilled to the
+/// heap.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::IntoEqualParts;
+/// use smallvec::{SmallVec, smallvec};
+///
+/// let data: SmallVec<[u8; 4]> = smallvec![1, 2, 3, 4, 5, 6, 7];
+/// assert!(data.spilled());
+///
+/// let parts: Vec<SmallVec<[u8; 4]>> = data.into_equal_parts(2).collect();
+/// assert_eq!(parts[0].as_slice(), &[1, 2, 3, 4]);
+/// assert_eq!(parts[1].as_slice(), &[5, 6, 7]);
+/// assert!(parts.iter().all(|part| !part.spilled()));
+/// ```
+pub struct IntoSmallVecPartsIter<A: Array> {
+    data: smallvec::IntoIter<A>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<A: Array> fmt::Debug for IntoSmallVecPartsIter<A>
+where
+    A::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntoSmallVecPartsIter")
+            .field("data", &self.data)
+            .field("part_size", &self.part_size)
+            .field("full_parts_left", &self.full_parts_left)
+            .finish()
+    }
+}
+
+impl<A: Array + Clone> Clone for IntoSmallVecPartsIter<A>
+where
+    A::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        IntoSmallVecPartsIter {
+            data: self.data.clone(),
+            part_size: self.part_size,
+            full_parts_left: self.full_parts_left,
+        }
+    }
+}
+
+impl<A: Array> Iterator for IntoSmallVecPartsIter<A> {
+    type Item = SmallVec<A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() == 0 {
+            None
+        } else {
+            let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+            self.full_parts_left -= self.full_parts_left.min(1);
+            Some(self.data.by_ref().take(chunk_size).collect())
+        }
+    }
+}
+
+impl<A: Array> IntoEqualParts for SmallVec<A> {
+    type Item = SmallVec<A>;
+    type IntoIter = IntoSmallVecPartsIter<A>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+        IntoSmallVecPartsIter {
+            data: self.into_iter(),
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+impl<'a, A: Array> EqualParts for &'a SmallVec<A> {
+    type Item = &'a [A::Item];
+    type Iter = EqualPartsIter<'a, A::Item>;
+
+    fn equal_parts(self, num_parts: usize) -> Self::Iter {
+        self.as_slice().equal_parts(num_parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use smallvec::{SmallVec, smallvec};
+
+    use crate::{EqualParts, IntoEqualParts};
+
+    #[test]
+    fn into_smallvec_parts() {
+        let data: SmallVec<[i32; 2]> = smallvec![1, 2, 3, 4, 5];
+        let parts: Vec<SmallVec<[i32; 2]>> = data.into_equal_parts(3).collect();
+        assert_eq!(
+            parts,
+            vec![smallvec![1, 2], smallvec![3, 4], smallvec![5]] as Vec<SmallVec<[i32; 2]>>
+        );
+        assert!(parts.iter().all(|part| !part.spilled()));
+    }
+
+    #[test]
+    fn borrowed_smallvec_parts() {
+        let data: SmallVec<[i32; 8]> = smallvec![1, 2, 3];
+        let parts: Vec<&[i32]> = (&data).equal_parts(5).collect();
+        assert_eq!(parts, vec![&[1][..], &[2], &[3]]);
+    }
+
+    #[test]
+    fn into_empty_smallvec() {
+        let data: SmallVec<[u8; 4]> = SmallVec::new();
+        assert!(data.into_equal_parts(2).next().is_none());
+    }
+
+    #[test]
+    fn cloned_parts_resume_at_the_same_part() {
+        let data: SmallVec<[u8; 4]> = smallvec![1, 2, 3, 4, 5];
+        let mut parts = data.into_equal_parts(3);
+        parts.next();
+        let mut copy = parts.clone();
+        assert_eq!(copy.next().as_deref(), Some(&[3, 4][..]));
+        assert_eq!(parts.next().as_deref(), Some(&[3, 4][..]));
+    }
+}
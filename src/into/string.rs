@@ -0,0 +1,105 @@
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+
+/// Iterator that yields approximately equal owned parts of a String.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a String. Parts are measured in `char`s, like the parts of
+/// [`str::equal_parts`](crate::EqualParts::equal_parts), and each one is yielded as an
+/// owned `String`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::IntoEqualParts;
+///
+/// let text = String::from("große Straße");
+/// let parts: Vec<String> = text.into_equal_parts(3).collect();
+/// assert_eq!(parts, vec!["groß", "e St", "raße"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoStringPartsIter {
+    data: String,
+    offset: usize,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl Iterator for IntoStringPartsIter {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = &self.data[self.offset..];
+        if rest.is_empty() {
+            return None;
+        }
+
+        let chars = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let end = rest
+            .char_indices()
+            .nth(chars)
+            .map_or(rest.len(), |(index, _)| index);
+        if end == rest.len() && self.offset == 0 {
+            // The whole string is a single part, so hand over its buffer as is.
+            self.offset = self.data.len();
+            return Some(std::mem::take(&mut self.data));
+        }
+
+        let part = rest[..end].to_owned();
+        self.offset += end;
+        Some(part)
+    }
+}
+
+impl IntoEqualParts for String {
+    type Item = String;
+    type IntoIter = IntoStringPartsIter;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let len = self.chars().count();
+        let part_size = len.div_ceil(num_parts);
+
+        IntoStringPartsIter {
+            data: self,
+            offset: 0,
+            part_size,
+            full_parts_left: full_part_count(len, num_parts),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntoEqualParts;
+
+    #[test]
+    fn into_string_parts() {
+        let parts: Vec<String> = String::from("abcdefg").into_equal_parts(3).collect();
+        assert_eq!(parts, vec!["abc", "de", "fg"]);
+    }
+
+    #[test]
+    fn into_string_never_splits_chars() {
+        let text = "😀😁😂🤣😃".to_string();
+        let parts: Vec<String> = text.clone().into_equal_parts(2).collect();
+        assert_eq!(parts, vec!["😀😁😂", "🤣😃"]);
+        assert_eq!(parts.concat(), text);
+    }
+
+    #[test]
+    fn into_string_one_part_reuses_buffer() {
+        let text = String::with_capacity(64) + "hello";
+        let part = text.into_equal_parts(1).next().unwrap();
+        assert_eq!(part, "hello");
+        assert_eq!(part.capacity(), 64);
+    }
+
+    #[test]
+    fn into_empty_string() {
+        assert_eq!(String::new().into_equal_parts(2).next(), None);
+    }
+}
@@ -0,0 +1,277 @@
+use std::fmt;
+
+use tinyvec::{Array, ArrayVec, ArrayVecIterator};
+#[cfg(feature = "tinyvec-alloc")]
+use tinyvec::{TinyVec, TinyVecIterator};
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+use crate::{EqualParts, EqualPartsIter};
+
+/// Iterator that yields approximately equal owned parts of a `tinyvec::TinyVec`.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a [`TinyVec`]. Each part is a `TinyVec` with the same inline capacity, so parts
+/// that fit inline are stored inline even if the original had moved to the heap.
+///
+/// `TinyVec` needs tinyvec's `alloc` feature, so this is only available with the
+/// `tinyvec-alloc` feature. The `tinyvec` feature alone supports only `ArrayVec`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::IntoEqualParts;
+/// use tinyvec::{TinyVec, tiny_vec};
+///
+/// let data: TinyVec<[u8; 4]> = tiny_vec![1, 2, 3, 4, 5, 6, 7];
+/// assert!(data.is_heap());
+///
+/// let parts: Vec<TinyVec<[u8; 4]>> = data.into_equal_parts(2).collect();
+/// assert_eq!(parts[0].as_slice(), &[1, 2, 3, 4]);
+/// assert_eq!(parts[1].as_slice(), &[5, 6, 7]);
+/// assert!(parts.iter().all(TinyVec::is_inline));
+/// ```
+#[cfg(feature = "tinyvec-alloc")]
+pub struct IntoTinyVecPartsIter<A: Array> {
+    data: TinyVecIterator<A>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+#[cfg(feature = "tinyvec-alloc")]
+impl<A: Array> fmt::Debug for IntoTinyVecPartsIter<A>
+where
+    A::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntoTinyVecPartsIter")
+            .field("data", &self.data)
+            .field("part_size", &self.part_size)
+            .field("full_parts_left", &self.full_parts_left)
+            .finish()
+    }
+}
+
+#[cfg(feature = "tinyvec-alloc")]
+impl<A: Array> Clone for IntoTinyVecPartsIter<A>
+where
+    A::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        // The iterator is not `Clone`, so the remaining elements are collected again.
+        let remaining: TinyVec<A> = self.data.as_slice().iter().cloned().collect();
+        IntoTinyVecPartsIter {
+            data: remaining.into_iter(),
+            part_size: self.part_size,
+            full_parts_left: self.full_parts_left,
+        }
+    }
+}
+
+#[cfg(feature = "tinyvec-alloc")]
+impl<A: Array> Iterator for IntoTinyVecPartsIter<A> {
+    type Item = TinyVec<A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() == 0 {
+            None
+        } else {
+            let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+            self.full_parts_left -= self.full_parts_left.min(1);
+            Some(self.data.by_ref().take(chunk_size).collect())
+        }
+    }
+}
+
+#[cfg(feature = "tinyvec-alloc")]
+impl<A: Array> IntoEqualParts for TinyVec<A> {
+    type Item = TinyVec<A>;
+    type IntoIter = IntoTinyVecPartsIter<A>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+        IntoTinyVecPartsIter {
+            data: self.into_iter(),
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+#[cfg(feature = "tinyvec-alloc")]
+impl<'a, A: Array> EqualParts for &'a TinyVec<A> {
+    type Item = &'a [A::Item];
+    type Iter = EqualPartsIter<'a, A::Item>;
+
+    fn equal_parts(self, num_parts: usize) -> Self::Iter {
+        self.as_slice().equal_parts(num_parts)
+    }
+}
+
+/// Iterator that yields approximately equal owned parts of a `tinyvec::ArrayVec`.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a tinyvec [`ArrayVec`]. Each part is an `ArrayVec` backed by the same array type,
+/// which always has room for it, so splitting never touches the heap.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::IntoEqualParts;
+/// use tinyvec::{ArrayVec, array_vec};
+///
+/// let data: ArrayVec<[u8; 8]> = array_vec![1, 2, 3, 4, 5];
+/// let parts: Vec<ArrayVec<[u8; 8]>> = data.into_equal_parts(2).collect();
+/// assert_eq!(parts[0].as_slice(), &[1, 2, 3]);
+/// assert_eq!(parts[1].as_slice(), &[4, 5]);
+/// ```
+pub struct IntoTinyArrayVecPartsIter<A: Array> {
+    data: ArrayVecIterator<A>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<A: Array> fmt::Debug for IntoTinyArrayVecPartsIter<A>
+where
+    A::Item: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntoTinyArrayVecPartsIter")
+            .field("data", &self.data)
+            .field("part_size", &self.part_size)
+            .field("full_parts_left", &self.full_parts_left)
+            .finish()
+    }
+}
+
+impl<A: Array> Clone for IntoTinyArrayVecPartsIter<A>
+where
+    A::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        // The iterator is not `Clone`, so the remaining elements are collected again.
+        let remaining: ArrayVec<A> = self.data.as_slice().iter().cloned().collect();
+        IntoTinyArrayVecPartsIter {
+            data: remaining.into_iter(),
+            part_size: self.part_size,
+            full_parts_left: self.full_parts_left,
+        }
+    }
+}
+
+impl<A: Array> Iterator for IntoTinyArrayVecPartsIter<A> {
+    type Item = ArrayVec<A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() == 0 {
+            None
+        } else {
+            let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+            self.full_parts_left -= self.full_parts_left.min(1);
+            Some(self.data.by_ref().take(chunk_size).collect())
+        }
+    }
+}
+
+impl<A: Array> IntoEqualParts for ArrayVec<A> {
+    type Item = ArrayVec<A>;
+    type IntoIter = IntoTinyArrayVecPartsIter<A>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+        IntoTinyArrayVecPartsIter {
+            data: self.into_iter(),
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+impl<'a, A: Array> EqualParts for &'a ArrayVec<A> {
+    type Item = &'a [A::Item];
+    type Iter = EqualPartsIter<'a, A::Item>;
+
+    fn equal_parts(self, num_parts: usize) -> Self::Iter {
+        self.as_slice().equal_parts(num_parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use tinyvec::{ArrayVec, array_vec};
+    #[cfg(feature = "tinyvec-alloc")]
+    use tinyvec::{TinyVec, tiny_vec};
+
+    use crate::{EqualParts, IntoEqualParts};
+
+    #[test]
+    #[cfg(feature = "tinyvec-alloc")]
+    fn into_tinyvec_parts() {
+        let data: TinyVec<[i32; 2]> = tiny_vec![1, 2, 3, 4, 5];
+        let parts: Vec<Vec<i32>> = data.into_equal_parts(3).map(|part| part.to_vec()).collect();
+        assert_eq!(parts, vec![vec![1, 2], vec![3, 4], vec![5]]);
+    }
+
+    #[test]
+    #[cfg(feature = "tinyvec-alloc")]
+    fn borrowed_tiny_parts() {
+        let tiny: TinyVec<[i32; 4]> = tiny_vec![1, 2, 3];
+        let parts: Vec<&[i32]> = (&tiny).equal_parts(2).collect();
+        assert_eq!(parts, vec![&[1, 2][..], &[3]]);
+    }
+
+    #[test]
+    fn borrowed_array_parts() {
+        let array: ArrayVec<[i32; 4]> = array_vec![1, 2, 3];
+        let parts: Vec<&[i32]> = (&array).equal_parts(5).collect();
+        assert_eq!(parts, vec![&[1][..], &[2], &[3]]);
+    }
+
+    #[test]
+    #[cfg(feature = "tinyvec-alloc")]
+    fn into_empty_tiny() {
+        assert!(
+            TinyVec::<[u8; 4]>::new()
+                .into_equal_parts(2)
+                .next()
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn into_empty_array() {
+        assert!(
+            ArrayVec::<[u8; 4]>::new()
+                .into_equal_parts(2)
+                .next()
+                .is_none()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tinyvec-alloc")]
+    fn cloned_tiny_parts_resume_at_the_same_part() {
+        let data: TinyVec<[u8; 2]> = tiny_vec![1, 2, 3, 4, 5];
+        let mut parts = data.into_equal_parts(3);
+        parts.next();
+        let mut copy = parts.clone();
+        assert_eq!(copy.next().as_deref(), Some(&[3, 4][..]));
+        assert_eq!(parts.next().as_deref(), Some(&[3, 4][..]));
+    }
+
+    #[test]
+    fn cloned_array_parts_resume_at_the_same_part() {
+        let data: ArrayVec<[u8; 5]> = array_vec![1, 2, 3, 4, 5];
+        let mut parts = data.into_equal_parts(3);
+        parts.next();
+        let mut copy = parts.clone();
+        assert_eq!(copy.next().as_deref(), Some(&[3, 4][..]));
+        assert_eq!(parts.next().as_deref(), Some(&[3, 4][..]));
+    }
+}
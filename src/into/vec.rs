@@ -1,3 +1,4 @@
+use crate::full_part_count;
 use crate::into::into_equal_parts::IntoEqualParts;
 
 /// Iterator that yields approximately equal owned parts of a Vec.
@@ -10,6 +11,9 @@ use crate::into::into_equal_parts::IntoEqualParts;
 /// - When the total length doesn't divide evenly, larger parts come first
 /// - The iterator stops when all elements have been consumed
 ///
+/// Parts of a `Vec` of a zero-sized type are split off in constant time, without
+/// visiting their elements.
+///
 /// # Examples
 ///
 /// ```
@@ -23,10 +27,109 @@ use crate::into::into_equal_parts::IntoEqualParts;
 /// assert_eq!(iter.next(), Some(vec![6, 7]));
 /// assert_eq!(iter.next(), None);
 /// ```
+#[derive(Debug, Clone)]
 pub struct IntoEqualPartsIter<T> {
     data: Vec<T>,
     part_size: usize,
     full_parts_left: usize,
+    reserve: Reserve,
+}
+
+/// Extra capacity to reserve in each owned part produced by [`IntoEqualPartsIter`].
+///
+/// By default each part is allocated with exactly enough room for its elements. When
+/// the consumer of a part appends to it, that first push forces a reallocation. A
+/// reserve hint lets each part be allocated with some headroom up front instead.
+///
+/// A capacity that does not fit in a `usize` saturates at `usize::MAX`, so a reserve
+/// that is too large for the element type panics with `Vec`'s own capacity overflow
+/// rather than wrapping around to a smaller allocation.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::{IntoEqualParts, Reserve};
+///
+/// let data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+/// let mut parts = data.into_equal_parts(2).with_reserve(Reserve::Additional(10));
+///
+/// let part = parts.next().unwrap();
+/// assert_eq!(part, vec![1, 2, 3, 4]);
+/// assert!(part.capacity() >= 14);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Reserve {
+    /// Reserve no extra capacity.
+    #[default]
+    None,
+    /// Reserve room for this many extra elements in every part.
+    Additional(usize),
+    /// Reserve room for this percentage of each part's length, rounded up.
+    Percent(usize),
+}
+
+impl Reserve {
+    /// Returns the extra capacity to reserve for a part of `len` elements, saturating
+    /// at `usize::MAX`.
+    fn extra_for(self, len: usize) -> usize {
+        match self {
+            Reserve::None => 0,
+            Reserve::Additional(extra) => extra,
+            Reserve::Percent(percent) => {
+                let extra = (len as u128 * percent as u128).div_ceil(100);
+                usize::try_from(extra).unwrap_or(usize::MAX)
+            }
+        }
+    }
+}
+
+impl<T> IntoEqualPartsIter<T> {
+    /// Allocates every remaining part with extra capacity according to `reserve`.
+    ///
+    /// This is useful when the consumer of each part appends to it, as it avoids an
+    /// immediate reallocation on the first push.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::{IntoEqualParts, Reserve};
+    ///
+    /// let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// let parts: Vec<Vec<i32>> = data
+    ///     .into_equal_parts(2)
+    ///     .with_reserve(Reserve::Percent(50))
+    ///     .collect();
+    ///
+    /// assert_eq!(parts, vec![vec![1, 2, 3, 4, 5], vec![6, 7, 8, 9, 10]]);
+    /// assert!(parts.iter().all(|part| part.capacity() >= 8));
+    /// ```
+    pub fn with_reserve(mut self, reserve: Reserve) -> Self {
+        self.reserve = reserve;
+        self
+    }
+
+    /// Turns this iterator into one that yields each part as a `Box<[T]>`.
+    ///
+    /// Boxed slices carry no spare capacity and are a word smaller than a `Vec`, which
+    /// keeps resident memory down when many parts are held alive at once, such as in a
+    /// work queue. Any [`Reserve`] set earlier is ignored, since the parts cannot grow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::IntoEqualParts;
+    ///
+    /// let data = vec![1, 2, 3, 4, 5, 6, 7];
+    /// let parts: Vec<Box<[i32]>> = data.into_equal_parts(3).boxed().collect();
+    ///
+    /// assert_eq!(*parts[0], [1, 2, 3]);
+    /// assert_eq!(*parts[1], [4, 5]);
+    /// assert_eq!(*parts[2], [6, 7]);
+    /// ```
+    pub fn boxed(mut self) -> BoxedParts<T> {
+        self.reserve = Reserve::None;
+        BoxedParts { parts: self }
+    }
 }
 
 impl<T> Iterator for IntoEqualPartsIter<T> {
@@ -41,7 +144,34 @@ impl<T> Iterator for IntoEqualPartsIter<T> {
         self.full_parts_left -= self.full_parts_left.min(1);
         debug_assert!(chunk_size <= self.data.len());
 
-        Some(self.data.drain(0..chunk_size).collect())
+        if size_of::<T>() == 0 {
+            // Zero-sized elements are indistinguishable and occupy no memory, so the
+            // part can be split off the end in constant time instead of moving
+            // `chunk_size` elements out one by one.
+            return Some(self.data.split_off(self.data.len() - chunk_size));
+        }
+
+        let mut part =
+            Vec::with_capacity(chunk_size.saturating_add(self.reserve.extra_for(chunk_size)));
+        part.extend(self.data.drain(0..chunk_size));
+        Some(part)
+    }
+}
+
+/// Iterator that yields approximately equal owned parts of a Vec as boxed slices.
+///
+/// This iterator is created by [`IntoEqualPartsIter::boxed`].
+#[derive(Debug, Clone)]
+pub struct BoxedParts<T> {
+    parts: IntoEqualPartsIter<T>,
+}
+
+impl<T> Iterator for BoxedParts<T> {
+    type Item = Box<[T]>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // Parts are allocated with exactly their length, so this never reallocates.
+        self.parts.next().map(Vec::into_boxed_slice)
     }
 }
 
@@ -52,20 +182,38 @@ impl<T> IntoEqualParts for Vec<T> {
     fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
         assert!(num_parts > 0, "Number of parts must be greater than 0");
 
-        let part_size = self.len().div_ceil(num_parts);
-        let small_part_count = part_size * num_parts - self.len();
-
         IntoEqualPartsIter {
+            part_size: self.len().div_ceil(num_parts),
+            full_parts_left: full_part_count(self.len(), num_parts),
             data: self,
-            part_size,
-            full_parts_left: num_parts - small_part_count,
+            reserve: Reserve::None,
         }
     }
 }
 
+impl<T> IntoEqualParts for Box<[T]> {
+    type Item = Box<[T]>;
+    type IntoIter = BoxedParts<T>;
+
+    /// Splits the boxed slice into boxed slices with no spare capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::IntoEqualParts;
+    ///
+    /// let data: Box<[i32]> = Box::new([1, 2, 3, 4, 5]);
+    /// let parts: Vec<Box<[i32]>> = data.into_equal_parts(2).collect();
+    /// assert_eq!(parts, vec![Box::from([1, 2, 3]), Box::from([4, 5])]);
+    /// ```
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        self.into_vec().into_equal_parts(num_parts).boxed()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::IntoEqualParts;
+    use crate::{IntoEqualParts, Reserve};
 
     #[test]
     fn into_simple_equal_parts() {
@@ -153,4 +301,84 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn into_reserve_additional() {
+        let data = vec![1, 2, 3, 4, 5, 6, 7];
+        let parts: Vec<Vec<i32>> = data
+            .into_equal_parts(3)
+            .with_reserve(Reserve::Additional(4))
+            .collect();
+        assert_eq!(parts, vec![vec![1, 2, 3], vec![4, 5], vec![6, 7]]);
+        assert!(parts[0].capacity() >= 7);
+        assert!(parts[1].capacity() >= 6);
+        assert!(parts[2].capacity() >= 6);
+    }
+
+    #[test]
+    fn into_reserve_percent_rounds_up() {
+        let data = vec![1, 2, 3];
+        let mut parts = data.into_equal_parts(1).with_reserve(Reserve::Percent(10));
+        let part = parts.next().unwrap();
+        assert_eq!(part, vec![1, 2, 3]);
+        assert!(part.capacity() >= 4);
+    }
+
+    #[test]
+    fn into_reserve_saturates() {
+        assert_eq!(
+            Reserve::Percent(usize::MAX).extra_for(usize::MAX),
+            usize::MAX
+        );
+        assert_eq!(
+            Reserve::Percent(200).extra_for(usize::MAX / 2),
+            usize::MAX - 1
+        );
+
+        // Zero-sized elements need no memory, so a saturated capacity is fine.
+        for reserve in [
+            Reserve::Percent(usize::MAX),
+            Reserve::Additional(usize::MAX),
+        ] {
+            let parts: Vec<Vec<()>> = vec![(); 10]
+                .into_equal_parts(2)
+                .with_reserve(reserve)
+                .collect();
+            assert_eq!(parts, vec![vec![(); 5], vec![(); 5]]);
+        }
+    }
+
+    #[test]
+    fn into_boxed_ignores_reserve() {
+        let data = vec![1, 2, 3, 4, 5];
+        let parts: Vec<Box<[i32]>> = data
+            .into_equal_parts(2)
+            .with_reserve(Reserve::Additional(100))
+            .boxed()
+            .collect();
+        assert_eq!(parts, vec![vec![1, 2, 3].into(), vec![4, 5].into()]);
+    }
+
+    #[test]
+    fn cloned_boxed_parts_resume_at_the_same_part() {
+        let mut parts = vec![1, 2, 3, 4, 5].into_equal_parts(3).boxed();
+        parts.next();
+        let mut copy = parts.clone();
+        assert_eq!(copy.next().as_deref(), Some(&[3, 4][..]));
+        assert_eq!(parts.next().as_deref(), Some(&[3, 4][..]));
+    }
+
+    #[test]
+    fn into_boxed_slice_parts() {
+        let data: Box<[u8]> = (0..7).collect();
+        let lengths: Vec<usize> = data.into_equal_parts(3).map(|part| part.len()).collect();
+        assert_eq!(lengths, vec![3, 2, 2]);
+    }
+
+    #[test]
+    fn into_zero_sized_parts() {
+        let data = vec![(); 10_000_000];
+        let lens: Vec<usize> = data.into_equal_parts(3).map(|part| part.len()).collect();
+        assert_eq!(lens, vec![3_333_334, 3_333_333, 3_333_333]);
+    }
 }
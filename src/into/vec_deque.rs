@@ -0,0 +1,116 @@
+use std::collections::VecDeque;
+use std::mem;
+
+use crate::full_part_count;
+use crate::into::into_equal_parts::IntoEqualParts;
+
+/// Iterator that yields approximately equal owned parts of a VecDeque.
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a VecDeque. Each part is drained from the front of the deque in one go, and the
+/// last part reuses the deque's own buffer.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::VecDeque;
+///
+/// use equal_parts::IntoEqualParts;
+///
+/// let data = VecDeque::from([1, 2, 3, 4, 5]);
+/// let mut parts = data.into_equal_parts(2);
+///
+/// assert_eq!(parts.next(), Some(VecDeque::from([1, 2, 3])));
+/// assert_eq!(parts.next(), Some(VecDeque::from([4, 5])));
+/// assert_eq!(parts.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoVecDequePartsIter<T> {
+    data: VecDeque<T>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<T> Iterator for IntoVecDequePartsIter<T> {
+    type Item = VecDeque<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            return None;
+        }
+
+        let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+        debug_assert!(chunk_size <= self.data.len());
+
+        if chunk_size == self.data.len() {
+            return Some(mem::take(&mut self.data));
+        }
+        let mut part = VecDeque::with_capacity(chunk_size);
+        part.extend(self.data.drain(..chunk_size));
+        Some(part)
+    }
+}
+
+impl<T> IntoEqualParts for VecDeque<T> {
+    type Item = VecDeque<T>;
+    type IntoIter = IntoVecDequePartsIter<T>;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let part_size = self.len().div_ceil(num_parts);
+        let full_parts_left = full_part_count(self.len(), num_parts);
+
+        IntoVecDequePartsIter {
+            data: self,
+            part_size,
+            full_parts_left,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::IntoEqualParts;
+    use std::collections::VecDeque;
+
+    #[test]
+    fn into_deque_parts_follow_deque_order() {
+        let mut data: VecDeque<i32> = (4..10).collect();
+        for i in (0..4).rev() {
+            data.push_front(i);
+        }
+        let parts: Vec<Vec<i32>> = data
+            .into_equal_parts(3)
+            .map(|part| part.into_iter().collect())
+            .collect();
+        assert_eq!(parts, vec![vec![0, 1, 2, 3], vec![4, 5, 6], vec![7, 8, 9]]);
+    }
+
+    #[test]
+    fn into_deque_last_part_keeps_buffer() {
+        let mut data = VecDeque::with_capacity(100);
+        data.extend([1, 2, 3, 4]);
+        let parts: Vec<VecDeque<i32>> = data.into_equal_parts(2).collect();
+        assert!(parts[0].capacity() < 100);
+        assert!(parts[1].capacity() >= 100);
+    }
+
+    #[test]
+    fn into_empty_deque() {
+        let data: VecDeque<u8> = VecDeque::new();
+        assert_eq!(data.into_equal_parts(3).next(), None);
+    }
+
+    #[test]
+    fn into_zero_sized_deque_near_max_len() {
+        // Only the plan is checked, since moving the parts element by element would
+        // take too long.
+        let parts = VecDeque::from(vec![(); usize::MAX]).into_equal_parts(2);
+        assert_eq!(
+            (parts.part_size, parts.full_parts_left),
+            (usize::MAX / 2 + 1, 1)
+        );
+    }
+}
@@ -0,0 +1,270 @@
+use crate::IntoEqualParts;
+use crate::full_part_count;
+use crate::into::vec::IntoEqualPartsIter;
+
+/// An extension trait for splitting iterators of known length into approximately equal
+/// parts.
+///
+/// This is the iterator counterpart of [`EqualParts`](crate::EqualParts). Since the
+/// length of an [`ExactSizeIterator`] is known up front, the part sizes can be worked
+/// out without collecting the items first. Each part is collected into a `Vec` as it
+/// is reached, so at most one part is held in memory at a time.
+///
+/// The method is named [`iter_equal_parts`](IteratorEqualParts::iter_equal_parts)
+/// rather than `equal_parts` so that it does not clash with
+/// [`EqualParts`](crate::EqualParts) on types that are both, such as ranges.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::IteratorEqualParts;
+///
+/// let words = ["a", "bb", "ccc", "dddd", "eeeee"];
+/// let parts: Vec<Vec<usize>> = words.iter().map(|word| word.len()).iter_equal_parts(2).collect();
+/// assert_eq!(parts, vec![vec![1, 2, 3], vec![4, 5]]);
+/// ```
+pub trait IteratorEqualParts: ExactSizeIterator + Sized {
+    /// Splits the iterator into approximately equal parts.
+    ///
+    /// The parts have the same sizes as those produced by
+    /// [`equal_parts`](crate::EqualParts::equal_parts) for a slice of the same length,
+    /// with larger parts first.
+    ///
+    /// If the iterator yields fewer items than its [`len`](ExactSizeIterator::len)
+    /// promised, the parts end early rather than padding or panicking.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_parts` - The number of parts to split the iterator into.
+    ///   Must be greater than 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::IteratorEqualParts;
+    ///
+    /// let mut parts = vec![1, 2, 3, 4, 5, 6, 7].into_iter().iter_equal_parts(3);
+    ///
+    /// assert_eq!(parts.next(), Some(vec![1, 2, 3]));
+    /// assert_eq!(parts.next(), Some(vec![4, 5]));
+    /// assert_eq!(parts.next(), Some(vec![6, 7]));
+    /// assert_eq!(parts.next(), None);
+    /// ```
+    fn iter_equal_parts(self, num_parts: usize) -> IterEqualParts<Self> {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let len = self.len();
+        let part_size = len.div_ceil(num_parts);
+        IterEqualParts {
+            iter: self,
+            remaining: len,
+            part_size,
+            full_parts_left: full_part_count(len, num_parts),
+        }
+    }
+}
+
+impl<I: ExactSizeIterator> IteratorEqualParts for I {}
+
+/// Iterator that yields approximately equal parts of another iterator.
+///
+/// This iterator is created by calling
+/// [`iter_equal_parts`](IteratorEqualParts::iter_equal_parts) on an [`ExactSizeIterator`]. It
+/// yields each part as a `Vec` of the underlying items.
+#[derive(Debug, Clone)]
+pub struct IterEqualParts<I> {
+    iter: I,
+    remaining: usize,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<I: Iterator> Iterator for IterEqualParts<I> {
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+        self.remaining -= size;
+
+        let part: Vec<I::Item> = self.iter.by_ref().take(size).collect();
+        if part.is_empty() {
+            self.remaining = 0;
+            return None;
+        }
+        Some(part)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let parts = if self.remaining == 0 {
+            0
+        } else if self.part_size == 1 {
+            self.remaining
+        } else {
+            // Full parts come first, and the rest are one element smaller.
+            let full = self.full_parts_left.min(self.remaining / self.part_size);
+            full + (self.remaining - full * self.part_size).div_ceil(self.part_size - 1)
+        };
+        (0, Some(parts))
+    }
+}
+
+/// Splits an iterator of unknown length into approximately equal parts by buffering
+/// all of its items.
+///
+/// The items are collected into a `Vec` and split with
+/// [`into_equal_parts`](crate::IntoEqualParts::into_equal_parts), so the parts are
+/// moved out of one buffer without copying. This takes a single pass over the source,
+/// but holds every item in memory until its part is yielded. Use
+/// [`counted_equal_parts`] instead when the iterator is cheap to clone and the items
+/// are too large to buffer.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::iter::buffered_equal_parts;
+///
+/// let records = "a,b,c,d,e".split(',');
+/// let parts: Vec<Vec<&str>> = buffered_equal_parts(records, 2).collect();
+/// assert_eq!(parts, vec![vec!["a", "b", "c"], vec!["d", "e"]]);
+/// ```
+pub fn buffered_equal_parts<I: IntoIterator>(
+    iter: I,
+    num_parts: usize,
+) -> IntoEqualPartsIter<I::Item> {
+    iter.into_iter()
+        .collect::<Vec<_>>()
+        .into_equal_parts(num_parts)
+}
+
+/// Splits a cloneable iterator of unknown length into approximately equal parts by
+/// counting its items first.
+///
+/// A clone of the iterator is run to the end to find the length, then the original is
+/// split like an [`ExactSizeIterator`]. Only one part is held in memory at a time, at
+/// the cost of producing every item twice. This suits sources that can be restarted
+/// cheaply, such as a parser over a borrowed buffer. Use [`buffered_equal_parts`] when
+/// producing the items is the expensive part.
+///
+/// If the original yields fewer items than its clone did, the parts end early.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::iter::counted_equal_parts;
+///
+/// let evens = (1..=20).filter(|n| n % 2 == 0);
+/// let parts: Vec<Vec<i32>> = counted_equal_parts(evens, 3).collect();
+/// assert_eq!(parts, vec![vec![2, 4, 6, 8], vec![10, 12, 14], vec![16, 18, 20]]);
+/// ```
+pub fn counted_equal_parts<I: Iterator + Clone>(iter: I, num_parts: usize) -> IterEqualParts<I> {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    let len = iter.clone().count();
+    let part_size = len.div_ceil(num_parts);
+    IterEqualParts {
+        iter,
+        remaining: len,
+        part_size,
+        full_parts_left: full_part_count(len, num_parts),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IteratorEqualParts, buffered_equal_parts, counted_equal_parts};
+    use crate::EqualParts;
+
+    #[test]
+    fn same_sizes_as_slices() {
+        let data: Vec<u32> = (0..23).collect();
+        for num_parts in 1..30 {
+            let expected: Vec<Vec<u32>> =
+                data.equal_parts(num_parts).map(<[u32]>::to_vec).collect();
+            let parts = data.iter().copied().iter_equal_parts(num_parts);
+            assert_eq!(parts.size_hint().1, Some(expected.len()));
+            assert_eq!(parts.collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn works_on_adapters() {
+        let parts: Vec<Vec<String>> = ["x", "y", "z"]
+            .iter()
+            .map(|s| s.repeat(2))
+            .iter_equal_parts(2)
+            .collect();
+        assert_eq!(parts, vec![vec!["xx", "yy"], vec!["zz"]]);
+    }
+
+    #[test]
+    fn buffered_and_counted_agree() {
+        let source = || (0..50).filter(|n| n % 3 != 0);
+        for num_parts in 1..40 {
+            let buffered: Vec<Vec<i32>> = buffered_equal_parts(source(), num_parts).collect();
+            let counted: Vec<Vec<i32>> = counted_equal_parts(source(), num_parts).collect();
+            assert_eq!(buffered, counted);
+            assert_eq!(buffered.concat(), source().collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn unknown_length_empty() {
+        assert_eq!(
+            buffered_equal_parts(std::iter::empty::<u8>(), 3).next(),
+            None
+        );
+        assert_eq!(
+            counted_equal_parts(std::iter::empty::<u8>(), 3).next(),
+            None
+        );
+    }
+
+    #[test]
+    fn empty_iterator() {
+        assert_eq!(
+            Vec::<u8>::new().into_iter().iter_equal_parts(4).next(),
+            None
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = [1, 2].iter().iter_equal_parts(0);
+    }
+
+    #[test]
+    fn zero_sized_items_near_max_len() {
+        let parts = std::iter::repeat_n((), usize::MAX).iter_equal_parts(2);
+        assert_eq!(parts.size_hint().1, Some(2));
+        let parts = counted_equal_parts(std::iter::repeat_n((), usize::MAX), 2);
+        assert_eq!(parts.size_hint().1, Some(2));
+    }
+
+    #[test]
+    fn no_clash_with_equal_parts_on_ranges() {
+        // Both traits are imported by this module.
+        let ranges: Vec<_> = (0..10usize).equal_parts(3).collect();
+        let parts: Vec<Vec<usize>> = (0..10usize).iter_equal_parts(3).collect();
+        assert_eq!(ranges, vec![0..4, 4..7, 7..10]);
+        let expected: Vec<Vec<usize>> = ranges.into_iter().map(Iterator::collect).collect();
+        assert_eq!(parts, expected);
+    }
+}
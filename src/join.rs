@@ -0,0 +1,214 @@
+use crate::split_count;
+
+/// Splits two slices sorted by the same key into parts covering identical key ranges.
+///
+/// Part `i` of `left` and part `i` of `right` contain exactly the keys in the same
+/// half-open key range, and all equal keys end up in the same part. Each pair of parts
+/// can therefore be merge-joined or intersected independently, for example on its own
+/// thread, and the results concatenated in order.
+///
+/// Boundaries are chosen so the combined size of each pair of parts is approximately
+/// equal, following the largest-first rule of
+/// [`equal_parts`](crate::EqualParts::equal_parts). Because equal keys are never split
+/// across parts, long runs of a single key can make some parts larger than the others,
+/// and pairs of parts that would both be empty are left out.
+///
+/// Both slices must be sorted in ascending order of their key; otherwise the parts are
+/// unspecified, but will still cover both slices.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::join::co_partition_by_key;
+///
+/// let orders = [(1, "tea"), (2, "jam"), (2, "egg"), (5, "ham")];
+/// let customers = [(1, "ann"), (2, "bob"), (3, "cy"), (4, "di"), (5, "ed")];
+///
+/// let parts = co_partition_by_key(&orders, &customers, 3, |o| o.0, |c| c.0);
+/// for (orders, customers) in &parts {
+///     // Every order in this part can only match customers in the same part.
+///     for order in *orders {
+///         assert!(customers.iter().any(|c| c.0 == order.0));
+///     }
+/// }
+/// assert_eq!(parts.len(), 3);
+/// ```
+pub fn co_partition_by_key<'a, 'b, A, B, K, FA, FB>(
+    left: &'a [A],
+    right: &'b [B],
+    num_parts: usize,
+    left_key: FA,
+    right_key: FB,
+) -> Vec<(&'a [A], &'b [B])>
+where
+    K: Ord,
+    FA: Fn(&'a A) -> K,
+    FB: Fn(&'b B) -> K,
+{
+    let total = left.len() + right.len();
+    let mut parts = Vec::with_capacity(num_parts.min(total));
+    let (mut left_start, mut right_start) = (0, 0);
+
+    for (offset, _) in split_count(total, num_parts).with_offsets().skip(1) {
+        let key = merged_key_at(left, right, offset, &left_key, &right_key);
+        let left_end = partition_index(left.len(), |i| left_key(&left[i]) < key);
+        let right_end = partition_index(right.len(), |i| right_key(&right[i]) < key);
+        if left_end > left_start || right_end > right_start {
+            parts.push((&left[left_start..left_end], &right[right_start..right_end]));
+            left_start = left_end;
+            right_start = right_end;
+        }
+    }
+    if left_start < left.len() || right_start < right.len() {
+        parts.push((&left[left_start..], &right[right_start..]));
+    }
+    parts
+}
+
+/// Splits two sorted slices into parts covering identical value ranges.
+///
+/// This is [`co_partition_by_key`] using the elements themselves as the key.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::join::co_partition;
+///
+/// let a = [1, 3, 5, 7, 9, 11];
+/// let b = [2, 3, 4, 10];
+/// let parts = co_partition(&a, &b, 2);
+/// assert_eq!(
+///     parts,
+///     vec![(&[1, 3][..], &[2, 3, 4][..]), (&[5, 7, 9, 11][..], &[10][..])]
+/// );
+/// ```
+pub fn co_partition<'a, 'b, T: Ord>(
+    left: &'a [T],
+    right: &'b [T],
+    num_parts: usize,
+) -> Vec<(&'a [T], &'b [T])> {
+    co_partition_by_key(left, right, num_parts, |item| item, |item| item)
+}
+
+/// Returns the key of the element at `position` in the merge of `left` and `right`,
+/// without merging them.
+fn merged_key_at<'a, 'b, A, B, K: Ord>(
+    left: &'a [A],
+    right: &'b [B],
+    position: usize,
+    left_key: impl Fn(&'a A) -> K,
+    right_key: impl Fn(&'b B) -> K,
+) -> K {
+    // Binary search for how many of the first `position` merged elements come from
+    // `left`, taking from `left` first on ties.
+    let mut low = position.saturating_sub(right.len());
+    let mut high = position.min(left.len());
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if left_key(&left[mid]) <= right_key(&right[position - mid - 1]) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+
+    let from_right = position - low;
+    match (left.get(low), right.get(from_right)) {
+        (Some(a), Some(b)) => left_key(a).min(right_key(b)),
+        (Some(a), None) => left_key(a),
+        (None, Some(b)) => right_key(b),
+        (None, None) => unreachable!("position is within the merged length"),
+    }
+}
+
+/// Like [`slice::partition_point`], but passes indices so the predicate can borrow
+/// elements for the slice's whole lifetime.
+fn partition_index(len: usize, pred: impl Fn(usize) -> bool) -> usize {
+    let (mut low, mut high) = (0, len);
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{co_partition, co_partition_by_key};
+
+    fn check_aligned(a: &[u32], b: &[u32], num_parts: usize) {
+        let parts = co_partition(a, b, num_parts);
+        assert!(parts.len() <= num_parts);
+        let left: Vec<u32> = parts.iter().flat_map(|(l, _)| l.iter().copied()).collect();
+        let right: Vec<u32> = parts.iter().flat_map(|(_, r)| r.iter().copied()).collect();
+        assert_eq!(left, a);
+        assert_eq!(right, b);
+        for pair in parts.windows(2) {
+            let max = pair[0].0.iter().chain(pair[0].1).max().unwrap();
+            let min = pair[1].0.iter().chain(pair[1].1).min().unwrap();
+            assert!(max < min);
+        }
+    }
+
+    #[test]
+    fn balances_combined_size() {
+        let a: Vec<u32> = (0..100).map(|i| i * 2).collect();
+        let b: Vec<u32> = (0..100).map(|i| i * 2 + 1).collect();
+        let parts = co_partition(&a, &b, 4);
+        for (l, r) in &parts {
+            assert_eq!(l.len() + r.len(), 50);
+        }
+        check_aligned(&a, &b, 4);
+    }
+
+    #[test]
+    fn keeps_duplicates_together() {
+        let a = [1, 2, 2, 2, 2, 2, 3];
+        let b = [2, 2, 4];
+        check_aligned(&a, &b, 3);
+        let parts = co_partition(&a, &b, 3);
+        let left_twos = parts.iter().position(|(l, _)| l.contains(&2));
+        let right_twos = parts.iter().position(|(_, r)| r.contains(&2));
+        assert_eq!(left_twos, right_twos);
+        assert_eq!(parts.iter().filter(|(l, _)| l.contains(&2)).count(), 1);
+    }
+
+    #[test]
+    fn lopsided_lengths() {
+        let a = [50];
+        let b: Vec<u32> = (0..100).collect();
+        check_aligned(&a, &b, 5);
+        check_aligned(&b, &a, 5);
+        check_aligned(&[], &b, 3);
+        assert!(co_partition::<u32>(&[], &[], 3).is_empty());
+    }
+
+    #[test]
+    fn different_element_types() {
+        let ids = [1u8, 4, 9];
+        let names = [(1, "a"), (2, "b"), (9, "c")];
+        let parts = co_partition_by_key(&ids, &names, 2, |&id| u32::from(id), |n| n.0);
+        assert_eq!(
+            parts,
+            vec![(&ids[..1], &names[..2]), (&ids[1..], &names[2..])]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = co_partition(&[1], &[2], 0);
+    }
+}
@@ -1,3 +1,5 @@
+use std::num::NonZeroUsize;
+
 /// A trait for splitting collections into approximately equal parts.
 ///
 /// This trait provides functionality to divide a collection into a specified number
@@ -79,6 +81,59 @@ pub trait EqualParts {
     /// assert_eq!(parts.next(), None);
     /// ```
     fn equal_parts(self, num_parts: usize) -> Self::Iter;
+
+    /// Splits the collection into approximately equal parts, or returns an error
+    /// instead of panicking if the split is not possible.
+    ///
+    /// This is the non-panicking form of [`equal_parts`](Self::equal_parts), which
+    /// behaves like `try_equal_parts(num_parts).unwrap()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EqualPartsError::ZeroParts`] if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::{EqualParts, EqualPartsError};
+    ///
+    /// let data = [1, 2, 3];
+    /// assert_eq!(data.try_equal_parts(2).unwrap().count(), 2);
+    /// assert_eq!(data.try_equal_parts(0).err(), Some(EqualPartsError::ZeroParts));
+    /// ```
+    fn try_equal_parts(self, num_parts: usize) -> Result<Self::Iter, EqualPartsError>
+    where
+        Self: Sized,
+    {
+        if num_parts == 0 {
+            return Err(EqualPartsError::ZeroParts);
+        }
+        Ok(self.equal_parts(num_parts))
+    }
+
+    /// Splits the collection into approximately equal parts, taking the number of
+    /// parts as a [`NonZeroUsize`] so that it cannot panic on zero parts.
+    ///
+    /// This is useful for callers that validate their configuration once, up front,
+    /// and want the type system to carry that guarantee to the split.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use equal_parts::EqualParts;
+    ///
+    /// let workers = NonZeroUsize::new(3).unwrap();
+    /// let data = [1, 2, 3, 4, 5, 6, 7];
+    /// assert_eq!(data.equal_parts_nonzero(workers).count(), 3);
+    /// ```
+    fn equal_parts_nonzero(self, num_parts: NonZeroUsize) -> Self::Iter
+    where
+        Self: Sized,
+    {
+        self.equal_parts(num_parts.get())
+    }
 }
 
 /// Iterator that yields approximately equal parts of a slice.
@@ -104,6 +159,7 @@ pub trait EqualParts {
 /// assert_eq!(iter.next(), Some([6, 7].as_slice()));
 /// assert_eq!(iter.next(), None);
 /// ```
+#[derive(Debug, Clone)]
 pub struct EqualPartsIter<'a, T> {
     data: &'a [T],
     part_size: usize,
@@ -132,16 +188,27 @@ impl<'a, T> EqualParts for &'a [T] {
     type Iter = EqualPartsIter<'a, T>;
 
     fn equal_parts(self, num_parts: usize) -> Self::Iter {
-        let part_size = self.len().div_ceil(num_parts);
-        let small_part_count = part_size * num_parts - self.len();
         EqualPartsIter {
             data: self,
-            part_size,
-            full_parts_left: num_parts - small_part_count,
+            part_size: self.len().div_ceil(num_parts),
+            full_parts_left: full_part_count(self.len(), num_parts),
         }
     }
 }
 
+/// Returns how many parts get the larger size when `len` elements are split into
+/// `num_parts` parts.
+///
+/// This equals `num_parts - (part_size * num_parts - len)`, but avoids the
+/// multiplication, which overflows for slices of zero-sized types longer than
+/// `usize::MAX / 2`.
+pub(crate) fn full_part_count(len: usize, num_parts: usize) -> usize {
+    match len % num_parts {
+        0 => num_parts,
+        remainder => remainder,
+    }
+}
+
 impl<'a, T> EqualParts for &'a Vec<T> {
     type Item = &'a [T];
     type Iter = EqualPartsIter<'a, T>;
@@ -151,9 +218,189 @@ impl<'a, T> EqualParts for &'a Vec<T> {
     }
 }
 
+impl<'a, T, const N: usize> EqualParts for &'a [T; N] {
+    type Item = &'a [T];
+    type Iter = EqualPartsIter<'a, T>;
+
+    fn equal_parts(self, num_parts: usize) -> Self::Iter {
+        self.as_slice().equal_parts(num_parts)
+    }
+}
+
 // Also include the IntoEqualParts trait
 pub mod into;
+pub use crate::into::arc::ArcPart;
 pub use crate::into::into_equal_parts::IntoEqualParts;
+pub use crate::into::vec::Reserve;
+
+/// Derives [`EqualParts`] and [`IntoEqualParts`] for a newtype around a `Vec<T>` or
+/// `[T]`.
+///
+/// Borrowed parts are the `&[T]` slices of the inner collection. Owned parts are
+/// wrapped in the newtype again, so a batch splits into smaller batches. Newtypes
+/// around an unsized `[T]` only get the borrowed implementation.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::{EqualParts, IntoEqualParts};
+///
+/// #[derive(Debug, PartialEq, EqualParts)]
+/// struct Batch(Vec<u32>);
+///
+/// let batch = Batch(vec![1, 2, 3, 4, 5]);
+/// let slices: Vec<&[u32]> = batch.equal_parts(2).collect();
+/// assert_eq!(slices, vec![&[1, 2, 3][..], &[4, 5]]);
+///
+/// let batches: Vec<Batch> = batch.into_equal_parts(2).collect();
+/// assert_eq!(batches, vec![Batch(vec![1, 2, 3]), Batch(vec![4, 5])]);
+/// ```
+#[cfg(feature = "derive")]
+pub use equal_parts_derive::EqualParts;
+
+pub mod mutable;
+pub use crate::mutable::EqualPartsMut;
+
+pub mod from_parts;
+pub use crate::from_parts::{FromParts, LengthMismatch};
+
+pub mod parallel;
+
+#[cfg(feature = "stream")]
+pub mod stream;
+#[cfg(feature = "stream")]
+pub use crate::stream::stream_equal_parts;
+
+pub mod adaptive;
+
+pub mod aligned;
+
+pub mod arena;
+pub use crate::arena::OccupancyParts;
+
+#[cfg(feature = "ndarray")]
+pub mod axis;
+
+#[cfg(feature = "image")]
+pub mod bands;
+
+pub mod batch;
+
+#[cfg(feature = "bitvec")]
+pub mod bits;
+
+pub mod boundary;
+
+pub mod bounded;
+
+pub mod calibrate;
+
+pub mod cardinality;
+
+pub mod checked;
+
+pub mod count;
+pub use crate::count::split_count;
+
+#[cfg(feature = "csv")]
+pub mod csv;
+
+pub mod dependency;
+
+pub mod deque;
+pub use crate::deque::DequePart;
+
+pub mod direct_io;
+
+pub mod drain;
+
+pub mod error;
+pub use crate::error::EqualPartsError;
+
+pub mod dynamic;
+pub use crate::dynamic::DynEqualParts;
+
+pub mod exactly;
+
+pub mod fastx;
+
+pub mod folds;
+
+#[cfg(feature = "unicode-segmentation")]
+pub mod graphemes;
+
+pub mod grid;
+
+pub mod grouped;
+
+pub mod iter;
+pub use crate::iter::IteratorEqualParts;
+
+pub mod join;
+
+#[cfg(feature = "memchr")]
+pub mod lines;
+
+pub mod mapping;
+
+#[cfg(feature = "nalgebra")]
+pub mod matrix;
+
+mod min_max;
+
+pub mod net;
+
+pub mod numpy;
+
+#[cfg(feature = "unicode-width")]
+pub mod width;
+#[cfg(feature = "unicode-width")]
+pub use crate::width::equal_width_parts;
+
+pub mod partitioner;
+
+pub mod placement;
+
+pub mod round_robin;
+pub use crate::round_robin::{
+    InterleavedParts, IntoInterleavedParts, gather_round_robin, round_robin_index,
+};
+
+pub mod range;
+
+pub mod ratio;
+
+pub mod records;
+
+#[cfg(feature = "ropey")]
+pub mod rope;
+
+pub mod seed;
+
+pub mod shard;
+
+pub mod shared;
+
+#[cfg(feature = "rand")]
+pub mod shuffle;
+
+pub mod slice_like;
+
+#[cfg(feature = "num")]
+pub mod span;
+
+pub mod spare;
+
+pub mod stencil;
+
+pub mod stratified;
+
+pub mod text;
+
+pub mod weighted;
+
+#[cfg(feature = "crossbeam-deque")]
+pub mod steal;
 
 #[cfg(test)]
 mod tests {
@@ -202,6 +449,32 @@ mod tests {
         let _ = data.equal_parts(0);
     }
 
+    #[test]
+    fn zero_sized_slices_near_max_len() {
+        // Slices of zero-sized types can be as long as `usize::MAX` without any memory.
+        let data: &[()] = unsafe {
+            std::slice::from_raw_parts(std::ptr::NonNull::dangling().as_ptr(), usize::MAX)
+        };
+        let lens: Vec<usize> = data.equal_parts(2).map(<[()]>::len).collect();
+        assert_eq!(lens, vec![usize::MAX / 2 + 1, usize::MAX / 2]);
+        assert_eq!(data.equal_parts(3).count(), 3);
+    }
+
+    #[test]
+    fn nonzero_matches_equal_parts() {
+        let data: Vec<u8> = (0..11).collect();
+        let num_parts = std::num::NonZeroUsize::new(4).unwrap();
+        let parts: Vec<&[u8]> = data.equal_parts_nonzero(num_parts).collect();
+        assert_eq!(parts, data.equal_parts(4).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn works_on_array() {
+        let data = [1, 2, 3, 4, 5];
+        let parts: Vec<&[i32]> = data.equal_parts(2).collect();
+        assert_eq!(parts, vec![&[1, 2, 3][..], &[4, 5]]);
+    }
+
     #[test]
     fn works_on_vec() {
         let data = vec![1, 2, 3, 4, 5, 6];
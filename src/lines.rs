@@ -0,0 +1,143 @@
+use memchr::memchr;
+
+use crate::boundary::split_at_boundaries;
+use crate::split_count;
+use crate::text::split_str_at;
+
+/// A trait for splitting text buffers into approximately equal byte parts that hold
+/// whole lines.
+///
+/// Splitting a large log, CSV, or JSON Lines file into byte ranges is the usual first
+/// step of processing it in parallel, but the ranges have to end on line breaks so
+/// that no line is cut in two. The newline search uses `memchr`.
+pub trait LineParts: Sized {
+    /// Splits the buffer into up to `num_parts` parts of approximately equal byte
+    /// length, each ending just after a `\n`, except possibly the last.
+    ///
+    /// The boundaries start at the byte offsets
+    /// [`equal_parts`](crate::EqualParts::equal_parts) would use, and each one moves
+    /// forward to the start of the next line. A boundary that is already at the start
+    /// of a line stays put. Boundaries that would make a part empty, or that run past
+    /// the last line break, are dropped, so fewer than `num_parts` parts are returned
+    /// when there are few long lines. No part is empty. Since `\r\n` ends in `\n`,
+    /// Windows line endings are kept together too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::lines::LineParts;
+    ///
+    /// let log = "GET /a\nGET /bb\nPOST /ccc\nGET /d\n";
+    /// let parts = log.equal_parts_at_lines(2);
+    ///
+    /// assert_eq!(parts, vec!["GET /a\nGET /bb\nPOST /ccc\n", "GET /d\n"]);
+    ///
+    /// let bytes: &[u8] = log.as_bytes();
+    /// assert_eq!(bytes.equal_parts_at_lines(2)[1], b"GET /d\n");
+    /// ```
+    fn equal_parts_at_lines(self, num_parts: usize) -> Vec<Self>;
+}
+
+impl LineParts for &[u8] {
+    fn equal_parts_at_lines(self, num_parts: usize) -> Vec<Self> {
+        split_at_boundaries(self, &line_boundaries(self, num_parts))
+    }
+}
+
+impl LineParts for &str {
+    fn equal_parts_at_lines(self, num_parts: usize) -> Vec<Self> {
+        // Every boundary follows a `\n`, so it is always on a char boundary.
+        split_str_at(self, &line_boundaries(self.as_bytes(), num_parts))
+    }
+}
+
+/// Returns the inner boundaries of an equal byte split of `data`, each moved forward
+/// to the next line start.
+fn line_boundaries(data: &[u8], num_parts: usize) -> Vec<usize> {
+    let mut boundaries = Vec::with_capacity(num_parts.min(data.len()).saturating_sub(1));
+    let mut previous = 0;
+    for (offset, _) in split_count(data.len(), num_parts).with_offsets().skip(1) {
+        let from = offset.max(previous + 1);
+        let boundary = if data.get(from - 1) == Some(&b'\n') {
+            from
+        } else {
+            match data.get(from..).and_then(|rest| memchr(b'\n', rest)) {
+                Some(newline) => from + newline + 1,
+                None => break,
+            }
+        };
+        if boundary >= data.len() {
+            break;
+        }
+        boundaries.push(boundary);
+        previous = boundary;
+    }
+    boundaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LineParts;
+
+    #[test]
+    fn parts_hold_whole_lines() {
+        let text: String = (0..200)
+            .map(|i| format!("line {}\n", "x".repeat(i % 13)))
+            .collect();
+        for num_parts in 1..50 {
+            let parts = text.as_str().equal_parts_at_lines(num_parts);
+            assert!(parts.len() <= num_parts);
+            assert_eq!(parts.concat(), text);
+            for part in &parts {
+                assert!(part.ends_with('\n'));
+                assert!(part.starts_with("line "));
+            }
+        }
+    }
+
+    #[test]
+    fn parts_are_near_equal_in_bytes() {
+        let text = "abcdefghi\n".repeat(100);
+        let parts = text.as_str().equal_parts_at_lines(4);
+        let lens: Vec<usize> = parts.iter().map(|part| part.len()).collect();
+        assert_eq!(lens, vec![250, 250, 250, 250]);
+    }
+
+    #[test]
+    fn last_line_without_newline() {
+        let data: &[u8] = b"one\ntwo\nthree\nfour";
+        assert_eq!(
+            data.equal_parts_at_lines(3),
+            vec![&b"one\ntwo\n"[..], b"three\n", b"four"]
+        );
+    }
+
+    #[test]
+    fn long_lines_give_fewer_parts() {
+        let text = "a very long single line\nb\n";
+        assert_eq!(
+            text.equal_parts_at_lines(5),
+            vec!["a very long single line\n", "b\n"]
+        );
+        assert_eq!("no newline".equal_parts_at_lines(3), vec!["no newline"]);
+        assert!("".equal_parts_at_lines(3).is_empty());
+    }
+
+    #[test]
+    fn multibyte_text() {
+        let text = "héllo wörld\nñandú\n日本語\n";
+        let parts = text.equal_parts_at_lines(3);
+        assert_eq!(parts.concat(), text);
+        assert!(parts.iter().all(|part| part.ends_with('\n')));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = "a\nb\n".equal_parts_at_lines(0);
+    }
+}
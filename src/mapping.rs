@@ -0,0 +1,205 @@
+use crate::split_count;
+
+/// Returns the boundaries of the parts [`equal_parts`](crate::EqualParts::equal_parts)
+/// would produce for `len` elements.
+///
+/// Part `i` spans `boundaries[i]..boundaries[i + 1]`, so there is one more boundary than
+/// there are parts. Like `equal_parts`, this never describes empty parts, so there are
+/// fewer than `num_parts` parts when `len` is less than `num_parts`.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::mapping::part_boundaries;
+///
+/// assert_eq!(part_boundaries(10, 4), vec![0, 3, 6, 8, 10]);
+/// assert_eq!(part_boundaries(2, 4), vec![0, 1, 2]);
+/// ```
+pub fn part_boundaries(len: usize, num_parts: usize) -> Vec<usize> {
+    let mut boundaries = vec![0];
+    boundaries.extend(
+        split_count(len, num_parts)
+            .with_offsets()
+            .map(|(offset, count)| offset + count),
+    );
+    boundaries
+}
+
+/// Scales part boundaries of one array onto a parallel array of a different length.
+///
+/// Each boundary `b` of an array of `from_len` elements is mapped to
+/// `b * to_len / from_len`, rounded to the nearest index, so part `i` of both arrays
+/// covers the same fraction of each. The first and last boundaries always map to `0`
+/// and `to_len`. The number of boundaries is unchanged, which keeps part indices
+/// aligned across both arrays, but parts can become empty in the target array when it
+/// is shorter.
+///
+/// Use [`map_boundaries_with`] instead when the exact correspondence between the arrays
+/// is known.
+///
+/// # Panics
+///
+/// Panics if a boundary is greater than `from_len`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::mapping::{map_boundaries_proportional, part_boundaries};
+///
+/// // Split 10 samples into 3 parts and find the matching parts of 1000 timestamps.
+/// let boundaries = part_boundaries(10, 3);
+/// assert_eq!(boundaries, vec![0, 4, 7, 10]);
+/// assert_eq!(
+///     map_boundaries_proportional(&boundaries, 10, 1000),
+///     vec![0, 400, 700, 1000]
+/// );
+/// ```
+pub fn map_boundaries_proportional(
+    boundaries: &[usize],
+    from_len: usize,
+    to_len: usize,
+) -> Vec<usize> {
+    boundaries
+        .iter()
+        .map(|&boundary| {
+            assert!(
+                boundary <= from_len,
+                "Boundary is past the end of the array"
+            );
+            if boundary == from_len {
+                return to_len;
+            }
+            // `from_len` is not 0 here, since `boundary < from_len`.
+            let scaled = boundary as u128 * to_len as u128;
+            ((scaled + from_len as u128 / 2) / from_len as u128) as usize
+        })
+        .collect()
+}
+
+/// Maps part boundaries onto a parallel array through a known index mapping.
+///
+/// `starts[i]` is the index in the target array where source element `i` begins; for
+/// example, the byte offset of each token in the text it was taken from. A boundary
+/// `b` maps to `starts[b]`, and the boundary at the end of the source maps to
+/// `to_len`. The result has one boundary per input boundary, so part indices stay
+/// aligned across both arrays.
+///
+/// # Panics
+///
+/// Panics if a boundary is greater than `starts.len()`, or if the mapped boundaries
+/// are not in ascending order or are greater than `to_len`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::mapping::{map_boundaries_with, part_boundaries};
+///
+/// let text = "the quick brown fox jumps";
+/// let starts: Vec<usize> = text
+///     .split(' ')
+///     .scan(0, |offset, word| {
+///         let start = *offset;
+///         *offset += word.len() + 1;
+///         Some(start)
+///     })
+///     .collect();
+///
+/// let tokens = part_boundaries(starts.len(), 2);
+/// let chars = map_boundaries_with(&tokens, &starts, text.len());
+/// assert_eq!(&text[chars[0]..chars[1]], "the quick brown ");
+/// assert_eq!(&text[chars[1]..chars[2]], "fox jumps");
+/// ```
+pub fn map_boundaries_with(boundaries: &[usize], starts: &[usize], to_len: usize) -> Vec<usize> {
+    let mapped: Vec<usize> = boundaries
+        .iter()
+        .map(|&boundary| match starts.get(boundary) {
+            Some(&start) => start,
+            None if boundary == starts.len() => to_len,
+            None => panic!("Boundary is past the end of the array"),
+        })
+        .collect();
+    assert!(
+        mapped.is_sorted() && mapped.last().is_none_or(|&last| last <= to_len),
+        "Mapped boundaries must be ascending and within the target array"
+    );
+    mapped
+}
+
+/// Splits a slice at the given boundaries, producing one part per pair of adjacent
+/// boundaries.
+///
+/// This is the counterpart of [`part_boundaries`] and the mapping functions, and keeps
+/// empty parts so that part `i` always corresponds to `boundaries[i]..boundaries[i + 1]`.
+///
+/// # Panics
+///
+/// Panics if the boundaries are not in ascending order or exceed the slice length.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::mapping::split_at_boundaries;
+///
+/// let data = [1, 2, 3, 4, 5];
+/// let parts: Vec<&[i32]> = split_at_boundaries(&data, &[0, 2, 2, 5]).collect();
+/// assert_eq!(parts, vec![&[1, 2][..], &[], &[3, 4, 5]]);
+/// ```
+pub fn split_at_boundaries<'a, T>(
+    data: &'a [T],
+    boundaries: &'a [usize],
+) -> impl Iterator<Item = &'a [T]> + use<'a, T> {
+    boundaries
+        .windows(2)
+        .map(move |pair| &data[pair[0]..pair[1]])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        map_boundaries_proportional, map_boundaries_with, part_boundaries, split_at_boundaries,
+    };
+    use crate::EqualParts;
+
+    #[test]
+    fn boundaries_match_equal_parts() {
+        let data: Vec<u8> = (0..17).collect();
+        for num_parts in 1..20 {
+            let boundaries = part_boundaries(data.len(), num_parts);
+            let ours: Vec<&[u8]> = split_at_boundaries(&data, &boundaries).collect();
+            let expected: Vec<&[u8]> = data.equal_parts(num_parts).collect();
+            assert_eq!(ours, expected);
+        }
+    }
+
+    #[test]
+    fn proportional_to_shorter_array() {
+        let boundaries = part_boundaries(9, 3);
+        assert_eq!(
+            map_boundaries_proportional(&boundaries, 9, 2),
+            vec![0, 1, 1, 2]
+        );
+    }
+
+    #[test]
+    fn proportional_does_not_overflow() {
+        let mapped = map_boundaries_proportional(&[0, usize::MAX / 2, usize::MAX], usize::MAX, 10);
+        assert_eq!(mapped, vec![0, 5, 10]);
+    }
+
+    #[test]
+    fn mapping_keeps_empty_targets() {
+        // The second source element maps to nothing in the target.
+        let mapped = map_boundaries_with(&[0, 1, 2, 3], &[0, 4, 4], 6);
+        assert_eq!(mapped, vec![0, 4, 4, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mapping_rejects_descending_starts() {
+        let _ = map_boundaries_with(&[0, 1, 2], &[0, 5], 3);
+    }
+}
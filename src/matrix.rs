@@ -0,0 +1,175 @@
+use std::fmt;
+
+use nalgebra::{Dim, Dyn, Matrix, MatrixView, RawStorage, Scalar};
+
+use crate::full_part_count;
+
+/// Splits a matrix into approximately equal blocks of rows.
+///
+/// The rows are divided into `num_parts` contiguous blocks with the same heights
+/// [`equal_parts`](crate::EqualParts::equal_parts) would give a slice of `nrows`
+/// elements, taller blocks first. Each block is a [`MatrixView`] borrowing the
+/// original, so nothing is copied, and since the views are `Send` whenever `T` is
+/// `Sync`, they can be processed on separate threads.
+///
+/// This works for any matrix or matrix view, including `DMatrix`, static matrices and
+/// the views returned by methods like `columns`.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::matrix::row_blocks;
+/// use nalgebra::DMatrix;
+///
+/// let matrix = DMatrix::from_fn(5, 3, |row, col| (row * 3 + col) as f64);
+/// let blocks: Vec<_> = row_blocks(&matrix, 2).collect();
+///
+/// assert_eq!(blocks.len(), 2);
+/// assert_eq!(blocks[0].shape(), (3, 3));
+/// assert_eq!(blocks[1].shape(), (2, 3));
+/// assert_eq!(blocks[1][(0, 0)], 9.0);
+/// ```
+pub fn row_blocks<T, R, C, S>(
+    matrix: &Matrix<T, R, C, S>,
+    num_parts: usize,
+) -> RowBlocks<'_, T, R, C, S>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    let nrows = matrix.nrows();
+    let part_size = nrows.div_ceil(num_parts);
+    RowBlocks {
+        matrix,
+        next_row: 0,
+        part_size,
+        full_parts_left: full_part_count(nrows, num_parts),
+    }
+}
+
+/// Iterator over approximately equal row blocks of a matrix.
+///
+/// This iterator is created by [`row_blocks`].
+pub struct RowBlocks<'a, T, R, C, S> {
+    matrix: &'a Matrix<T, R, C, S>,
+    next_row: usize,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<T, R, C, S> Clone for RowBlocks<'_, T, R, C, S> {
+    fn clone(&self) -> Self {
+        RowBlocks {
+            matrix: self.matrix,
+            next_row: self.next_row,
+            part_size: self.part_size,
+            full_parts_left: self.full_parts_left,
+        }
+    }
+}
+
+impl<T, R, C, S> fmt::Debug for RowBlocks<'_, T, R, C, S>
+where
+    Matrix<T, R, C, S>: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RowBlocks")
+            .field("matrix", self.matrix)
+            .field("next_row", &self.next_row)
+            .field("part_size", &self.part_size)
+            .field("full_parts_left", &self.full_parts_left)
+            .finish()
+    }
+}
+
+impl<'a, T, R, C, S> Iterator for RowBlocks<'a, T, R, C, S>
+where
+    T: Scalar,
+    R: Dim,
+    C: Dim,
+    S: RawStorage<T, R, C>,
+{
+    type Item = MatrixView<'a, T, Dyn, C, S::RStride, S::CStride>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_row == self.matrix.nrows() {
+            None
+        } else {
+            let height = self.part_size - (self.full_parts_left.min(1) ^ 1);
+            self.full_parts_left -= self.full_parts_left.min(1);
+
+            let block = self.matrix.rows(self.next_row, height);
+            self.next_row += height;
+            Some(block)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use nalgebra::{DMatrix, Matrix4x2};
+
+    use super::row_blocks;
+
+    #[test]
+    fn blocks_cover_all_rows() {
+        let matrix = DMatrix::from_fn(10, 4, |row, col| row * 4 + col);
+        let heights: Vec<usize> = row_blocks(&matrix, 4).map(|block| block.nrows()).collect();
+        assert_eq!(heights, vec![3, 3, 2, 2]);
+
+        let total: usize = row_blocks(&matrix, 4).map(|block| block.sum()).sum();
+        assert_eq!(total, matrix.sum());
+    }
+
+    #[test]
+    fn works_on_views_and_static_matrices() {
+        let matrix = Matrix4x2::new(1, 2, 3, 4, 5, 6, 7, 8);
+        let column = matrix.column(1);
+        let blocks: Vec<Vec<i32>> = row_blocks(&column, 3)
+            .map(|block| block.iter().copied().collect())
+            .collect();
+        assert_eq!(blocks, vec![vec![2, 4], vec![6], vec![8]]);
+    }
+
+    #[test]
+    fn blocks_on_threads() {
+        let matrix = DMatrix::from_element(7, 3, 1.0f64);
+        let sums: Vec<f64> = thread::scope(|scope| {
+            let handles: Vec<_> = row_blocks(&matrix, 3)
+                .map(|block| scope.spawn(move || block.sum()))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| handle.join().unwrap())
+                .collect()
+        });
+        assert_eq!(sums, vec![9.0, 6.0, 6.0]);
+    }
+
+    #[test]
+    fn fewer_rows_than_parts() {
+        let matrix = DMatrix::<u8>::zeros(2, 5);
+        assert_eq!(row_blocks(&matrix, 4).count(), 2);
+        assert_eq!(row_blocks(&DMatrix::<u8>::zeros(0, 5), 4).count(), 0);
+    }
+
+    #[test]
+    fn cloned_blocks_resume_at_the_same_block() {
+        let matrix = Matrix4x2::from_fn(|row, col| row * 2 + col);
+        let mut blocks = row_blocks(&matrix, 3);
+        blocks.next();
+        let mut copy = blocks.clone();
+        assert_eq!(copy.next().unwrap()[(0, 0)], 4);
+        assert_eq!(blocks.next().unwrap()[(0, 0)], 4);
+    }
+}
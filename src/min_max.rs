@@ -0,0 +1,128 @@
+use std::ops::Range;
+
+/// A cost that can be summed without overflowing by widening it to `u128`.
+pub(crate) trait Cost: Copy {
+    fn widen(self) -> u128;
+}
+
+impl Cost for u64 {
+    fn widen(self) -> u128 {
+        self.into()
+    }
+}
+
+impl Cost for usize {
+    fn widen(self) -> u128 {
+        self as u128
+    }
+}
+
+/// Counts the parts produced by greedily packing `costs` into parts whose totals are
+/// at most `limit`.
+///
+/// A cost larger than `limit` still gets a part of its own.
+pub(crate) fn greedy_part_count<C: Cost>(costs: &[C], limit: u128) -> usize {
+    let mut parts = 0;
+    let mut current = 0u128;
+    for &cost in costs {
+        if parts == 0 || current + cost.widen() > limit {
+            parts += 1;
+            current = 0;
+        }
+        current += cost.widen();
+    }
+    parts
+}
+
+/// Splits `costs` into `min(num_parts, costs.len())` contiguous non-empty ranges,
+/// minimizing the largest range total.
+pub(crate) fn min_max_ranges<C: Cost>(costs: &[C], num_parts: usize) -> Vec<Range<usize>> {
+    if costs.is_empty() {
+        return Vec::new();
+    }
+    let num_parts = num_parts.min(costs.len());
+
+    // The smallest limit that greedy packing can meet with `num_parts` parts is the
+    // optimal largest part total.
+    let mut low = costs.iter().map(|cost| cost.widen()).max().unwrap_or(0);
+    let mut high: u128 = costs.iter().map(|cost| cost.widen()).sum();
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if greedy_part_count(costs, mid) <= num_parts {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    // Pack greedily under that limit, cutting early once the remaining elements are
+    // only just enough to give every remaining part one element.
+    let mut ranges = Vec::with_capacity(num_parts);
+    let mut start = 0;
+    let mut current = 0u128;
+    for (index, &cost) in costs.iter().enumerate() {
+        let parts_after = num_parts - ranges.len() - 1;
+        if index > start && (current + cost.widen() > low || costs.len() - index == parts_after) {
+            ranges.push(start..index);
+            start = index;
+            current = 0;
+        }
+        current += cost.widen();
+    }
+    ranges.push(start..costs.len());
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{greedy_part_count, min_max_ranges};
+
+    /// Finds the optimal largest part total by trying every split.
+    fn brute_force(costs: &[u64], num_parts: usize) -> u64 {
+        if num_parts == 1 {
+            return costs.iter().sum();
+        }
+        (1..=costs.len() - (num_parts - 1))
+            .map(|first| {
+                let head: u64 = costs[..first].iter().sum();
+                head.max(brute_force(&costs[first..], num_parts - 1))
+            })
+            .min()
+            .unwrap()
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        let mut seed = 17u64;
+        for _ in 0..300 {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            let len = (seed >> 60) as usize + 1;
+            let costs: Vec<u64> = (0..len)
+                .map(|i| (seed.rotate_left(i as u32 * 7) % 50) * ((i as u64 % 3) * 20 + 1))
+                .collect();
+            for num_parts in 1..=len {
+                let ranges = min_max_ranges(&costs, num_parts);
+                assert_eq!(ranges.len(), num_parts);
+                assert!(ranges.iter().all(|range| !range.is_empty()));
+                let heaviest = ranges
+                    .iter()
+                    .map(|range| costs[range.clone()].iter().sum::<u64>())
+                    .max()
+                    .unwrap();
+                assert_eq!(heaviest, brute_force(&costs, num_parts));
+            }
+        }
+    }
+
+    #[test]
+    fn totals_do_not_overflow() {
+        let costs = [u64::MAX; 4];
+        assert_eq!(min_max_ranges(&costs, 2), vec![0..2, 2..4]);
+        assert_eq!(greedy_part_count(&costs, 2 * u64::MAX as u128), 2);
+
+        let costs = [usize::MAX; 3];
+        assert_eq!(min_max_ranges(&costs, 3), vec![0..1, 1..2, 2..3]);
+    }
+}
@@ -0,0 +1,159 @@
+use std::mem;
+
+use crate::full_part_count;
+
+/// A trait for splitting mutable collections into approximately equal mutable parts.
+///
+/// This trait is the mutable counterpart of [`EqualParts`](crate::EqualParts), in the
+/// same way that [`chunks_mut`](slice::chunks_mut) is the counterpart of
+/// [`chunks`](slice::chunks). The parts do not overlap, so each one can be handed to
+/// its own scoped thread and modified in place.
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+///
+/// use equal_parts::EqualPartsMut;
+///
+/// let mut data = vec![1, 2, 3, 4, 5, 6, 7];
+/// thread::scope(|scope| {
+///     for part in data.equal_parts_mut(3) {
+///         scope.spawn(move || part.iter_mut().for_each(|x| *x *= 10));
+///     }
+/// });
+/// assert_eq!(data, vec![10, 20, 30, 40, 50, 60, 70]);
+/// ```
+pub trait EqualPartsMut {
+    /// The type of items yielded by the iterator.
+    type Item;
+
+    /// The iterator type returned by [`equal_parts_mut`](Self::equal_parts_mut).
+    type IterMut: Iterator<Item = Self::Item>;
+
+    /// Splits the collection into approximately equal mutable parts.
+    ///
+    /// The parts have the same sizes as those produced by
+    /// [`equal_parts`](crate::EqualParts::equal_parts), with larger parts first.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_parts` - The number of parts to split the collection into.
+    ///   Must be greater than 0.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::EqualPartsMut;
+    ///
+    /// let mut data = [1, 2, 3, 4, 5];
+    /// let mut parts = data.as_mut_slice().equal_parts_mut(2);
+    ///
+    /// parts.next().unwrap().reverse();
+    /// parts.next().unwrap().fill(0);
+    /// assert!(parts.next().is_none());
+    /// assert_eq!(data, [3, 2, 1, 0, 0]);
+    /// ```
+    fn equal_parts_mut(self, num_parts: usize) -> Self::IterMut;
+}
+
+/// Iterator that yields approximately equal mutable parts of a slice.
+///
+/// This iterator is created by calling [`equal_parts_mut`](EqualPartsMut::equal_parts_mut)
+/// on a mutable slice. It yields each part as a `&mut [T]` slice reference.
+#[derive(Debug)]
+pub struct EqualPartsMutIter<'a, T> {
+    data: &'a mut [T],
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<'a, T> Iterator for EqualPartsMutIter<'a, T> {
+    type Item = &'a mut [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.is_empty() {
+            None
+        } else {
+            let split_point = self.part_size - (self.full_parts_left.min(1) ^ 1);
+            self.full_parts_left -= self.full_parts_left.min(1);
+
+            let (chunk, rest) = mem::take(&mut self.data).split_at_mut(split_point);
+            self.data = rest;
+            Some(chunk)
+        }
+    }
+}
+
+impl<'a, T> EqualPartsMut for &'a mut [T] {
+    type Item = &'a mut [T];
+    type IterMut = EqualPartsMutIter<'a, T>;
+
+    fn equal_parts_mut(self, num_parts: usize) -> Self::IterMut {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        EqualPartsMutIter {
+            part_size: self.len().div_ceil(num_parts),
+            full_parts_left: full_part_count(self.len(), num_parts),
+            data: self,
+        }
+    }
+}
+
+impl<'a, T> EqualPartsMut for &'a mut Vec<T> {
+    type Item = &'a mut [T];
+    type IterMut = EqualPartsMutIter<'a, T>;
+
+    fn equal_parts_mut(self, num_parts: usize) -> Self::IterMut {
+        self.as_mut_slice().equal_parts_mut(num_parts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EqualPartsMut;
+    use crate::EqualParts;
+
+    #[test]
+    fn same_sizes_as_equal_parts() {
+        let mut data: Vec<u32> = (0..19).collect();
+        let expected: Vec<Vec<u32>> = data.equal_parts(5).map(<[u32]>::to_vec).collect();
+        let actual: Vec<Vec<u32>> = data.equal_parts_mut(5).map(|part| part.to_vec()).collect();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn not_enough_parts() {
+        let mut data = [1, 2];
+        let mut parts = data.as_mut_slice().equal_parts_mut(3);
+        assert_eq!(parts.next(), Some([1].as_mut_slice()));
+        assert_eq!(parts.next(), Some([2].as_mut_slice()));
+        assert_eq!(parts.next(), None);
+    }
+
+    #[test]
+    fn mutations_apply_in_place() {
+        let mut data = vec![0; 6];
+        for (i, part) in data.equal_parts_mut(3).enumerate() {
+            part.fill(i);
+        }
+        assert_eq!(data, vec![0, 0, 1, 1, 2, 2]);
+    }
+
+    #[test]
+    fn empty_data() {
+        let mut data: Vec<i32> = vec![];
+        assert_eq!(data.equal_parts_mut(3).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let mut data = [1, 2, 3];
+        let _ = data.as_mut_slice().equal_parts_mut(0);
+    }
+}
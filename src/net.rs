@@ -0,0 +1,366 @@
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr};
+use std::ops::RangeInclusive;
+
+mod private {
+    pub trait Sealed {}
+    impl Sealed for std::net::Ipv4Addr {}
+    impl Sealed for std::net::Ipv6Addr {}
+}
+
+/// An IP address type that can be split into ranges.
+///
+/// This trait is sealed and implemented for [`Ipv4Addr`] and [`Ipv6Addr`].
+pub trait IpAddress: Copy + Ord + private::Sealed {
+    /// The number of bits in the address.
+    const BITS: u32;
+
+    /// Converts the address to its numeric value.
+    fn to_bits(self) -> u128;
+
+    /// Converts a numeric value back to an address.
+    fn from_bits(bits: u128) -> Self;
+}
+
+impl IpAddress for Ipv4Addr {
+    const BITS: u32 = 32;
+
+    fn to_bits(self) -> u128 {
+        u32::from(self) as u128
+    }
+
+    fn from_bits(bits: u128) -> Self {
+        Ipv4Addr::from(bits as u32)
+    }
+}
+
+impl IpAddress for Ipv6Addr {
+    const BITS: u32 = 128;
+
+    fn to_bits(self) -> u128 {
+        u128::from(self)
+    }
+
+    fn from_bits(bits: u128) -> Self {
+        Ipv6Addr::from(bits)
+    }
+}
+
+/// A CIDR block: a network address and a prefix length.
+///
+/// Formats as `address/prefix_len`.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use equal_parts::net::Cidr;
+///
+/// let block = Cidr::new(Ipv4Addr::new(10, 1, 2, 3), 16);
+/// assert_eq!(block.to_string(), "10.1.0.0/16");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Cidr<A> {
+    /// The network address, with all host bits cleared.
+    pub addr: A,
+    /// The number of leading bits that identify the network.
+    pub prefix_len: u8,
+}
+
+impl<A: IpAddress> Cidr<A> {
+    /// Creates a CIDR block, clearing any host bits set in `addr`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `prefix_len` is larger than the number of bits in the address.
+    pub fn new(addr: A, prefix_len: u8) -> Self {
+        assert!(
+            u32::from(prefix_len) <= A::BITS,
+            "Prefix length is longer than the address"
+        );
+        let host_mask = host_mask(A::BITS - u32::from(prefix_len));
+        Cidr {
+            addr: A::from_bits(addr.to_bits() & !host_mask),
+            prefix_len,
+        }
+    }
+
+    /// Returns the first and last address in the block.
+    pub fn range(&self) -> RangeInclusive<A> {
+        let start = self.addr.to_bits();
+        let end = start | host_mask(A::BITS - u32::from(self.prefix_len));
+        A::from_bits(start)..=A::from_bits(end)
+    }
+}
+
+impl<A: fmt::Display> fmt::Display for Cidr<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+/// Returns a mask with the lowest `host_bits` bits set.
+fn host_mask(host_bits: u32) -> u128 {
+    u128::MAX.checked_shr(128 - host_bits).unwrap_or(0)
+}
+
+/// Splits an inclusive IP address range into approximately equal sub-ranges.
+///
+/// Works like [`equal_parts`](crate::EqualParts::equal_parts) on the addresses in the
+/// range: the sub-ranges are contiguous, cover the whole range, differ in size by at
+/// most one address, and larger sub-ranges come first. If the range holds fewer
+/// addresses than `num_parts`, only one sub-range per address is yielded. An empty
+/// range (start after end) yields nothing.
+///
+/// The full IPv6 address space is supported even though its size does not fit in a
+/// `u128`.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use equal_parts::net::ip_range_parts;
+///
+/// let start = Ipv4Addr::new(192, 168, 0, 0);
+/// let end = Ipv4Addr::new(192, 168, 0, 9);
+/// let mut parts = ip_range_parts(start..=end, 3);
+///
+/// assert_eq!(parts.next(), Some(start..=Ipv4Addr::new(192, 168, 0, 3)));
+/// assert_eq!(parts.next(), Some(Ipv4Addr::new(192, 168, 0, 4)..=Ipv4Addr::new(192, 168, 0, 6)));
+/// assert_eq!(parts.next(), Some(Ipv4Addr::new(192, 168, 0, 7)..=end));
+/// assert_eq!(parts.next(), None);
+/// ```
+pub fn ip_range_parts<A: IpAddress>(range: RangeInclusive<A>, num_parts: usize) -> IpRangeParts<A> {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    let (start, end) = range.into_inner();
+    if start > end {
+        return IpRangeParts {
+            next: 0,
+            step: 0,
+            large_parts_left: 0,
+            small_parts_left: 0,
+            _addr: std::marker::PhantomData,
+        };
+    }
+
+    // Work from the span (length - 1) so that the full IPv6 space cannot overflow.
+    // The first `large_parts` parts get `step + 1` addresses and the rest get `step`.
+    let span = end.to_bits() - start.to_bits();
+    let num_parts = num_parts as u128;
+    let step = span / num_parts;
+    let large_parts = span % num_parts + 1;
+    let small_parts = if step == 0 {
+        0
+    } else {
+        num_parts - large_parts
+    };
+
+    IpRangeParts {
+        next: start.to_bits(),
+        step,
+        large_parts_left: large_parts,
+        small_parts_left: small_parts,
+        _addr: std::marker::PhantomData,
+    }
+}
+
+/// Splits a CIDR block into approximately equal sub-ranges.
+///
+/// Host bits set in `addr` are ignored. See [`ip_range_parts`] for how the block is
+/// divided.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0 or if `prefix_len` is larger than the number of bits in
+/// the address.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv6Addr;
+/// use equal_parts::net::cidr_parts;
+///
+/// let network: Ipv6Addr = "2001:db8::".parse().unwrap();
+/// let parts: Vec<_> = cidr_parts(network, 126, 2).collect();
+///
+/// assert_eq!(parts, vec![
+///     "2001:db8::".parse().unwrap()..="2001:db8::1".parse().unwrap(),
+///     "2001:db8::2".parse().unwrap()..="2001:db8::3".parse().unwrap(),
+/// ]);
+/// ```
+pub fn cidr_parts<A: IpAddress>(addr: A, prefix_len: u8, num_parts: usize) -> IpRangeParts<A> {
+    ip_range_parts(Cidr::new(addr, prefix_len).range(), num_parts)
+}
+
+/// Converts an inclusive IP address range into the smallest list of CIDR blocks that
+/// exactly covers it.
+///
+/// This is useful for turning the sub-ranges produced by [`ip_range_parts`] into
+/// entries for firewalls, routing tables, or scanners that only accept CIDR notation.
+///
+/// # Examples
+///
+/// ```
+/// use std::net::Ipv4Addr;
+/// use equal_parts::net::range_to_cidrs;
+///
+/// let start = Ipv4Addr::new(10, 0, 0, 1);
+/// let end = Ipv4Addr::new(10, 0, 0, 6);
+/// let blocks: Vec<String> = range_to_cidrs(start..=end).iter().map(|b| b.to_string()).collect();
+///
+/// assert_eq!(blocks, vec!["10.0.0.1/32", "10.0.0.2/31", "10.0.0.4/31", "10.0.0.6/32"]);
+/// ```
+pub fn range_to_cidrs<A: IpAddress>(range: RangeInclusive<A>) -> Vec<Cidr<A>> {
+    let (start, end) = range.into_inner();
+    let mut blocks = Vec::new();
+    if start > end {
+        return blocks;
+    }
+
+    let mut next = start.to_bits();
+    let end = end.to_bits();
+    loop {
+        let span = end - next;
+        // The largest block is limited both by the alignment of `next` and by the
+        // number of addresses left in the range.
+        let size_bits = match span.checked_add(1) {
+            Some(len) => 127 - len.leading_zeros(),
+            None => 128,
+        };
+        let host_bits = next.trailing_zeros().min(size_bits).min(A::BITS);
+
+        blocks.push(Cidr {
+            addr: A::from_bits(next),
+            prefix_len: (A::BITS - host_bits) as u8,
+        });
+
+        let last = next | host_mask(host_bits);
+        if last == end {
+            return blocks;
+        }
+        next = last + 1;
+    }
+}
+
+/// Iterator over the sub-ranges of an IP address range.
+///
+/// This iterator is created by [`ip_range_parts`] or [`cidr_parts`].
+#[derive(Debug, Clone)]
+pub struct IpRangeParts<A> {
+    next: u128,
+    step: u128,
+    large_parts_left: u128,
+    small_parts_left: u128,
+    _addr: std::marker::PhantomData<A>,
+}
+
+impl<A: IpAddress> Iterator for IpRangeParts<A> {
+    type Item = RangeInclusive<A>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let span = if self.large_parts_left > 0 {
+            self.large_parts_left -= 1;
+            self.step
+        } else if self.small_parts_left > 0 {
+            self.small_parts_left -= 1;
+            self.step - 1
+        } else {
+            return None;
+        };
+
+        let start = self.next;
+        let end = start + span;
+        // Wrapping only happens after the very last address, when no parts remain.
+        self.next = end.wrapping_add(1);
+        Some(A::from_bits(start)..=A::from_bits(end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Cidr, cidr_parts, ip_range_parts, range_to_cidrs};
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    fn v4(last: u8) -> Ipv4Addr {
+        Ipv4Addr::new(10, 0, 0, last)
+    }
+
+    #[test]
+    fn even_ipv4_parts() {
+        let parts: Vec<_> = ip_range_parts(v4(0)..=v4(7), 4).collect();
+        assert_eq!(
+            parts,
+            vec![v4(0)..=v4(1), v4(2)..=v4(3), v4(4)..=v4(5), v4(6)..=v4(7)]
+        );
+    }
+
+    #[test]
+    fn more_parts_than_addresses() {
+        let parts: Vec<_> = ip_range_parts(v4(1)..=v4(2), 5).collect();
+        assert_eq!(parts, vec![v4(1)..=v4(1), v4(2)..=v4(2)]);
+    }
+
+    #[test]
+    fn empty_range() {
+        #[allow(clippy::reversed_empty_ranges)]
+        let mut parts = ip_range_parts(v4(2)..=v4(1), 3);
+        assert_eq!(parts.next(), None);
+    }
+
+    #[test]
+    fn whole_ipv4_space() {
+        let parts: Vec<_> =
+            ip_range_parts(Ipv4Addr::UNSPECIFIED..=Ipv4Addr::BROADCAST, 4).collect();
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts[3].end(), &Ipv4Addr::BROADCAST);
+        assert_eq!(parts[1].start(), &Ipv4Addr::new(64, 0, 0, 0));
+    }
+
+    #[test]
+    fn whole_ipv6_space() {
+        let parts: Vec<_> = cidr_parts(Ipv6Addr::UNSPECIFIED, 0, 2).collect();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(parts[0].end(), &Ipv6Addr::from(u128::MAX >> 1));
+        assert_eq!(parts[1].end(), &Ipv6Addr::from(u128::MAX));
+
+        let whole: Vec<_> = cidr_parts(Ipv6Addr::UNSPECIFIED, 0, 1).collect();
+        assert_eq!(
+            whole,
+            vec![Ipv6Addr::UNSPECIFIED..=Ipv6Addr::from(u128::MAX)]
+        );
+    }
+
+    #[test]
+    fn cidr_ignores_host_bits() {
+        let parts: Vec<_> = cidr_parts(v4(77), 30, 2).collect();
+        assert_eq!(parts, vec![v4(76)..=v4(77), v4(78)..=v4(79)]);
+    }
+
+    #[test]
+    fn parts_convert_back_to_cidrs() {
+        let blocks: Vec<Vec<Cidr<Ipv4Addr>>> = cidr_parts(Ipv4Addr::new(10, 0, 0, 0), 8, 4)
+            .map(range_to_cidrs)
+            .collect();
+        assert_eq!(blocks[0], vec![Cidr::new(Ipv4Addr::new(10, 0, 0, 0), 10)]);
+        assert_eq!(blocks[3], vec![Cidr::new(Ipv4Addr::new(10, 192, 0, 0), 10)]);
+    }
+
+    #[test]
+    fn whole_space_is_one_cidr() {
+        let blocks = range_to_cidrs(Ipv4Addr::UNSPECIFIED..=Ipv4Addr::BROADCAST);
+        assert_eq!(blocks, vec![Cidr::new(Ipv4Addr::UNSPECIFIED, 0)]);
+        let blocks = range_to_cidrs(Ipv6Addr::UNSPECIFIED..=Ipv6Addr::from(u128::MAX));
+        assert_eq!(blocks, vec![Cidr::new(Ipv6Addr::UNSPECIFIED, 0)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = ip_range_parts(v4(0)..=v4(7), 0);
+    }
+}
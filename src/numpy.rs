@@ -0,0 +1,140 @@
+/// Computes the part boundaries NumPy's `array_split` uses for `num_sections` parts.
+///
+/// Returns `num_sections + 1` offsets, where part `i` spans
+/// `boundaries[i]..boundaries[i + 1]`. This reproduces the `div_points` NumPy computes
+/// internally, so Rust and Python code exchanging part indices agree exactly on where
+/// each part starts.
+///
+/// NumPy places the larger parts first, like [`equal_parts`](crate::EqualParts), but
+/// always produces exactly `num_sections` parts, including empty ones at the end when
+/// there are fewer elements than sections.
+///
+/// # Panics
+///
+/// Panics if `num_sections` is 0, matching the `ValueError` raised by NumPy.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::numpy::array_split_boundaries;
+///
+/// // np.array_split(np.arange(10), 4) gives parts of 3, 3, 2 and 2 elements.
+/// assert_eq!(array_split_boundaries(10, 4), vec![0, 3, 6, 8, 10]);
+///
+/// // np.array_split(np.arange(2), 4) gives parts of 1, 1, 0 and 0 elements.
+/// assert_eq!(array_split_boundaries(2, 4), vec![0, 1, 2, 2, 2]);
+/// ```
+pub fn array_split_boundaries(len: usize, num_sections: usize) -> Vec<usize> {
+    assert!(
+        num_sections > 0,
+        "Number of sections must be greater than 0"
+    );
+
+    let each = len / num_sections;
+    let extras = len % num_sections;
+
+    let mut boundaries = Vec::with_capacity(num_sections + 1);
+    let mut offset = 0;
+    boundaries.push(offset);
+    for section in 0..num_sections {
+        offset += if section < extras { each + 1 } else { each };
+        boundaries.push(offset);
+    }
+    boundaries
+}
+
+/// Splits a slice exactly like NumPy's `array_split` with an integer section count.
+///
+/// Always yields `num_sections` parts, which may be empty when the slice has fewer
+/// elements than sections. See [`array_split_boundaries`] for the boundary placement.
+///
+/// # Panics
+///
+/// Panics if `num_sections` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::numpy::array_split;
+///
+/// let data = [1, 2, 3, 4, 5, 6, 7];
+/// let parts: Vec<&[i32]> = array_split(&data, 3).collect();
+/// assert_eq!(parts, vec![&[1, 2, 3][..], &[4, 5], &[6, 7]]);
+///
+/// let parts: Vec<&[i32]> = array_split(&data[..2], 3).collect();
+/// assert_eq!(parts, vec![&[1][..], &[2], &[]]);
+/// ```
+pub fn array_split<T>(data: &[T], num_sections: usize) -> ArraySplit<'_, T> {
+    let boundaries = array_split_boundaries(data.len(), num_sections);
+    ArraySplit {
+        data,
+        boundaries,
+        next: 0,
+    }
+}
+
+/// Iterator over the parts of a slice split like NumPy's `array_split`.
+///
+/// This iterator is created by [`array_split`].
+#[derive(Debug, Clone)]
+pub struct ArraySplit<'a, T> {
+    data: &'a [T],
+    boundaries: Vec<usize>,
+    next: usize,
+}
+
+impl<'a, T> Iterator for ArraySplit<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let start = *self.boundaries.get(self.next)?;
+        let end = *self.boundaries.get(self.next + 1)?;
+        self.next += 1;
+        Some(&self.data[start..end])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let left = self.boundaries.len() - 1 - self.next;
+        (left, Some(left))
+    }
+}
+
+impl<T> ExactSizeIterator for ArraySplit<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::{array_split, array_split_boundaries};
+    use crate::EqualParts;
+
+    #[test]
+    fn matches_numpy_reference() {
+        // Values of np.cumsum of the section sizes computed by np.array_split.
+        assert_eq!(array_split_boundaries(11, 3), vec![0, 4, 8, 11]);
+        assert_eq!(array_split_boundaries(0, 2), vec![0, 0, 0]);
+        assert_eq!(array_split_boundaries(5, 1), vec![0, 5]);
+    }
+
+    #[test]
+    fn agrees_with_equal_parts_when_not_short() {
+        let data: Vec<i32> = (0..23).collect();
+        for sections in 1..=23 {
+            let numpy: Vec<&[i32]> = array_split(&data, sections).collect();
+            let ours: Vec<&[i32]> = data.equal_parts(sections).collect();
+            assert_eq!(numpy, ours);
+        }
+    }
+
+    #[test]
+    fn always_yields_every_section() {
+        let data = [1];
+        let parts = array_split(&data, 4);
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts.filter(|part| part.is_empty()).count(), 3);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_sections() {
+        let _ = array_split_boundaries(3, 0);
+    }
+}
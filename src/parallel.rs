@@ -0,0 +1,161 @@
+#[cfg(not(any(
+    feature = "serial",
+    all(
+        target_family = "wasm",
+        not(all(target_os = "wasi", target_feature = "atomics"))
+    )
+)))]
+use std::thread;
+use std::time::Duration;
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+use std::time::Instant;
+
+use crate::EqualParts;
+
+/// Splits `data` into approximately equal parts and maps `f` over each part on its own
+/// scoped thread.
+///
+/// The data is split with [`equal_parts`](EqualParts::equal_parts), and the results
+/// are returned in part order, one per part.
+///
+/// Where the standard library cannot start threads, the parts are processed one after
+/// another on the calling thread instead, so the same code runs unchanged in a browser.
+/// This is the case on wasm targets other than `wasm32-wasip1-threads`, including
+/// `wasm32-unknown-unknown` even when built with the `atomics` target feature, since
+/// its threads are Web Workers started by JavaScript. Enabling the `serial` feature
+/// processes the parts on the calling thread on every target, which helps when
+/// debugging or when the caller already runs inside a thread pool.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0, or if `f` panics on any part.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::parallel::map_parts;
+///
+/// let data: Vec<u64> = (1..=10).collect();
+/// let sums = map_parts(&data, 3, |part| part.iter().sum::<u64>());
+///
+/// assert_eq!(sums, vec![10, 18, 27]);
+/// ```
+pub fn map_parts<T, R, F>(data: &[T], num_parts: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&[T]) -> R + Sync,
+{
+    let f = &f;
+    scoped_map(data.equal_parts(num_parts).map(|part| move || f(part)))
+}
+
+/// Runs each task on its own scoped thread and returns the results in order.
+///
+/// A panic in any task is resumed on the calling thread.
+#[cfg(not(any(
+    feature = "serial",
+    all(
+        target_family = "wasm",
+        not(all(target_os = "wasi", target_feature = "atomics"))
+    )
+)))]
+pub(crate) fn scoped_map<R, F>(tasks: impl IntoIterator<Item = F>) -> Vec<R>
+where
+    R: Send,
+    F: FnOnce() -> R + Send,
+{
+    thread::scope(|scope| {
+        let handles: Vec<_> = tasks.into_iter().map(|task| scope.spawn(task)).collect();
+        handles
+            .into_iter()
+            .map(|handle| {
+                handle
+                    .join()
+                    .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+            })
+            .collect()
+    })
+}
+
+/// Runs the tasks one after another on the calling thread, where threads are not
+/// available or the `serial` feature is enabled.
+#[cfg(any(
+    feature = "serial",
+    all(
+        target_family = "wasm",
+        not(all(target_os = "wasi", target_feature = "atomics"))
+    )
+))]
+pub(crate) fn scoped_map<R, F>(tasks: impl IntoIterator<Item = F>) -> Vec<R>
+where
+    R: Send,
+    F: FnOnce() -> R + Send,
+{
+    tasks.into_iter().map(|task| task()).collect()
+}
+
+/// Runs `f` and measures how long it took with the wall clock.
+///
+/// The duration is `None` on `wasm32-unknown-unknown`, where the standard library has
+/// no clock and [`Instant::now`] panics.
+#[cfg(not(all(target_family = "wasm", target_os = "unknown")))]
+pub(crate) fn timed<R>(f: impl FnOnce() -> R) -> (R, Option<Duration>) {
+    let start = Instant::now();
+    let result = f();
+    (result, Some(start.elapsed()))
+}
+
+/// Runs `f` without timing it, since no clock is available on this target.
+#[cfg(all(target_family = "wasm", target_os = "unknown"))]
+pub(crate) fn timed<R>(f: impl FnOnce() -> R) -> (R, Option<Duration>) {
+    (f(), None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_parts, scoped_map, timed};
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    #[test]
+    fn results_are_in_part_order() {
+        let data: Vec<usize> = (0..100).collect();
+        let firsts = map_parts(&data, 7, |part| part[0]);
+        assert_eq!(firsts, vec![0, 15, 30, 44, 58, 72, 86]);
+
+        let empty: [u8; 0] = [];
+        assert!(map_parts(&empty, 4, <[u8]>::len).is_empty());
+    }
+
+    #[test]
+    fn tasks_see_borrowed_data() {
+        let words = ["a", "bb", "ccc"];
+        let lens = scoped_map(words.iter().map(|word| move || word.len()));
+        assert_eq!(lens, vec![1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "part failed")]
+    fn panics_are_resumed() {
+        let _ = map_parts(&[1, 2, 3], 3, |part| {
+            assert!(part[0] != 2, "part failed");
+        });
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = map_parts(&[1], 0, <[i32]>::len);
+    }
+
+    #[test]
+    fn timed_returns_result_and_duration() {
+        let (value, elapsed) = timed(|| {
+            sleep(Duration::from_millis(2));
+            7
+        });
+        assert_eq!(value, 7);
+        assert!(elapsed.unwrap() >= Duration::from_millis(2));
+    }
+}
@@ -0,0 +1,267 @@
+use std::ops::Range;
+
+use crate::placement::{PartSizes, PlacedParts, RemainderPlacement, part_sizes};
+
+/// A builder that resolves several constraints on a split into a concrete [`Plan`].
+///
+/// Each constraint is optional:
+///
+/// - [`parts`](Self::parts) sets the preferred number of parts. Without it, the plan
+///   uses as few parts as [`max_len`](Self::max_len) allows, or a single part.
+/// - [`min_len`](Self::min_len) lowers the number of parts until no part is shorter
+///   than the minimum, as long as there is more than one part.
+/// - [`max_len`](Self::max_len) raises the number of parts until no part is longer than
+///   the maximum. This is a hard limit and takes precedence over the other two, so a
+///   plan never breaks it.
+/// - [`remainder`](Self::remainder) chooses where the larger parts go.
+///
+/// The parts of the resulting plan are always balanced, with sizes that differ by at
+/// most one.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::partitioner::Partitioner;
+/// use equal_parts::placement::RemainderPlacement;
+///
+/// let partitioner = Partitioner::new()
+///     .parts(8)
+///     .min_len(64)
+///     .max_len(4096)
+///     .remainder(RemainderPlacement::Back);
+///
+/// // Too little data for 8 parts of 64 elements.
+/// assert_eq!(partitioner.build(300).num_parts(), 4);
+/// // Enough for 8 parts.
+/// assert_eq!(partitioner.build(10_000).num_parts(), 8);
+/// // So much that 8 parts would break the maximum.
+/// assert_eq!(partitioner.build(100_000).num_parts(), 25);
+///
+/// let data: Vec<u32> = (0..300).collect();
+/// let lens: Vec<usize> = partitioner.build(data.len()).split(&data).map(<[u32]>::len).collect();
+/// assert_eq!(lens, vec![75, 75, 75, 75]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Partitioner {
+    parts: Option<usize>,
+    min_len: usize,
+    max_len: Option<usize>,
+    remainder: RemainderPlacement,
+}
+
+impl Partitioner {
+    /// Creates a partitioner with no constraints, which plans a single part.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the preferred number of parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    pub fn parts(mut self, num_parts: usize) -> Self {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+        self.parts = Some(num_parts);
+        self
+    }
+
+    /// Sets the minimum number of elements in a part, unless there is only one.
+    pub fn min_len(mut self, min_len: usize) -> Self {
+        self.min_len = min_len;
+        self
+    }
+
+    /// Sets the maximum number of elements in a part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_len` is 0.
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        assert!(max_len > 0, "Maximum part length must be greater than 0");
+        self.max_len = Some(max_len);
+        self
+    }
+
+    /// Sets where the larger parts go when the elements don't divide evenly.
+    pub fn remainder(mut self, placement: RemainderPlacement) -> Self {
+        self.remainder = placement;
+        self
+    }
+
+    /// Resolves the constraints for `len` elements into a plan.
+    pub fn build(&self, len: usize) -> Plan {
+        let fewest_parts = self
+            .max_len
+            .map_or(1, |max_len| len.div_ceil(max_len).max(1));
+        let mut num_parts = self.parts.unwrap_or(fewest_parts);
+        if let Some(most_parts) = len.checked_div(self.min_len) {
+            num_parts = num_parts.min(most_parts).max(1);
+        }
+        Plan {
+            len,
+            num_parts: num_parts.max(fewest_parts),
+            placement: self.remainder,
+        }
+    }
+}
+
+/// A concrete split of a known number of elements, created by [`Partitioner::build`].
+///
+/// A plan only describes the part sizes, so it can be computed once and then used to
+/// split several collections of the same length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Plan {
+    len: usize,
+    num_parts: usize,
+    placement: RemainderPlacement,
+}
+
+impl Plan {
+    /// Returns the number of elements the plan splits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the plan splits no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of non-empty parts in the plan.
+    pub fn num_parts(&self) -> usize {
+        self.num_parts.min(self.len)
+    }
+
+    /// Returns the sizes of the parts, in order.
+    pub fn sizes(&self) -> PartSizes {
+        part_sizes(self.len, self.num_parts, self.placement)
+    }
+
+    /// Returns the index ranges of the parts, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::partitioner::Partitioner;
+    ///
+    /// let plan = Partitioner::new().max_len(4).build(10);
+    /// assert_eq!(plan.ranges().collect::<Vec<_>>(), vec![0..4, 4..7, 7..10]);
+    /// ```
+    pub fn ranges(&self) -> impl Iterator<Item = Range<usize>> + use<> {
+        self.sizes().scan(0, |start, size| {
+            let range = *start..*start + size;
+            *start = range.end;
+            Some(range)
+        })
+    }
+
+    /// Splits a slice according to the plan.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` differs from the length the plan was built for.
+    pub fn split<'a, T>(&self, data: &'a [T]) -> PlacedParts<'a, T> {
+        self.check_len(data.len());
+        PlacedParts::new(data, self.sizes())
+    }
+
+    /// Splits a `Vec` into owned parts according to the plan.
+    ///
+    /// Each element is moved once, however many parts there are.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `data` differs from the length the plan was built for.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::partitioner::Partitioner;
+    ///
+    /// let plan = Partitioner::new().parts(3).build(7);
+    /// let parts = plan.split_vec(vec![1, 2, 3, 4, 5, 6, 7]);
+    /// assert_eq!(parts, vec![vec![1, 2, 3], vec![4, 5], vec![6, 7]]);
+    /// ```
+    pub fn split_vec<T>(&self, mut data: Vec<T>) -> Vec<Vec<T>> {
+        self.check_len(data.len());
+        let sizes: Vec<usize> = self.sizes().collect();
+        // Splitting off the back keeps every element from being moved more than once.
+        let mut parts: Vec<Vec<T>> = sizes
+            .iter()
+            .rev()
+            .map(|size| data.split_off(data.len() - size))
+            .collect();
+        parts.reverse();
+        parts
+    }
+
+    fn check_len(&self, len: usize) {
+        assert_eq!(
+            len, self.len,
+            "Data length must match the length the plan was built for"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Partitioner;
+    use crate::EqualParts;
+    use crate::placement::RemainderPlacement;
+
+    #[test]
+    fn no_constraints_gives_one_part() {
+        assert_eq!(Partitioner::new().build(100).num_parts(), 1);
+        assert_eq!(Partitioner::new().build(0).num_parts(), 0);
+    }
+
+    #[test]
+    fn parts_alone_matches_equal_parts() {
+        let data: Vec<u8> = (0..29).collect();
+        let plan = Partitioner::new().parts(6).build(data.len());
+        let planned: Vec<&[u8]> = plan.split(&data).collect();
+        assert_eq!(planned, data.equal_parts(6).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn max_len_takes_precedence_over_min_len() {
+        let plan = Partitioner::new()
+            .parts(2)
+            .min_len(50)
+            .max_len(10)
+            .build(45);
+        assert!(plan.sizes().all(|size| size <= 10));
+        assert_eq!(plan.num_parts(), 5);
+    }
+
+    #[test]
+    fn constraints_hold_for_many_lengths() {
+        let partitioner = Partitioner::new().parts(6).min_len(5).max_len(12);
+        for len in 0..200 {
+            let plan = partitioner.build(len);
+            let sizes: Vec<usize> = plan.sizes().collect();
+            assert_eq!(sizes.iter().sum::<usize>(), len);
+            assert!(sizes.iter().all(|&size| size <= 12));
+            if sizes.len() > 1 && len <= 72 {
+                assert!(sizes.iter().all(|&size| size >= 5));
+            }
+        }
+    }
+
+    #[test]
+    fn remainder_placement_applies() {
+        let plan = Partitioner::new()
+            .parts(4)
+            .remainder(RemainderPlacement::Spread)
+            .build(10);
+        assert_eq!(plan.sizes().collect::<Vec<_>>(), vec![3, 2, 3, 2]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn split_panics_on_length_mismatch() {
+        let plan = Partitioner::new().parts(2).build(4);
+        let _ = plan.split(&[1, 2, 3]);
+    }
+}
@@ -0,0 +1,275 @@
+use crate::full_part_count;
+
+/// Where the larger parts go when the elements don't divide evenly.
+///
+/// Whatever the placement, part sizes differ by at most one and the same number of
+/// parts is larger. Only their position in the sequence changes.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::placement::{EqualPartsWith, RemainderPlacement};
+///
+/// let data = [1, 2, 3, 4, 5, 6, 7];
+/// let parts: Vec<&[i32]> = data.equal_parts_with(3, RemainderPlacement::Back).collect();
+/// assert_eq!(parts, vec![&[1, 2][..], &[3, 4], &[5, 6, 7]]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RemainderPlacement {
+    /// Larger parts come first, as with [`equal_parts`](crate::EqualParts::equal_parts).
+    #[default]
+    Front,
+    /// Larger parts come last, so the tail of the sequence is heavier.
+    Back,
+    /// Larger parts are spread evenly through the sequence, starting with a larger
+    /// part, so no stretch of consecutive parts is heavier than another.
+    ///
+    /// For example, 10 elements in 4 parts have sizes `[3, 2, 3, 2]`.
+    Spread,
+}
+
+/// Returns the sizes of the parts `len` elements split into, with the larger parts
+/// placed according to `placement`.
+///
+/// Like [`split_count`](crate::split_count), only non-zero sizes are yielded, so there
+/// are fewer than `num_parts` sizes when `len` is less than `num_parts`.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::placement::{RemainderPlacement, part_sizes};
+///
+/// let sizes: Vec<usize> = part_sizes(10, 4, RemainderPlacement::Back).collect();
+/// assert_eq!(sizes, vec![2, 2, 3, 3]);
+/// ```
+pub fn part_sizes(len: usize, num_parts: usize, placement: RemainderPlacement) -> PartSizes {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    PartSizes {
+        index: 0,
+        count: num_parts.min(len),
+        part_size: len.div_ceil(num_parts),
+        full_parts: full_part_count(len, num_parts).min(len),
+        placement,
+    }
+}
+
+/// Iterator over the part sizes for a [`RemainderPlacement`].
+///
+/// This iterator is created by [`part_sizes`].
+#[derive(Debug, Clone)]
+pub struct PartSizes {
+    index: usize,
+    count: usize,
+    part_size: usize,
+    full_parts: usize,
+    placement: RemainderPlacement,
+}
+
+impl PartSizes {
+    /// Returns `true` if the part at `index` is one of the larger parts.
+    fn is_full(&self, index: usize) -> bool {
+        match self.placement {
+            RemainderPlacement::Front => index < self.full_parts,
+            RemainderPlacement::Back => index >= self.count - self.full_parts,
+            // Bresenham-style: part `index` is larger when the running share of larger
+            // parts, `index * full_parts / count`, crosses an integer.
+            RemainderPlacement::Spread => {
+                (index as u128 * self.full_parts as u128 % self.count as u128)
+                    < self.full_parts as u128
+            }
+        }
+    }
+}
+
+impl Iterator for PartSizes {
+    type Item = usize;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index == self.count {
+            return None;
+        }
+
+        let size = self.part_size - usize::from(!self.is_full(self.index));
+        self.index += 1;
+        Some(size)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.count - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for PartSizes {}
+
+/// A trait for splitting slices into approximately equal parts with a chosen
+/// [`RemainderPlacement`].
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::placement::{EqualPartsWith, RemainderPlacement};
+///
+/// let data: Vec<u32> = (0..10).collect();
+/// let lens: Vec<usize> = data
+///     .equal_parts_with(4, RemainderPlacement::Back)
+///     .map(<[u32]>::len)
+///     .collect();
+/// assert_eq!(lens, vec![2, 2, 3, 3]);
+/// ```
+pub trait EqualPartsWith<'a, T> {
+    /// Splits the collection into approximately equal parts, placing the larger parts
+    /// according to `placement`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    fn equal_parts_with(
+        self,
+        num_parts: usize,
+        placement: RemainderPlacement,
+    ) -> PlacedParts<'a, T>;
+}
+
+/// Iterator that yields approximately equal parts of a slice with the larger parts
+/// placed according to a [`RemainderPlacement`].
+///
+/// This iterator is created by [`EqualPartsWith::equal_parts_with`].
+#[derive(Debug, Clone)]
+pub struct PlacedParts<'a, T> {
+    data: &'a [T],
+    sizes: PartSizes,
+}
+
+impl<'a, T> PlacedParts<'a, T> {
+    /// Creates an iterator that splits `data` into parts of the given sizes.
+    pub(crate) fn new(data: &'a [T], sizes: PartSizes) -> Self {
+        PlacedParts { data, sizes }
+    }
+}
+
+impl<'a, T> Iterator for PlacedParts<'a, T> {
+    type Item = &'a [T];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let size = self.sizes.next()?;
+        let (chunk, rest) = self.data.split_at(size);
+        self.data = rest;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.sizes.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for PlacedParts<'_, T> {}
+
+impl<'a, T> EqualPartsWith<'a, T> for &'a [T] {
+    fn equal_parts_with(
+        self,
+        num_parts: usize,
+        placement: RemainderPlacement,
+    ) -> PlacedParts<'a, T> {
+        PlacedParts {
+            data: self,
+            sizes: part_sizes(self.len(), num_parts, placement),
+        }
+    }
+}
+
+impl<'a, T> EqualPartsWith<'a, T> for &'a Vec<T> {
+    fn equal_parts_with(
+        self,
+        num_parts: usize,
+        placement: RemainderPlacement,
+    ) -> PlacedParts<'a, T> {
+        self.as_slice().equal_parts_with(num_parts, placement)
+    }
+}
+
+impl<'a, T, const N: usize> EqualPartsWith<'a, T> for &'a [T; N] {
+    fn equal_parts_with(
+        self,
+        num_parts: usize,
+        placement: RemainderPlacement,
+    ) -> PlacedParts<'a, T> {
+        self.as_slice().equal_parts_with(num_parts, placement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EqualPartsWith, RemainderPlacement, part_sizes};
+    use crate::EqualParts;
+
+    #[test]
+    fn front_matches_equal_parts() {
+        let data: Vec<u8> = (0..37).collect();
+        for num_parts in 1..45 {
+            let placed: Vec<&[u8]> = data
+                .equal_parts_with(num_parts, RemainderPlacement::Front)
+                .collect();
+            let expected: Vec<&[u8]> = data.equal_parts(num_parts).collect();
+            assert_eq!(placed, expected);
+        }
+    }
+
+    #[test]
+    fn back_reverses_sizes() {
+        for len in 0..30 {
+            for num_parts in 1..12 {
+                let mut front: Vec<usize> =
+                    part_sizes(len, num_parts, RemainderPlacement::Front).collect();
+                let back: Vec<usize> =
+                    part_sizes(len, num_parts, RemainderPlacement::Back).collect();
+                front.reverse();
+                assert_eq!(back, front);
+            }
+        }
+    }
+
+    #[test]
+    fn spread_interleaves_larger_parts() {
+        let sizes: Vec<usize> = part_sizes(10, 4, RemainderPlacement::Spread).collect();
+        assert_eq!(sizes, vec![3, 2, 3, 2]);
+        let sizes: Vec<usize> = part_sizes(11, 6, RemainderPlacement::Spread).collect();
+        assert_eq!(sizes, vec![2, 1, 2, 2, 2, 2]);
+        let sizes: Vec<usize> = part_sizes(13, 6, RemainderPlacement::Spread).collect();
+        assert_eq!(sizes, vec![3, 2, 2, 2, 2, 2]);
+        let sizes: Vec<usize> = part_sizes(9, 6, RemainderPlacement::Spread).collect();
+        assert_eq!(sizes, vec![2, 1, 2, 1, 2, 1]);
+    }
+
+    #[test]
+    fn spread_keeps_totals() {
+        for len in 0..40 {
+            for num_parts in 1..15 {
+                let mut spread: Vec<usize> =
+                    part_sizes(len, num_parts, RemainderPlacement::Spread).collect();
+                let front: Vec<usize> =
+                    part_sizes(len, num_parts, RemainderPlacement::Front).collect();
+                spread.sort_unstable_by(|a, b| b.cmp(a));
+                assert_eq!(spread, front);
+            }
+        }
+    }
+
+    #[test]
+    fn back_fewer_elements_than_parts() {
+        let data = [1, 2];
+        let parts: Vec<&[i32]> = data.equal_parts_with(5, RemainderPlacement::Back).collect();
+        assert_eq!(parts, vec![&[1][..], &[2]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = [1].equal_parts_with(0, RemainderPlacement::Back);
+    }
+}
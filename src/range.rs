@@ -0,0 +1,129 @@
+use std::ops::Range;
+
+use crate::EqualParts;
+
+/// Iterator that yields approximately equal sub-ranges of a range.
+///
+/// This iterator is created by calling [`equal_parts`](EqualParts::equal_parts) on a
+/// `Range<usize>` or `Range<u64>`. It splits the range arithmetically, so no indices
+/// are ever materialized. The sub-ranges are contiguous, cover the whole range, and
+/// follow the same sizes as the parts of a slice of the same length.
+///
+/// Since more than one range type is supported, a range of bare integer literals needs
+/// a type suffix, as in `0..10usize`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::EqualParts;
+///
+/// let parts: Vec<_> = (0..10usize).equal_parts(4).collect();
+/// assert_eq!(parts, vec![0..3, 3..6, 6..8, 8..10]);
+///
+/// let parts: Vec<_> = (1_000_000_000_000u64..1_000_000_000_005).equal_parts(2).collect();
+/// assert_eq!(parts[1], 1_000_000_000_003..1_000_000_000_005);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RangeParts<Idx> {
+    start: Idx,
+    end: Idx,
+    part_size: Idx,
+    full_parts_left: Idx,
+}
+
+macro_rules! impl_range_parts {
+    ($($idx:ty),*) => {
+        $(
+            impl Iterator for RangeParts<$idx> {
+                type Item = Range<$idx>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    if self.start >= self.end {
+                        None
+                    } else {
+                        let size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+                        self.full_parts_left -= self.full_parts_left.min(1);
+
+                        let part = self.start..self.start + size;
+                        self.start = part.end;
+                        Some(part)
+                    }
+                }
+            }
+
+            impl EqualParts for Range<$idx> {
+                type Item = Range<$idx>;
+                type Iter = RangeParts<$idx>;
+
+                fn equal_parts(self, num_parts: usize) -> Self::Iter {
+                    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+                    let len = self.end.saturating_sub(self.start);
+                    // More parts than the index type can count is the same as one
+                    // part per index.
+                    let num_parts = <$idx>::try_from(num_parts).unwrap_or(<$idx>::MAX).min(len.max(1));
+                    // Equivalent to `full_part_count` in the slice
+                    // implementation, without overflowing for ranges near `MAX`.
+                    let full_parts = match len % num_parts {
+                        0 => num_parts,
+                        remainder => remainder,
+                    };
+                    RangeParts {
+                        start: self.start,
+                        end: self.end,
+                        part_size: len.div_ceil(num_parts),
+                        full_parts_left: full_parts,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_range_parts!(usize, u64);
+
+#[cfg(test)]
+mod tests {
+    use crate::EqualParts;
+
+    #[test]
+    fn matches_slice_parts() {
+        let data: Vec<usize> = (0..29).collect();
+        for num_parts in 1..35 {
+            let ranges: Vec<_> = (0..29usize).equal_parts(num_parts).collect();
+            let slices: Vec<&[usize]> = data.equal_parts(num_parts).collect();
+            assert_eq!(ranges.len(), slices.len());
+            for (range, slice) in ranges.iter().zip(slices) {
+                assert_eq!(&data[range.clone()], slice);
+            }
+        }
+    }
+
+    #[test]
+    fn offset_and_empty_ranges() {
+        let parts: Vec<_> = (5u64..8).equal_parts(2).collect();
+        assert_eq!(parts, vec![5..7, 7..8]);
+        assert_eq!((3usize..3).equal_parts(2).next(), None);
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = 9u64..2;
+        assert_eq!(reversed.equal_parts(2).next(), None);
+    }
+
+    #[test]
+    fn full_width_range() {
+        let parts: Vec<_> = (0..u64::MAX).equal_parts(2).collect();
+        assert_eq!(parts, vec![0..u64::MAX / 2 + 1, u64::MAX / 2 + 1..u64::MAX]);
+    }
+
+    #[test]
+    fn more_parts_than_elements() {
+        let parts: Vec<_> = (0u64..3).equal_parts(usize::MAX).collect();
+        assert_eq!(parts, vec![0..1, 1..2, 2..3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = (0usize..10).equal_parts(0);
+    }
+}
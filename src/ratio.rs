@@ -0,0 +1,262 @@
+/// Returns the sizes of parts of `len` elements proportional to `ratios`.
+///
+/// There is one size per ratio, in order, and the sizes always add up to `len`. Each
+/// part first gets the whole elements of its exact share, `len * ratio / total`,
+/// rounded down. The elements left over are then handed out one at a time to the
+/// parts with the largest fractional remainders, with ties going to the earlier
+/// part. This is the largest remainder method, so no size is ever more than one
+/// element away from its exact share.
+///
+/// A ratio of 0 gives an empty part.
+///
+/// # Panics
+///
+/// Panics if `ratios` is empty or all of its values are 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::ratio::ratio_sizes;
+///
+/// assert_eq!(ratio_sizes(10, &[3, 1, 1]), vec![6, 2, 2]);
+/// assert_eq!(ratio_sizes(11, &[3, 1, 1]), vec![7, 2, 2]);
+/// assert_eq!(ratio_sizes(12, &[3, 1, 1]), vec![7, 3, 2]);
+/// ```
+pub fn ratio_sizes(len: usize, ratios: &[usize]) -> Vec<usize> {
+    let total: u128 = ratios.iter().map(|&ratio| ratio as u128).sum();
+    assert!(total > 0, "Ratios must contain a value greater than 0");
+
+    let shares: Vec<(usize, u128)> = ratios
+        .iter()
+        .map(|&ratio| {
+            let exact = len as u128 * ratio as u128;
+            ((exact / total) as usize, exact % total)
+        })
+        .collect();
+    let mut sizes: Vec<usize> = shares.iter().map(|&(size, _)| size).collect();
+
+    let assigned: usize = sizes.iter().sum();
+    let mut by_remainder: Vec<usize> = (0..shares.len()).collect();
+    // Stable, so ties keep their original order.
+    by_remainder.sort_by(|&a, &b| shares[b].1.cmp(&shares[a].1));
+    for &index in by_remainder.iter().take(len - assigned) {
+        sizes[index] += 1;
+    }
+    sizes
+}
+
+/// Splits a slice into contiguous parts whose sizes are proportional to `ratios`.
+///
+/// There is one part per ratio, in order, sized by [`ratio_sizes`]. A ratio of 0
+/// gives an empty part, so the parts always line up with the ratios, for example when
+/// each ratio belongs to a particular worker.
+///
+/// # Panics
+///
+/// Panics if `ratios` is empty or all of its values are 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::ratio::parts_with_ratios;
+///
+/// // A worker three times as fast as the other two gets three times the share.
+/// let jobs: Vec<u32> = (0..10).collect();
+/// let parts = parts_with_ratios(&jobs, &[3, 1, 1]);
+/// assert_eq!(parts, vec![&jobs[..6], &jobs[6..8], &jobs[8..]]);
+/// ```
+pub fn parts_with_ratios<'a, T>(data: &'a [T], ratios: &[usize]) -> Vec<&'a [T]> {
+    let mut rest = data;
+    ratio_sizes(data.len(), ratios)
+        .into_iter()
+        .map(|size| {
+            let (part, tail) = rest.split_at(size);
+            rest = tail;
+            part
+        })
+        .collect()
+}
+
+/// How far the fractions passed to [`fraction_sizes`] may add up to something other
+/// than 1, to allow for values like `0.1` that floats cannot represent exactly.
+const FRACTION_TOLERANCE: f64 = 1e-6;
+
+/// Returns the part sizes for splitting `len` elements by `fractions`, such as
+/// `[0.8, 0.1, 0.1]` for a train/validation/test split.
+///
+/// There is one size per fraction, in order, and the sizes always add up to `len`.
+/// The rounding works like [`ratio_sizes`]: each part first gets its exact share,
+/// `len * fraction`, rounded down, and the elements left over are handed out one at a
+/// time to the parts with the largest fractional remainders, with ties going to the
+/// earlier part. No size is ever more than one element away from its exact share.
+///
+/// The shares are computed in `f64`, so they are exact only for lengths up to 2^53.
+///
+/// # Panics
+///
+/// Panics if `fractions` is empty, if any fraction is negative or not finite, or if
+/// the fractions do not add up to 1 (within a tolerance of one in a million).
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::ratio::fraction_sizes;
+///
+/// assert_eq!(fraction_sizes(100, &[0.8, 0.1, 0.1]), vec![80, 10, 10]);
+/// // 80.8, 10.1 and 10.1 round down to 100, and the largest remainder gets the rest.
+/// assert_eq!(fraction_sizes(101, &[0.8, 0.1, 0.1]), vec![81, 10, 10]);
+/// assert_eq!(fraction_sizes(7, &[0.5, 0.5]), vec![4, 3]);
+/// ```
+pub fn fraction_sizes(len: usize, fractions: &[f64]) -> Vec<usize> {
+    assert!(
+        fractions
+            .iter()
+            .all(|fraction| fraction.is_finite() && *fraction >= 0.0),
+        "Fractions must be finite and not negative"
+    );
+    let total: f64 = fractions.iter().sum();
+    assert!(
+        (total - 1.0).abs() <= FRACTION_TOLERANCE,
+        "Fractions must add up to 1"
+    );
+
+    let shares: Vec<(usize, f64)> = fractions
+        .iter()
+        .map(|fraction| {
+            // Dividing by the total absorbs the tolerance, so the shares add up to
+            // `len` exactly.
+            let exact = len as f64 * fraction / total;
+            let whole = exact.floor();
+            (whole as usize, exact - whole)
+        })
+        .collect();
+    let mut sizes: Vec<usize> = shares.iter().map(|&(size, _)| size).collect();
+
+    let assigned: usize = sizes.iter().sum();
+    let mut by_remainder: Vec<usize> = (0..shares.len()).collect();
+    // Stable, so ties keep their original order.
+    by_remainder.sort_by(|&a, &b| shares[b].1.total_cmp(&shares[a].1));
+    for &index in by_remainder.iter().take(len.saturating_sub(assigned)) {
+        sizes[index] += 1;
+    }
+    sizes
+}
+
+/// Splits a slice into contiguous parts by `fractions`, such as `[0.8, 0.1, 0.1]`
+/// for a train/validation/test split.
+///
+/// There is one part per fraction, in order, sized by [`fraction_sizes`]. For random
+/// rather than contiguous parts, shuffle the data first; for parts with the same mix
+/// of labels, see
+/// [`stratified_split_by_fractions`](crate::stratified::StratifiedParts::stratified_split_by_fractions).
+///
+/// # Panics
+///
+/// Panics under the same conditions as [`fraction_sizes`].
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::ratio::split_by_fractions;
+///
+/// let samples: Vec<u32> = (0..20).collect();
+/// let parts = split_by_fractions(&samples, &[0.8, 0.1, 0.1]);
+/// let [train, validation, test] = parts[..] else { unreachable!() };
+///
+/// assert_eq!(train.len(), 16);
+/// assert_eq!(validation, &[16, 17]);
+/// assert_eq!(test, &[18, 19]);
+/// ```
+pub fn split_by_fractions<'a, T>(data: &'a [T], fractions: &[f64]) -> Vec<&'a [T]> {
+    let mut rest = data;
+    fraction_sizes(data.len(), fractions)
+        .into_iter()
+        .map(|size| {
+            let (part, tail) = rest.split_at(size);
+            rest = tail;
+            part
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{fraction_sizes, parts_with_ratios, ratio_sizes, split_by_fractions};
+
+    #[test]
+    fn sizes_sum_to_len_and_stay_near_exact_share() {
+        let ratios = [5, 0, 2, 9, 1];
+        let total: usize = ratios.iter().sum();
+        for len in 0..200 {
+            let sizes = ratio_sizes(len, &ratios);
+            assert_eq!(sizes.iter().sum::<usize>(), len);
+            for (size, ratio) in sizes.iter().zip(ratios) {
+                let exact = len as f64 * ratio as f64 / total as f64;
+                assert!((*size as f64 - exact).abs() < 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn equal_ratios_match_equal_parts() {
+        assert_eq!(ratio_sizes(10, &[1, 1, 1, 1]), vec![3, 3, 2, 2]);
+        assert_eq!(ratio_sizes(2, &[1, 1, 1]), vec![1, 1, 0]);
+    }
+
+    #[test]
+    fn zero_ratio_gives_empty_part() {
+        let data = [1, 2, 3, 4];
+        assert_eq!(
+            parts_with_ratios(&data, &[1, 0, 1]),
+            vec![&[1, 2][..], &[], &[3, 4]]
+        );
+    }
+
+    #[test]
+    fn huge_values_do_not_overflow() {
+        assert_eq!(
+            ratio_sizes(usize::MAX, &[usize::MAX, usize::MAX])
+                .iter()
+                .sum::<usize>(),
+            usize::MAX
+        );
+    }
+
+    #[test]
+    fn fraction_sizes_sum_to_len_and_stay_near_exact_share() {
+        let fractions = [0.7, 0.15, 0.15];
+        for len in 0..500 {
+            let sizes = fraction_sizes(len, &fractions);
+            assert_eq!(sizes.iter().sum::<usize>(), len);
+            for (size, fraction) in sizes.iter().zip(fractions) {
+                assert!((*size as f64 - len as f64 * fraction).abs() < 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn fractions_tolerate_float_error() {
+        let tenths = [0.1; 10];
+        assert_eq!(fraction_sizes(10, &tenths), vec![1; 10]);
+        assert_eq!(fraction_sizes(3, &[1.0 / 3.0; 3]), vec![1, 1, 1]);
+    }
+
+    #[test]
+    fn split_by_fractions_covers_the_slice() {
+        let data: Vec<u32> = (0..13).collect();
+        let parts = split_by_fractions(&data, &[0.6, 0.0, 0.4]);
+        assert_eq!(parts, vec![&data[..8], &[], &data[8..]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_when_fractions_do_not_add_up() {
+        let _ = fraction_sizes(10, &[0.8, 0.1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_all_zero_ratios() {
+        let _ = ratio_sizes(5, &[0, 0]);
+    }
+}
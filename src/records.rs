@@ -0,0 +1,138 @@
+use std::fmt;
+
+use crate::aligned::{AlignedEqualParts, AlignedParts};
+
+/// A trait for splitting buffers of fixed-size records into parts that each hold whole
+/// records.
+pub trait RecordParts<'a> {
+    /// Splits a buffer of `record_len`-byte records into approximately equal parts by
+    /// record count, never cutting a record.
+    ///
+    /// Part lengths differ by at most one record, the longer parts come first, and no
+    /// part is empty. If there are fewer records than `num_parts`, there is one part
+    /// per record.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PartialRecord`] if the buffer length is not a multiple of
+    /// `record_len`, since a trailing partial record usually means the data is
+    /// truncated or the record size is wrong.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` or `record_len` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::records::RecordParts;
+    ///
+    /// // Five 3-byte records.
+    /// let data = b"AAABBBCCCDDDEEE";
+    /// let parts: Vec<&[u8]> = data.equal_record_parts(2, 3).unwrap().collect();
+    /// assert_eq!(parts, vec![&b"AAABBBCCC"[..], b"DDDEEE"]);
+    ///
+    /// let err = data[..14].equal_record_parts(2, 3).unwrap_err();
+    /// assert_eq!((err.len, err.record_len), (14, 3));
+    /// ```
+    fn equal_record_parts(
+        self,
+        num_parts: usize,
+        record_len: usize,
+    ) -> Result<AlignedParts<'a, u8>, PartialRecord>;
+}
+
+impl<'a> RecordParts<'a> for &'a [u8] {
+    fn equal_record_parts(
+        self,
+        num_parts: usize,
+        record_len: usize,
+    ) -> Result<AlignedParts<'a, u8>, PartialRecord> {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+        assert!(record_len > 0, "Record length must be greater than 0");
+
+        if !self.len().is_multiple_of(record_len) {
+            return Err(PartialRecord {
+                len: self.len(),
+                record_len,
+            });
+        }
+        Ok(self.equal_parts_aligned(num_parts, record_len))
+    }
+}
+
+impl<'a> RecordParts<'a> for &'a Vec<u8> {
+    fn equal_record_parts(
+        self,
+        num_parts: usize,
+        record_len: usize,
+    ) -> Result<AlignedParts<'a, u8>, PartialRecord> {
+        self.as_slice().equal_record_parts(num_parts, record_len)
+    }
+}
+
+/// Error returned by [`RecordParts::equal_record_parts`] when the buffer ends with a
+/// partial record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PartialRecord {
+    /// The length of the buffer in bytes.
+    pub len: usize,
+    /// The record length that was expected.
+    pub record_len: usize,
+}
+
+impl fmt::Display for PartialRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "buffer of {} bytes ends with a partial {}-byte record of {} bytes",
+            self.len,
+            self.record_len,
+            self.len % self.record_len
+        )
+    }
+}
+
+impl std::error::Error for PartialRecord {}
+
+#[cfg(test)]
+mod tests {
+    use super::RecordParts;
+
+    #[test]
+    fn parts_hold_whole_records() {
+        let data: Vec<u8> = (0..96).collect();
+        for num_parts in 1..40 {
+            let parts: Vec<&[u8]> = data.equal_record_parts(num_parts, 4).unwrap().collect();
+            assert_eq!(parts.len(), num_parts.min(24));
+            assert!(
+                parts
+                    .iter()
+                    .all(|part| part.len() % 4 == 0 && !part.is_empty())
+            );
+            let records: Vec<usize> = parts.iter().map(|part| part.len() / 4).collect();
+            assert!(records[0] - records[records.len() - 1] <= 1);
+            assert_eq!(parts.concat(), data);
+        }
+    }
+
+    #[test]
+    fn trailing_partial_record_is_an_error() {
+        let err = vec![0u8; 10].equal_record_parts(2, 4).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "buffer of 10 bytes ends with a partial 4-byte record of 2 bytes"
+        );
+    }
+
+    #[test]
+    fn empty_buffer_has_no_parts() {
+        assert_eq!(Vec::new().equal_record_parts(3, 8).unwrap().next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_record_len() {
+        let _ = b"abc".equal_record_parts(1, 0);
+    }
+}
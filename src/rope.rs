@@ -0,0 +1,247 @@
+use std::mem;
+
+use ropey::{Rope, RopeSlice};
+
+use crate::full_part_count;
+use crate::{EqualParts, IntoEqualParts, split_count};
+
+/// Iterator that yields approximately equal parts of a [`Rope`] as [`RopeSlice`]s.
+///
+/// This iterator is created by calling [`equal_parts`](EqualParts::equal_parts) on a
+/// `&Rope`. Parts are measured in `char`s, and slicing a rope copies no text.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::EqualParts;
+/// use ropey::Rope;
+///
+/// let rope = Rope::from_str("hello, wörld");
+/// let parts: Vec<String> = rope.equal_parts(3).map(String::from).collect();
+/// assert_eq!(parts, vec!["hell", "o, w", "örld"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RopeEqualPartsIter<'a> {
+    rope: RopeSlice<'a>,
+    start: usize,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<'a> Iterator for RopeEqualPartsIter<'a> {
+    type Item = RopeSlice<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.rope.len_chars() {
+            return None;
+        }
+
+        let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let end = self.start + chunk_size;
+        let part = self.rope.slice(self.start..end);
+        self.start = end;
+        Some(part)
+    }
+}
+
+impl<'a> EqualParts for &'a Rope {
+    type Item = RopeSlice<'a>;
+    type Iter = RopeEqualPartsIter<'a>;
+
+    fn equal_parts(self, num_parts: usize) -> Self::Iter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let len = self.len_chars();
+        let part_size = len.div_ceil(num_parts);
+        RopeEqualPartsIter {
+            rope: self.slice(..),
+            start: 0,
+            part_size,
+            full_parts_left: full_part_count(len, num_parts),
+        }
+    }
+}
+
+/// Iterator that yields approximately equal owned parts of a [`Rope`].
+///
+/// This iterator is created by calling [`into_equal_parts`](IntoEqualParts::into_equal_parts)
+/// on a `Rope`. Parts are measured in `char`s and cut off with [`Rope::split_off`], which
+/// shares the rope's internal nodes instead of copying its text.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::IntoEqualParts;
+/// use ropey::Rope;
+///
+/// let rope = Rope::from_str("abcdefg");
+/// let parts: Vec<Rope> = rope.into_equal_parts(2).collect();
+/// assert_eq!(parts, vec!["abcd", "efg"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct IntoRopePartsIter {
+    rope: Rope,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl Iterator for IntoRopePartsIter {
+    type Item = Rope;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rope.len_chars() == 0 {
+            return None;
+        }
+
+        let chunk_size = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let rest = self.rope.split_off(chunk_size);
+        Some(mem::replace(&mut self.rope, rest))
+    }
+}
+
+impl IntoEqualParts for Rope {
+    type Item = Rope;
+    type IntoIter = IntoRopePartsIter;
+
+    fn into_equal_parts(self, num_parts: usize) -> Self::IntoIter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let len = self.len_chars();
+        let part_size = len.div_ceil(num_parts);
+        IntoRopePartsIter {
+            rope: self,
+            part_size,
+            full_parts_left: full_part_count(len, num_parts),
+        }
+    }
+}
+
+/// Splits a rope into parts with approximately equal numbers of lines.
+///
+/// Each part is a [`RopeSlice`] made of whole lines, including their line breaks. A line
+/// break at the very end of the rope does not start another line, so
+/// `"a\nb\n"` has two lines. Like [`equal_parts`](EqualParts::equal_parts), larger parts
+/// come first and no part is empty.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::rope::line_parts;
+/// use ropey::Rope;
+///
+/// let rope = Rope::from_str("one\ntwo\nthree\nfour\nfive\n");
+/// let parts: Vec<String> = line_parts(&rope, 2).into_iter().map(String::from).collect();
+/// assert_eq!(parts, vec!["one\ntwo\nthree\n", "four\nfive\n"]);
+/// ```
+pub fn line_parts(rope: &Rope, num_parts: usize) -> Vec<RopeSlice<'_>> {
+    let boundaries = line_boundaries(rope, num_parts);
+    boundaries
+        .windows(2)
+        .map(|pair| rope.slice(pair[0]..pair[1]))
+        .collect()
+}
+
+/// Splits a rope into owned ropes with approximately equal numbers of lines.
+///
+/// This is the owned counterpart of [`line_parts`], using [`Rope::split_off`] so no text
+/// is copied.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::rope::into_line_parts;
+/// use ropey::Rope;
+///
+/// let rope = Rope::from_str("a\nb\nc");
+/// assert_eq!(into_line_parts(rope, 2), vec!["a\nb\n", "c"]);
+/// ```
+pub fn into_line_parts(mut rope: Rope, num_parts: usize) -> Vec<Rope> {
+    let boundaries = line_boundaries(&rope, num_parts);
+    if boundaries.len() < 2 {
+        return Vec::new();
+    }
+
+    // Split from the back so earlier boundaries stay valid.
+    let mut parts: Vec<Rope> = boundaries[1..boundaries.len() - 1]
+        .iter()
+        .rev()
+        .map(|&boundary| rope.split_off(boundary))
+        .collect();
+    parts.push(rope);
+    parts.reverse();
+    parts
+}
+
+/// Returns the char indices at which to split `rope` into parts of whole lines,
+/// including 0 and the length of the rope.
+fn line_boundaries(rope: &Rope, num_parts: usize) -> Vec<usize> {
+    let mut lines = rope.len_lines();
+    if rope.line(lines - 1).len_chars() == 0 {
+        // The last line is the empty one after a final line break.
+        lines -= 1;
+    }
+
+    let mut boundaries = vec![0];
+    boundaries.extend(
+        split_count(lines, num_parts)
+            .with_offsets()
+            .map(|(offset, count)| rope.line_to_char(offset + count)),
+    );
+    boundaries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{into_line_parts, line_parts};
+    use crate::{EqualParts, IntoEqualParts};
+    use ropey::Rope;
+
+    fn long_rope() -> Rope {
+        let text: String = (0..500).map(|i| format!("line {i} ✓\n")).collect();
+        Rope::from_str(&text)
+    }
+
+    #[test]
+    fn borrowed_and_owned_char_parts_agree() {
+        let rope = long_rope();
+        let borrowed: Vec<String> = rope.equal_parts(7).map(String::from).collect();
+        let owned: Vec<String> = rope.clone().into_equal_parts(7).map(String::from).collect();
+        assert_eq!(borrowed, owned);
+        assert_eq!(borrowed.concat(), rope.to_string());
+
+        let lengths: Vec<usize> = borrowed.iter().map(|part| part.chars().count()).collect();
+        assert!(lengths.iter().max().unwrap() - lengths.iter().min().unwrap() <= 1);
+    }
+
+    #[test]
+    fn line_parts_hold_whole_lines() {
+        let rope = long_rope();
+        let parts = line_parts(&rope, 3);
+        let lines: Vec<usize> = parts.iter().map(|part| part.len_lines() - 1).collect();
+        assert_eq!(lines, vec![167, 167, 166]);
+
+        let owned = into_line_parts(rope.clone(), 3);
+        assert_eq!(owned, parts);
+    }
+
+    #[test]
+    fn empty_rope() {
+        let rope = Rope::new();
+        assert_eq!(rope.equal_parts(2).next(), None);
+        assert!(line_parts(&rope, 2).is_empty());
+        assert!(into_line_parts(rope.clone(), 2).is_empty());
+        assert_eq!(rope.into_equal_parts(2).next(), None);
+    }
+}
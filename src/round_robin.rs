@@ -0,0 +1,291 @@
+use std::iter::StepBy;
+use std::slice;
+
+/// Returns the original input index of the element at `position` within round-robin
+/// part `part`.
+///
+/// When a collection is split round-robin into `num_parts` parts, part `i` receives
+/// the elements at indices `i, i + num_parts, i + 2 * num_parts, …`. This function
+/// performs the inverse mapping, which is useful when scattering per-element results
+/// into a preallocated output buffer.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0 or if `part` is not less than `num_parts`.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::round_robin_index;
+///
+/// // With 3 parts, part 1 holds the elements originally at indices 1, 4, 7, ...
+/// assert_eq!(round_robin_index(1, 0, 3), 1);
+/// assert_eq!(round_robin_index(1, 1, 3), 4);
+/// assert_eq!(round_robin_index(1, 2, 3), 7);
+/// ```
+pub fn round_robin_index(part: usize, position: usize, num_parts: usize) -> usize {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+    assert!(
+        part < num_parts,
+        "Part index must be less than the number of parts"
+    );
+    position * num_parts + part
+}
+
+/// Restores per-element results from round-robin parts to the original input order.
+///
+/// When work was split round-robin, each part's results come back grouped by part:
+/// part `i` holds the results for input indices `i, i + n, i + 2n, …`. This function
+/// interleaves the parts again so that the returned `Vec` is ordered exactly like the
+/// input was.
+///
+/// The number of parts is taken from the number of items yielded by `parts`.
+///
+/// # Panics
+///
+/// Panics if the part lengths could not have come from a round-robin split, that is
+/// if any part is shorter than a later part or longer than a later part by more
+/// than one element.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::gather_round_robin;
+///
+/// // The inputs 0..7 were dealt out to 3 workers, which squared them.
+/// let parts = vec![vec![0, 9, 36], vec![1, 16], vec![4, 25]];
+/// let results = gather_round_robin(parts);
+/// assert_eq!(results, vec![0, 1, 4, 9, 16, 25, 36]);
+/// ```
+pub fn gather_round_robin<T, P>(parts: impl IntoIterator<Item = P>) -> Vec<T>
+where
+    P: IntoIterator<Item = T>,
+{
+    let mut parts: Vec<P::IntoIter> = parts.into_iter().map(IntoIterator::into_iter).collect();
+    let mut gathered = Vec::with_capacity(parts.iter().map(|part| part.size_hint().0).sum());
+
+    if parts.is_empty() {
+        return gathered;
+    }
+
+    loop {
+        for index in 0..parts.len() {
+            match parts[index].next() {
+                Some(item) => gathered.push(item),
+                None => {
+                    // Every other part must be exhausted too, or the lengths are off.
+                    assert!(
+                        parts.iter_mut().all(|part| part.next().is_none()),
+                        "Parts do not form a round-robin split"
+                    );
+                    return gathered;
+                }
+            }
+        }
+    }
+}
+
+/// Iterator over the round-robin parts of a slice.
+///
+/// This iterator is created by calling
+/// [`interleaved_parts`](InterleavedParts::interleaved_parts) on a slice. Each item is
+/// a strided iterator over one part's elements, so nothing is copied.
+#[derive(Debug, Clone)]
+pub struct InterleavedPartsIter<'a, T> {
+    data: &'a [T],
+    next_part: usize,
+    num_parts: usize,
+}
+
+impl<'a, T> Iterator for InterleavedPartsIter<'a, T> {
+    type Item = StepBy<slice::Iter<'a, T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_part >= self.num_parts {
+            return None;
+        }
+        let part = self.data[self.next_part..].iter().step_by(self.num_parts);
+        self.next_part += 1;
+        Some(part)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.num_parts - self.next_part;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for InterleavedPartsIter<'_, T> {}
+
+/// A trait for splitting collections round-robin, so that part `i` receives the
+/// elements at indices `i, i + n, i + 2n, …`.
+///
+/// Contiguous parts are biased when the input is ordered by cost, for example a work
+/// list sorted by difficulty: the last part gets all the hard items. Dealing elements
+/// out in turn gives every part a similar mix at no extra cost. Results can be put
+/// back in input order with [`gather_round_robin`].
+pub trait InterleavedParts<'a, T> {
+    /// Splits the collection into `num_parts` round-robin parts.
+    ///
+    /// Part lengths match those of [`equal_parts`](crate::EqualParts::equal_parts):
+    /// they differ by at most one, the longer parts come first, and no part is empty.
+    /// If there are fewer elements than `num_parts`, there is one part per element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::InterleavedParts;
+    ///
+    /// let data = [0, 1, 2, 3, 4, 5, 6];
+    /// let parts: Vec<Vec<&i32>> = data
+    ///     .interleaved_parts(3)
+    ///     .map(|part| part.collect())
+    ///     .collect();
+    /// assert_eq!(parts, vec![vec![&0, &3, &6], vec![&1, &4], vec![&2, &5]]);
+    /// ```
+    fn interleaved_parts(self, num_parts: usize) -> InterleavedPartsIter<'a, T>;
+}
+
+impl<'a, T> InterleavedParts<'a, T> for &'a [T] {
+    fn interleaved_parts(self, num_parts: usize) -> InterleavedPartsIter<'a, T> {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        InterleavedPartsIter {
+            data: self,
+            next_part: 0,
+            num_parts: num_parts.min(self.len()),
+        }
+    }
+}
+
+impl<'a, T> InterleavedParts<'a, T> for &'a Vec<T> {
+    fn interleaved_parts(self, num_parts: usize) -> InterleavedPartsIter<'a, T> {
+        self.as_slice().interleaved_parts(num_parts)
+    }
+}
+
+/// A trait for splitting owned collections round-robin into owned parts.
+///
+/// This is the owned counterpart of [`InterleavedParts`].
+pub trait IntoInterleavedParts<T> {
+    /// Deals the elements out into `num_parts` owned parts, so that part `i` holds
+    /// the elements at indices `i, i + n, i + 2n, …`.
+    ///
+    /// The parts have the same lengths as those of
+    /// [`interleaved_parts`](InterleavedParts::interleaved_parts), and no part is
+    /// empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::IntoInterleavedParts;
+    ///
+    /// let names = vec!["a".to_string(), "b".into(), "c".into(), "d".into(), "e".into()];
+    /// let parts = names.into_interleaved_parts(2);
+    /// assert_eq!(parts, vec![vec!["a", "c", "e"], vec!["b", "d"]]);
+    /// ```
+    fn into_interleaved_parts(self, num_parts: usize) -> Vec<Vec<T>>;
+}
+
+impl<T> IntoInterleavedParts<T> for Vec<T> {
+    fn into_interleaved_parts(self, num_parts: usize) -> Vec<Vec<T>> {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let num_parts = num_parts.min(self.len());
+        let mut parts: Vec<Vec<T>> = (0..num_parts)
+            .map(|part| Vec::with_capacity((self.len() - part).div_ceil(num_parts)))
+            .collect();
+        for (index, item) in self.into_iter().enumerate() {
+            parts[index % num_parts].push(item);
+        }
+        parts
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{InterleavedParts, IntoInterleavedParts, gather_round_robin, round_robin_index};
+
+    #[test]
+    fn gather_even_parts() {
+        let parts = vec![vec![0, 3], vec![1, 4], vec![2, 5]];
+        assert_eq!(gather_round_robin(parts), vec![0, 1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn gather_uneven_parts() {
+        let parts = vec![vec![0, 4, 8], vec![1, 5, 9], vec![2, 6], vec![3, 7]];
+        assert_eq!(gather_round_robin(parts), (0..10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn gather_more_parts_than_elements() {
+        let parts = vec![vec!['a'], vec!['b'], vec![], vec![]];
+        assert_eq!(gather_round_robin(parts), vec!['a', 'b']);
+    }
+
+    #[test]
+    fn gather_no_parts() {
+        let parts: Vec<Vec<i32>> = vec![];
+        assert_eq!(gather_round_robin(parts), Vec::<i32>::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn gather_panics_on_invalid_lengths() {
+        let parts = vec![vec![0], vec![1, 3]];
+        let _ = gather_round_robin(parts);
+    }
+
+    #[test]
+    #[should_panic]
+    fn gather_panics_on_too_long_first_part() {
+        let parts = vec![vec![0, 2, 4], vec![1]];
+        let _ = gather_round_robin(parts);
+    }
+
+    #[test]
+    fn interleaved_parts_round_trip() {
+        let data: Vec<u32> = (0..23).collect();
+        for num_parts in 1..30 {
+            let parts: Vec<Vec<u32>> = data
+                .interleaved_parts(num_parts)
+                .map(|part| part.copied().collect())
+                .collect();
+            assert_eq!(parts.len(), num_parts.min(23));
+            assert!(parts.iter().all(|part| !part.is_empty()));
+            assert_eq!(parts, data.clone().into_interleaved_parts(num_parts));
+            assert_eq!(gather_round_robin(parts), data);
+        }
+    }
+
+    #[test]
+    fn interleaved_parts_of_empty_input() {
+        assert_eq!(Vec::<u8>::new().interleaved_parts(3).len(), 0);
+        assert!(Vec::<u8>::new().into_interleaved_parts(3).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn interleaved_parts_panic_with_zero_parts() {
+        let _ = [1, 2, 3].interleaved_parts(0);
+    }
+
+    #[test]
+    fn index_matches_gather() {
+        let parts = [vec![0, 3, 6], vec![1, 4], vec![2, 5]];
+        for (part, values) in parts.iter().enumerate() {
+            for (position, value) in values.iter().enumerate() {
+                assert_eq!(round_robin_index(part, position, parts.len()), *value);
+            }
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use crate::EqualParts;
+use crate::parallel::scoped_map;
+
+/// The increment of the SplitMix64 generator.
+const GOLDEN_GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+/// The SplitMix64 output function.
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Derives a stable, independent RNG seed for part `part` from a master seed.
+///
+/// The seed for part `i` is the `i`-th output of a SplitMix64 generator seeded with
+/// `master_seed`. It depends only on the master seed and the part index, so a parallel
+/// Monte Carlo run produces the same results no matter how many threads execute the
+/// parts or in which order they finish. The outputs are well mixed, so neighboring
+/// parts get unrelated seeds even though their indices differ by one.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::seed::part_seed;
+///
+/// let seeds: Vec<u64> = (0..4).map(|part| part_seed(42, part)).collect();
+/// assert_eq!(seeds[2], part_seed(42, 2));
+/// assert_ne!(seeds[1], seeds[2]);
+/// ```
+pub fn part_seed(master_seed: u64, part: usize) -> u64 {
+    mix(master_seed.wrapping_add((part as u64).wrapping_add(1).wrapping_mul(GOLDEN_GAMMA)))
+}
+
+/// Derives a stable, independent byte seed of any length for part `part`.
+///
+/// This is useful for generators seeded from a byte array, such as those implementing
+/// `rand::SeedableRng::from_seed`. The bytes are produced by a SplitMix64 generator
+/// seeded with [`part_seed`].
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::seed::part_seed_bytes;
+///
+/// let seed: [u8; 32] = part_seed_bytes(42, 3);
+/// assert_eq!(seed, part_seed_bytes::<32>(42, 3));
+/// assert_ne!(seed, part_seed_bytes::<32>(42, 4));
+/// ```
+pub fn part_seed_bytes<const N: usize>(master_seed: u64, part: usize) -> [u8; N] {
+    let mut state = part_seed(master_seed, part);
+    let mut bytes = [0; N];
+    for chunk in bytes.chunks_mut(8) {
+        state = state.wrapping_add(GOLDEN_GAMMA);
+        chunk.copy_from_slice(&mix(state).to_le_bytes()[..chunk.len()]);
+    }
+    bytes
+}
+
+/// Maps `f` over approximately equal parts of `data` on separate threads, giving each
+/// part its own RNG derived from a master seed.
+///
+/// The data is split with [`equal_parts`](EqualParts::equal_parts) and each part is
+/// processed on its own scoped thread, or on the calling thread where
+/// [`map_parts`](crate::parallel::map_parts) would do so. `make_rng` turns each part's
+/// seed (from [`part_seed`]) into a generator, which `f` receives alongside the part.
+/// The results are returned in part order and are reproducible for a given master seed
+/// and number of parts.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0, or if `make_rng` or `f` panics on any thread.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::seed::map_parts_with_rng;
+///
+/// // A toy linear congruential generator stands in for a real RNG.
+/// struct Lcg(u64);
+/// impl Lcg {
+///     fn next(&mut self) -> u64 {
+///         self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1);
+///         self.0 >> 33
+///     }
+/// }
+///
+/// let samples: Vec<u64> = (0..1000).collect();
+/// let run = || map_parts_with_rng(&samples, 4, 7, Lcg, |part, rng| {
+///     part.iter().map(|x| x ^ rng.next()).sum::<u64>()
+/// });
+///
+/// // The same master seed always reproduces the same results.
+/// assert_eq!(run(), run());
+/// ```
+pub fn map_parts_with_rng<T, G, R, M, F>(
+    data: &[T],
+    num_parts: usize,
+    master_seed: u64,
+    make_rng: M,
+    f: F,
+) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    M: Fn(u64) -> G + Sync,
+    F: Fn(&[T], &mut G) -> R + Sync,
+{
+    let (make_rng, f) = (&make_rng, &f);
+    scoped_map(
+        data.equal_parts(num_parts)
+            .enumerate()
+            .map(|(index, part)| move || f(part, &mut make_rng(part_seed(master_seed, index)))),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{map_parts_with_rng, part_seed, part_seed_bytes};
+
+    #[test]
+    fn matches_splitmix64_reference() {
+        // The first outputs of SplitMix64 seeded with 0.
+        assert_eq!(part_seed(0, 0), 0xE220_A839_7B1D_CDAF);
+        assert_eq!(part_seed(0, 1), 0x6E78_9E6A_A1B9_65F4);
+    }
+
+    #[test]
+    fn seeds_are_distinct() {
+        let mut seeds: Vec<u64> = (0..1000).map(|part| part_seed(1, part)).collect();
+        seeds.sort_unstable();
+        seeds.dedup();
+        assert_eq!(seeds.len(), 1000);
+    }
+
+    #[test]
+    fn seed_bytes_of_odd_length() {
+        let bytes: [u8; 11] = part_seed_bytes(5, 0);
+        let full: [u8; 16] = part_seed_bytes(5, 0);
+        assert_eq!(bytes[..], full[..11]);
+    }
+
+    #[test]
+    fn map_passes_part_seeds() {
+        let data: Vec<i32> = (0..10).collect();
+        let results =
+            map_parts_with_rng(&data, 3, 9, |seed| seed, |part, seed| (part.len(), *seed));
+        assert_eq!(
+            results,
+            vec![
+                (4, part_seed(9, 0)),
+                (3, part_seed(9, 1)),
+                (3, part_seed(9, 2))
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let data = [1, 2, 3];
+        let _ = map_parts_with_rng(&data, 0, 0, |seed| seed, |part, _| part.len());
+    }
+}
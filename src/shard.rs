@@ -0,0 +1,421 @@
+use std::hash::{BuildHasher, Hash};
+
+/// Returns the shard of `key_hash` among `num_shards` shards, using jump consistent
+/// hashing.
+///
+/// Every hash is mapped to a shard in `0..num_shards`, and the hashes are spread
+/// evenly over the shards. Unlike `key_hash % num_shards`, the mapping is
+/// consistent: when the number of shards grows from `n` to `n + 1`, a key either
+/// stays on its shard or moves to the new shard `n`, so only about `1 / (n + 1)` of
+/// the keys move. This makes it a good fit for caches and storage that are resharded
+/// while running. The algorithm is the one by Lamping and Veach, which needs no
+/// memory and runs in `O(log num_shards)` time.
+///
+/// The hashes should be well mixed. Sequential integers or other low-entropy keys
+/// should be passed through a hash function first.
+///
+/// # Panics
+///
+/// Panics if `num_shards` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::shard::shard_of;
+///
+/// let hashes = [0x9e37_79b9_7f4a_7c15, 0x2545_f491_4f6c_dd1d, 0xbf58_476d_1ce4_e5b9];
+/// for hash in hashes {
+///     let before = shard_of(hash, 10);
+///     let after = shard_of(hash, 11);
+///
+///     assert!(before < 10);
+///     // Adding a shard only ever moves keys onto the new shard.
+///     assert!(after == before || after == 10);
+/// }
+/// ```
+pub fn shard_of(key_hash: u64, num_shards: usize) -> usize {
+    assert!(num_shards > 0, "Number of shards must be greater than 0");
+
+    let num_shards = num_shards as u64;
+    let mut key = key_hash;
+    let mut shard = 0;
+    let mut next = 0;
+    while next < num_shards {
+        shard = next;
+        key = key.wrapping_mul(2_862_933_555_777_941_757).wrapping_add(1);
+        next = ((shard + 1) as f64 * ((1u64 << 31) as f64 / ((key >> 33) + 1) as f64)) as u64;
+    }
+    shard as usize
+}
+
+/// A trait for distributing the elements of a collection over shards with jump
+/// consistent hashing.
+///
+/// Every element is placed on the shard that [`shard_of`] returns for its key hash, so
+/// it always lands on the same shard for the same number of shards, whatever the other
+/// elements are.
+/// This trades the balance of contiguous splitting for key affinity: the shards only
+/// hold about equal numbers of elements when there are many distinct keys.
+pub trait JumpShards<'a, T> {
+    /// Assigns every element to one of `num_shards` shards by its key hash, and
+    /// returns the element indices of each shard.
+    ///
+    /// Exactly `num_shards` lists are returned, so that list `i` always belongs to
+    /// shard `i`, and shards that received no elements are empty. The indices in each
+    /// list are in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_shards` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::shard::{JumpShards, shard_of};
+    ///
+    /// let hashes: [u64; 6] = [11, 0x5bd1_e995, 42, 0xdead_beef, 7, 11];
+    /// let shards = hashes.jump_shard_assignments(3, |&hash| hash);
+    ///
+    /// assert_eq!(shards.len(), 3);
+    /// for (shard, indices) in shards.iter().enumerate() {
+    ///     for &index in indices {
+    ///         assert_eq!(shard_of(hashes[index], 3), shard);
+    ///     }
+    /// }
+    /// ```
+    fn jump_shard_assignments<F>(self, num_shards: usize, key_hash: F) -> Vec<Vec<usize>>
+    where
+        F: FnMut(&T) -> u64;
+
+    /// Distributes the elements over `num_shards` shards by their key hash.
+    ///
+    /// This is [`jump_shard_assignments`](JumpShards::jump_shard_assignments) with each
+    /// index replaced by a reference to its element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_shards` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::shard::JumpShards;
+    ///
+    /// // (user id hash, event)
+    /// let events = [(0x1f3a, "login"), (0x77c2, "view"), (0x1f3a, "logout")];
+    /// let shards = events.jump_shards(4, |&(user, _)| user);
+    ///
+    /// assert_eq!(shards.len(), 4);
+    /// // Both events of the same user are on the same shard.
+    /// assert!(shards.iter().any(|shard| shard.len() >= 2));
+    /// ```
+    fn jump_shards<F>(self, num_shards: usize, key_hash: F) -> Vec<Vec<&'a T>>
+    where
+        F: FnMut(&T) -> u64;
+}
+
+impl<'a, T> JumpShards<'a, T> for &'a [T] {
+    fn jump_shard_assignments<F>(self, num_shards: usize, mut key_hash: F) -> Vec<Vec<usize>>
+    where
+        F: FnMut(&T) -> u64,
+    {
+        assert!(num_shards > 0, "Number of shards must be greater than 0");
+
+        let mut shards = vec![Vec::new(); num_shards];
+        for (index, item) in self.iter().enumerate() {
+            shards[shard_of(key_hash(item), num_shards)].push(index);
+        }
+        shards
+    }
+
+    fn jump_shards<F>(self, num_shards: usize, key_hash: F) -> Vec<Vec<&'a T>>
+    where
+        F: FnMut(&T) -> u64,
+    {
+        self.jump_shard_assignments(num_shards, key_hash)
+            .into_iter()
+            .map(|indices| indices.into_iter().map(|index| &self[index]).collect())
+            .collect()
+    }
+}
+
+impl<'a, T> JumpShards<'a, T> for &'a Vec<T> {
+    fn jump_shard_assignments<F>(self, num_shards: usize, key_hash: F) -> Vec<Vec<usize>>
+    where
+        F: FnMut(&T) -> u64,
+    {
+        self.as_slice().jump_shard_assignments(num_shards, key_hash)
+    }
+
+    fn jump_shards<F>(self, num_shards: usize, key_hash: F) -> Vec<Vec<&'a T>>
+    where
+        F: FnMut(&T) -> u64,
+    {
+        self.as_slice().jump_shards(num_shards, key_hash)
+    }
+}
+
+/// A trait for distributing the elements of a collection over buckets by their hash.
+///
+/// Each element is hashed with a caller-supplied [`BuildHasher`] and placed on the
+/// bucket that [`shard_of`] returns for the hash. Equal elements therefore always land in
+/// the same bucket, and a deterministic hasher such as
+/// [`BuildHasherDefault<DefaultHasher>`](std::hash::BuildHasherDefault) gives the same
+/// buckets in every run. Since buckets are chosen by jump consistent hashing, growing
+/// the number of buckets by one only moves elements into the new bucket.
+///
+/// To shard by part of an element rather than all of it, hash the key yourself and use
+/// [`JumpShards`].
+pub trait HashShards<'a, T> {
+    /// Distributes the elements over `num_buckets` buckets by their hash, and returns
+    /// the element indices of each bucket.
+    ///
+    /// Exactly `num_buckets` lists are returned, and buckets that received no elements
+    /// are empty. The indices in each list are in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_buckets` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::{BuildHasherDefault, DefaultHasher};
+    ///
+    /// use equal_parts::shard::HashShards;
+    ///
+    /// let words = ["apple", "pear", "apple", "fig", "pear"];
+    /// let buckets = words.shard_indices_by_hash(3, BuildHasherDefault::<DefaultHasher>::new());
+    ///
+    /// assert_eq!(buckets.len(), 3);
+    /// // Equal words share a bucket.
+    /// let bucket_of = |index| buckets.iter().position(|bucket| bucket.contains(&index));
+    /// assert_eq!(bucket_of(0), bucket_of(2));
+    /// assert_eq!(bucket_of(1), bucket_of(4));
+    /// ```
+    fn shard_indices_by_hash<S>(self, num_buckets: usize, build_hasher: S) -> Vec<Vec<usize>>
+    where
+        T: Hash,
+        S: BuildHasher;
+
+    /// Distributes the elements over `num_buckets` buckets by their hash.
+    ///
+    /// This is [`shard_indices_by_hash`](HashShards::shard_indices_by_hash) with each
+    /// index replaced by a reference to its element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_buckets` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::{BuildHasherDefault, DefaultHasher};
+    ///
+    /// use equal_parts::shard::HashShards;
+    ///
+    /// let ids: Vec<u32> = (0..1000).collect();
+    /// let buckets = ids.shard_by_hash(4, BuildHasherDefault::<DefaultHasher>::new());
+    ///
+    /// assert_eq!(buckets.len(), 4);
+    /// assert_eq!(buckets.iter().map(Vec::len).sum::<usize>(), 1000);
+    /// ```
+    fn shard_by_hash<S>(self, num_buckets: usize, build_hasher: S) -> Vec<Vec<&'a T>>
+    where
+        T: Hash,
+        S: BuildHasher;
+}
+
+impl<'a, T> HashShards<'a, T> for &'a [T] {
+    fn shard_indices_by_hash<S>(self, num_buckets: usize, build_hasher: S) -> Vec<Vec<usize>>
+    where
+        T: Hash,
+        S: BuildHasher,
+    {
+        self.jump_shard_assignments(num_buckets, |item| build_hasher.hash_one(item))
+    }
+
+    fn shard_by_hash<S>(self, num_buckets: usize, build_hasher: S) -> Vec<Vec<&'a T>>
+    where
+        T: Hash,
+        S: BuildHasher,
+    {
+        self.jump_shards(num_buckets, |item| build_hasher.hash_one(item))
+    }
+}
+
+impl<'a, T> HashShards<'a, T> for &'a Vec<T> {
+    fn shard_indices_by_hash<S>(self, num_buckets: usize, build_hasher: S) -> Vec<Vec<usize>>
+    where
+        T: Hash,
+        S: BuildHasher,
+    {
+        self.as_slice()
+            .shard_indices_by_hash(num_buckets, build_hasher)
+    }
+
+    fn shard_by_hash<S>(self, num_buckets: usize, build_hasher: S) -> Vec<Vec<&'a T>>
+    where
+        T: Hash,
+        S: BuildHasher,
+    {
+        self.as_slice().shard_by_hash(num_buckets, build_hasher)
+    }
+}
+
+/// A trait for distributing the elements of an owned collection over buckets by their
+/// hash.
+///
+/// This is the owning counterpart of [`HashShards`].
+pub trait IntoHashShards<T> {
+    /// Moves the elements into `num_buckets` buckets by their hash.
+    ///
+    /// The buckets are the same as those of
+    /// [`shard_by_hash`](HashShards::shard_by_hash), and each keeps its elements in
+    /// their original order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_buckets` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::hash::{BuildHasherDefault, DefaultHasher};
+    ///
+    /// use equal_parts::shard::IntoHashShards;
+    ///
+    /// let names = vec![String::from("ann"), String::from("bob"), String::from("ann")];
+    /// let buckets = names.into_shard_by_hash(2, BuildHasherDefault::<DefaultHasher>::new());
+    ///
+    /// assert_eq!(buckets.len(), 2);
+    /// assert!(buckets.iter().any(|bucket| bucket.iter().filter(|name| *name == "ann").count() == 2));
+    /// ```
+    fn into_shard_by_hash<S>(self, num_buckets: usize, build_hasher: S) -> Vec<Vec<T>>
+    where
+        T: Hash,
+        S: BuildHasher;
+}
+
+impl<T> IntoHashShards<T> for Vec<T> {
+    fn into_shard_by_hash<S>(self, num_buckets: usize, build_hasher: S) -> Vec<Vec<T>>
+    where
+        T: Hash,
+        S: BuildHasher,
+    {
+        assert!(num_buckets > 0, "Number of shards must be greater than 0");
+
+        let buckets_of: Vec<usize> = self
+            .iter()
+            .map(|item| shard_of(build_hasher.hash_one(item), num_buckets))
+            .collect();
+        let mut buckets: Vec<Vec<T>> = (0..num_buckets).map(|_| Vec::new()).collect();
+        for (item, bucket) in self.into_iter().zip(buckets_of) {
+            buckets[bucket].push(item);
+        }
+        buckets
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::hash::{BuildHasherDefault, DefaultHasher};
+
+    use super::{HashShards, IntoHashShards, JumpShards, shard_of};
+
+    /// A splitmix64 step, to turn sequential integers into well-mixed hashes.
+    fn mix(mut x: u64) -> u64 {
+        x = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        x = (x ^ (x >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        x = (x ^ (x >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        x ^ (x >> 31)
+    }
+
+    #[test]
+    fn single_shard_and_zero_key() {
+        assert_eq!(shard_of(0, 1), 0);
+        assert_eq!(shard_of(0, 1000), 0);
+        assert_eq!(shard_of(u64::MAX, 1), 0);
+        // A key only ever moves up as shards are added.
+        let shards: Vec<usize> = (1..50).map(|n| shard_of(mix(1), n)).collect();
+        assert!(shards.windows(2).all(|pair| pair[0] <= pair[1]));
+    }
+
+    #[test]
+    fn growing_only_moves_keys_to_the_new_shard() {
+        for num_shards in 1..40 {
+            for key in 0..500 {
+                let before = shard_of(mix(key), num_shards);
+                let after = shard_of(mix(key), num_shards + 1);
+                assert!(before < num_shards);
+                assert!(after == before || after == num_shards);
+            }
+        }
+    }
+
+    #[test]
+    fn spreads_keys_evenly() {
+        let counts = (0..100_000).fold(vec![0usize; 10], |mut counts, key| {
+            counts[shard_of(mix(key), 10)] += 1;
+            counts
+        });
+        assert!(counts.iter().all(|&count| (9_000..11_000).contains(&count)));
+    }
+
+    #[test]
+    fn assignments_cover_every_index() {
+        let keys: Vec<u64> = (0..200).map(|key| mix(key % 37)).collect();
+        let shards = keys.jump_shard_assignments(6, |&hash| hash);
+        assert_eq!(shards.len(), 6);
+        for (shard, indices) in shards.iter().enumerate() {
+            assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+            assert!(indices.iter().all(|&i| shard_of(keys[i], 6) == shard));
+        }
+        assert_eq!(shards.iter().map(Vec::len).sum::<usize>(), 200);
+
+        let empty: Vec<u64> = Vec::new();
+        assert_eq!(
+            empty.jump_shards(3, |&hash| hash),
+            vec![Vec::<&u64>::new(); 3]
+        );
+    }
+
+    #[test]
+    fn hash_shards_agree_with_each_other() {
+        let hasher = BuildHasherDefault::<DefaultHasher>::new();
+        let data: Vec<u32> = (0..300).map(|i| i % 50).collect();
+
+        let indices = data.shard_indices_by_hash(7, hasher.clone());
+        let refs = data.shard_by_hash(7, hasher.clone());
+        let owned = data.clone().into_shard_by_hash(7, hasher);
+        assert_eq!(indices.len(), 7);
+        for ((indices, refs), owned) in indices.iter().zip(&refs).zip(&owned) {
+            let from_indices: Vec<u32> = indices.iter().map(|&i| data[i]).collect();
+            assert_eq!(
+                refs.iter().copied().copied().collect::<Vec<_>>(),
+                from_indices
+            );
+            assert_eq!(owned, &from_indices);
+        }
+
+        for value in 0..50 {
+            let holding = owned
+                .iter()
+                .filter(|bucket| bucket.contains(&value))
+                .count();
+            assert_eq!(holding, 1);
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn hash_shards_panic_with_zero_buckets() {
+        let _ = Vec::<u8>::new().into_shard_by_hash(0, BuildHasherDefault::<DefaultHasher>::new());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_shards() {
+        let _ = shard_of(1, 0);
+    }
+}
@@ -0,0 +1,197 @@
+use std::cell::Cell;
+use std::mem;
+use std::sync::atomic;
+
+use crate::{EqualParts, EqualPartsIter};
+
+/// Splits a mutable slice into approximately equal parts of [`Cell`]s.
+///
+/// Unlike the `&mut` parts of a mutable split, these parts are shared references, so
+/// they can be freely copied and any part can update any element. This is handy on a
+/// single thread when several parts need to be held at once, for example to process
+/// elements round-robin across parts. `Cell` is not `Sync`, so use [`atomic_parts`] to
+/// share elements between threads instead.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::shared::cell_parts;
+///
+/// let mut data = [1, 2, 3, 4, 5, 6];
+/// let parts: Vec<_> = cell_parts(&mut data, 2).collect();
+///
+/// // Swap the two halves element by element, holding both parts at once.
+/// for (a, b) in parts[0].iter().zip(parts[1]) {
+///     a.swap(b);
+/// }
+/// assert_eq!(data, [4, 5, 6, 1, 2, 3]);
+/// ```
+pub fn cell_parts<T>(data: &mut [T], num_parts: usize) -> EqualPartsIter<'_, Cell<T>> {
+    Cell::from_mut(data)
+        .as_slice_of_cells()
+        .equal_parts(num_parts)
+}
+
+/// Primitive integer and boolean types that have a matching atomic type with the same
+/// in-memory representation.
+///
+/// This trait is implemented for every primitive with an atomic counterpart on the
+/// target platform, and is sealed.
+pub trait AtomicPrimitive: Sized + private::Sealed {
+    /// The atomic version of this type, such as [`AtomicU32`](atomic::AtomicU32) for
+    /// `u32`.
+    type Atomic: Sync;
+
+    /// Views a mutable slice of this type as a shared slice of its atomic type.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slice is not aligned for the atomic type. This can only happen on
+    /// platforms where the atomic type is more strictly aligned than the primitive,
+    /// such as `AtomicU64` on some 32-bit targets.
+    fn as_atomic_slice(data: &mut [Self]) -> &[Self::Atomic];
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! impl_atomic_primitive {
+    ($($width:literal: $primitive:ty => $atomic:ident),* $(,)?) => {
+        $(
+            #[cfg(target_has_atomic = $width)]
+            impl private::Sealed for $primitive {}
+
+            #[cfg(target_has_atomic = $width)]
+            impl AtomicPrimitive for $primitive {
+                type Atomic = atomic::$atomic;
+
+                fn as_atomic_slice(data: &mut [Self]) -> &[Self::Atomic] {
+                    assert!(
+                        data.as_ptr() as usize % mem::align_of::<Self::Atomic>() == 0,
+                        "Slice is not aligned for {}",
+                        stringify!($atomic)
+                    );
+                    // SAFETY: the atomic type has the same size and bit validity as the
+                    // primitive, the pointer was just checked to be aligned for it (and
+                    // size equals alignment, so every element is), and the unique
+                    // borrow guarantees no non-atomic access while the result lives.
+                    unsafe {
+                        std::slice::from_raw_parts(data.as_mut_ptr().cast(), data.len())
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic_primitive! {
+    "8": bool => AtomicBool,
+    "8": u8 => AtomicU8,
+    "8": i8 => AtomicI8,
+    "16": u16 => AtomicU16,
+    "16": i16 => AtomicI16,
+    "32": u32 => AtomicU32,
+    "32": i32 => AtomicI32,
+    "64": u64 => AtomicU64,
+    "64": i64 => AtomicI64,
+    "ptr": usize => AtomicUsize,
+    "ptr": isize => AtomicIsize,
+}
+
+/// Splits a mutable slice of primitives into approximately equal parts of atomics.
+///
+/// The parts are shared references to atomics, so they can be sent to scoped threads
+/// and every thread can update any element without locks or unsafe code. This allows
+/// access patterns that `&mut` parts cannot express, such as threads that mostly write
+/// their own part but occasionally touch a neighbor's elements, or interleaved
+/// round-robin updates over the whole slice.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0, or if the slice is not aligned for the atomic type (see
+/// [`AtomicPrimitive::as_atomic_slice`]).
+///
+/// # Examples
+///
+/// ```
+/// use std::sync::atomic::Ordering;
+/// use std::thread;
+///
+/// use equal_parts::shared::atomic_parts;
+///
+/// let mut counts = [0u32; 8];
+/// let parts: Vec<_> = atomic_parts(&mut counts, 4).collect();
+///
+/// thread::scope(|scope| {
+///     for (i, &part) in parts.iter().enumerate() {
+///         let neighbor = parts[(i + 1) % parts.len()];
+///         scope.spawn(move || {
+///             part[0].fetch_add(1, Ordering::Relaxed);
+///             neighbor[1].fetch_add(10, Ordering::Relaxed);
+///         });
+///     }
+/// });
+///
+/// assert_eq!(counts, [1, 10, 1, 10, 1, 10, 1, 10]);
+/// ```
+pub fn atomic_parts<T: AtomicPrimitive>(
+    data: &mut [T],
+    num_parts: usize,
+) -> EqualPartsIter<'_, T::Atomic> {
+    T::as_atomic_slice(data).equal_parts(num_parts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AtomicPrimitive, atomic_parts, cell_parts};
+    use std::sync::atomic::Ordering;
+    use std::thread;
+
+    #[test]
+    fn cell_parts_follow_equal_parts() {
+        let mut data = [0; 7];
+        let lengths: Vec<usize> = cell_parts(&mut data, 3).map(<[_]>::len).collect();
+        assert_eq!(lengths, vec![3, 2, 2]);
+    }
+
+    #[test]
+    fn round_robin_updates_across_threads() {
+        let mut data = [0u64; 10];
+        let num_threads = 3;
+        let shared = u64::as_atomic_slice(&mut data);
+        thread::scope(|scope| {
+            for first in 0..num_threads {
+                scope.spawn(move || {
+                    for item in shared[first..].iter().step_by(num_threads) {
+                        item.store(first as u64 + 1, Ordering::Relaxed);
+                    }
+                });
+            }
+        });
+        assert_eq!(data, [1, 2, 3, 1, 2, 3, 1, 2, 3, 1]);
+    }
+
+    #[test]
+    fn bools_and_signed() {
+        let mut flags = [false; 4];
+        for part in atomic_parts(&mut flags, 2) {
+            part[0].store(true, Ordering::Relaxed);
+        }
+        assert_eq!(flags, [true, false, true, false]);
+
+        let mut values = [-1i8, -2];
+        atomic_parts(&mut values, 1).next().unwrap()[1].fetch_sub(1, Ordering::Relaxed);
+        assert_eq!(values, [-1, -3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = atomic_parts(&mut [1u8], 0);
+    }
+}
@@ -0,0 +1,156 @@
+use rand::Rng;
+use rand::seq::SliceRandom;
+
+use crate::IntoEqualParts;
+
+/// A trait for splitting collections into parts that are random samples rather than
+/// contiguous runs.
+///
+/// The elements are shuffled and then split like
+/// [`equal_parts`](crate::EqualParts::equal_parts), so the part lengths differ by at
+/// most one and the longer parts come first. This is handy for experiments and A/B
+/// bucketing, where each part should look like the whole. Passing a seeded random
+/// number generator makes the split reproducible.
+pub trait ShuffledEqualParts<'a, T> {
+    /// Splits the collection into `num_parts` parts of randomly chosen elements,
+    /// borrowing rather than moving them.
+    ///
+    /// Only references are shuffled, so the collection itself is left untouched. The
+    /// order of the elements within each part is random too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::shuffle::ShuffledEqualParts;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let users: Vec<u32> = (0..10).collect();
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let buckets = users.shuffled_equal_parts(3, &mut rng);
+    ///
+    /// assert_eq!(buckets.iter().map(Vec::len).collect::<Vec<_>>(), vec![4, 3, 3]);
+    /// ```
+    fn shuffled_equal_parts<R>(self, num_parts: usize, rng: &mut R) -> Vec<Vec<&'a T>>
+    where
+        R: Rng + ?Sized;
+}
+
+impl<'a, T> ShuffledEqualParts<'a, T> for &'a [T] {
+    fn shuffled_equal_parts<R>(self, num_parts: usize, rng: &mut R) -> Vec<Vec<&'a T>>
+    where
+        R: Rng + ?Sized,
+    {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let mut items: Vec<&'a T> = self.iter().collect();
+        items.shuffle(rng);
+        items.into_equal_parts(num_parts).collect()
+    }
+}
+
+impl<'a, T> ShuffledEqualParts<'a, T> for &'a Vec<T> {
+    fn shuffled_equal_parts<R>(self, num_parts: usize, rng: &mut R) -> Vec<Vec<&'a T>>
+    where
+        R: Rng + ?Sized,
+    {
+        self.as_slice().shuffled_equal_parts(num_parts, rng)
+    }
+}
+
+/// A trait for shuffling owned collections and splitting them into owned parts.
+///
+/// This is the owned counterpart of [`ShuffledEqualParts`].
+pub trait IntoShuffledEqualParts<T> {
+    /// Shuffles the elements in place, then splits them into `num_parts` owned parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::shuffle::IntoShuffledEqualParts;
+    /// use rand::SeedableRng;
+    /// use rand::rngs::StdRng;
+    ///
+    /// let samples: Vec<String> = (0..5).map(|i| format!("sample-{i}")).collect();
+    /// let mut rng = StdRng::seed_from_u64(7);
+    /// let parts = samples.into_shuffled_equal_parts(2, &mut rng);
+    ///
+    /// assert_eq!(parts.len(), 2);
+    /// assert_eq!(parts[0].len() + parts[1].len(), 5);
+    /// ```
+    fn into_shuffled_equal_parts<R>(self, num_parts: usize, rng: &mut R) -> Vec<Vec<T>>
+    where
+        R: Rng + ?Sized;
+}
+
+impl<T> IntoShuffledEqualParts<T> for Vec<T> {
+    fn into_shuffled_equal_parts<R>(mut self, num_parts: usize, rng: &mut R) -> Vec<Vec<T>>
+    where
+        R: Rng + ?Sized,
+    {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        self.shuffle(rng);
+        self.into_equal_parts(num_parts).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{IntoShuffledEqualParts, ShuffledEqualParts};
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn borrowed_parts_cover_every_element_once() {
+        let data: Vec<u32> = (0..50).collect();
+        let mut rng = StdRng::seed_from_u64(1);
+        let parts = data.shuffled_equal_parts(6, &mut rng);
+
+        let lens: Vec<usize> = parts.iter().map(Vec::len).collect();
+        assert_eq!(lens, vec![9, 9, 8, 8, 8, 8]);
+        let mut all: Vec<u32> = parts.concat().into_iter().copied().collect();
+        assert_ne!(all, data);
+        all.sort_unstable();
+        assert_eq!(all, data);
+    }
+
+    #[test]
+    fn same_seed_same_parts() {
+        let data: Vec<u32> = (0..20).collect();
+        let first = data
+            .clone()
+            .into_shuffled_equal_parts(3, &mut StdRng::seed_from_u64(9));
+        let second = data.into_shuffled_equal_parts(3, &mut StdRng::seed_from_u64(9));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn empty_input() {
+        let mut rng = StdRng::seed_from_u64(0);
+        assert!(
+            Vec::<u8>::new()
+                .shuffled_equal_parts(3, &mut rng)
+                .is_empty()
+        );
+        assert!(
+            Vec::<u8>::new()
+                .into_shuffled_equal_parts(3, &mut rng)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = vec![1, 2].into_shuffled_equal_parts(0, &mut StdRng::seed_from_u64(0));
+    }
+}
@@ -0,0 +1,155 @@
+use crate::EqualParts;
+use crate::full_part_count;
+
+/// A trait for custom containers that can be split like slices.
+///
+/// Implementing the two methods of this trait gives a type a blanket
+/// [`EqualParts`] implementation, with the same part sizes as slices. This suits
+/// view types over ring buffers, arenas, ropes and similar structures, which are cheap
+/// to split at an index but would otherwise need their own iterator.
+///
+/// `Self` is usually a borrowed view, such as a struct holding a reference, since
+/// splitting consumes it.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::EqualParts;
+/// use equal_parts::slice_like::SliceLike;
+///
+/// /// Every other element of a slice.
+/// #[derive(Debug, PartialEq)]
+/// struct Evens<'a>(&'a [u32]);
+///
+/// impl SliceLike for Evens<'_> {
+///     fn len(&self) -> usize {
+///         self.0.len().div_ceil(2)
+///     }
+///
+///     fn split_at_index(self, index: usize) -> (Self, Self) {
+///         let (left, right) = self.0.split_at((index * 2).min(self.0.len()));
+///         (Evens(left), Evens(right))
+///     }
+/// }
+///
+/// let data = [0, 1, 2, 3, 4, 5, 6, 7, 8];
+/// let parts: Vec<Evens> = Evens(&data).equal_parts(2).collect();
+/// assert_eq!(parts, vec![Evens(&data[..6]), Evens(&data[6..])]);
+/// ```
+pub trait SliceLike: Sized {
+    /// Returns the number of elements.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if there are no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Splits into the first `index` elements and the rest.
+    ///
+    /// `index` is never greater than [`len`](Self::len).
+    fn split_at_index(self, index: usize) -> (Self, Self);
+}
+
+/// Iterator that yields approximately equal parts of a [`SliceLike`] value.
+///
+/// This iterator is created by calling [`equal_parts`](EqualParts::equal_parts) on a
+/// type implementing [`SliceLike`].
+#[derive(Debug, Clone)]
+pub struct SliceLikeParts<S> {
+    rest: Option<S>,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<S: SliceLike> Iterator for SliceLikeParts<S> {
+    type Item = S;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.rest.take().filter(|rest| !rest.is_empty())?;
+
+        let split_point = self.part_size - (self.full_parts_left.min(1) ^ 1);
+        self.full_parts_left -= self.full_parts_left.min(1);
+
+        let (chunk, rest) = rest.split_at_index(split_point);
+        self.rest = Some(rest);
+        Some(chunk)
+    }
+}
+
+impl<S: SliceLike> EqualParts for S {
+    type Item = S;
+    type Iter = SliceLikeParts<S>;
+
+    fn equal_parts(self, num_parts: usize) -> Self::Iter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let len = self.len();
+        let part_size = len.div_ceil(num_parts);
+        SliceLikeParts {
+            rest: Some(self),
+            part_size,
+            full_parts_left: full_part_count(len, num_parts),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SliceLike;
+    use crate::EqualParts;
+
+    /// A view of a slice that counts how often it is split.
+    struct Counted<'a>(&'a [u8]);
+
+    impl SliceLike for Counted<'_> {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn split_at_index(self, index: usize) -> (Self, Self) {
+            let (left, right) = self.0.split_at(index);
+            (Counted(left), Counted(right))
+        }
+    }
+
+    #[test]
+    fn matches_slice_parts() {
+        let data: Vec<u8> = (0..23).collect();
+        for num_parts in 1..30 {
+            let ours: Vec<&[u8]> = Counted(&data).equal_parts(num_parts).map(|c| c.0).collect();
+            let expected: Vec<&[u8]> = data.equal_parts(num_parts).collect();
+            assert_eq!(ours, expected);
+        }
+    }
+
+    #[test]
+    fn empty_value() {
+        assert!(Counted(&[]).equal_parts(3).next().is_none());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = Counted(&[1]).equal_parts(0);
+    }
+
+    /// A slice-like value that is only a length, like a slice of a zero-sized type.
+    struct Len(usize);
+
+    impl SliceLike for Len {
+        fn len(&self) -> usize {
+            self.0
+        }
+
+        fn split_at_index(self, index: usize) -> (Self, Self) {
+            (Len(index), Len(self.0 - index))
+        }
+    }
+
+    #[test]
+    fn near_max_len() {
+        let lens: Vec<usize> = Len(usize::MAX).equal_parts(2).map(|part| part.0).collect();
+        assert_eq!(lens, vec![usize::MAX / 2 + 1, usize::MAX / 2]);
+    }
+}
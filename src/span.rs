@@ -0,0 +1,280 @@
+use std::cmp::Ordering;
+use std::ops::Range;
+
+use num_traits::{Num, NumCast};
+
+/// Splits a numeric interval into approximately equal sub-intervals.
+///
+/// Works with any type implementing [`num_traits::Num`] and [`NumCast`], including
+/// floats, every primitive integer and custom numeric types. The sub-intervals are
+/// half-open, contiguous, and together cover `span` exactly: the first starts at
+/// `span.start` and the last ends at `span.end`.
+///
+/// Rounding depends on whether division on `T` truncates:
+///
+/// - For integer-like types, the sub-intervals follow the same sizes as
+///   [`equal_parts`](crate::EqualParts::equal_parts): lengths differ by at most one,
+///   longer intervals come first, and there are fewer than `num_parts` intervals when
+///   the span is shorter than `num_parts`, so none is empty.
+/// - For other types, such as floats, boundary `i` is
+///   `start + (end - start) * i / num_parts`, and exactly `num_parts` intervals are
+///   produced unless the span is empty.
+///
+/// An empty or reversed span produces no intervals. Primitive integer spans may be
+/// wider than `T::MAX`, such as `i64::MIN..i64::MAX`; for other types, `end - start`
+/// must be representable in `T`.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::span::split_span;
+///
+/// let spans: Vec<_> = split_span(0.0..1.0, 4).collect();
+/// assert_eq!(spans, vec![0.0..0.25, 0.25..0.5, 0.5..0.75, 0.75..1.0]);
+///
+/// let spans: Vec<_> = split_span(-5i128..5, 3).collect();
+/// assert_eq!(spans, vec![-5..-1, -1..2, 2..5]);
+/// ```
+pub fn split_span<T>(span: Range<T>, num_parts: usize) -> EqualSpans<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    let Range { start, end } = span;
+    let two = T::one() + T::one();
+    let truncates = T::one() / two == T::zero();
+
+    let (count, mode) = if start.partial_cmp(&end) != Some(Ordering::Less) {
+        (0, SpanMode::Empty)
+    } else if let Some((origin, len)) = Origin::of(start, end).filter(|_| truncates) {
+        // Primitive integers are measured in 128 bits, where `end - start` cannot
+        // overflow even for spans wider than `T::MAX`.
+        let count = len.min(num_parts as u128);
+        let size = len / count;
+        let mode = SpanMode::Wide {
+            origin,
+            size,
+            remainder: len - size * count,
+        };
+        (count as usize, mode)
+    } else if truncates {
+        let len = end - start;
+        // There are never more parts than units.
+        let count = len.to_usize().map_or(num_parts, |len| len.min(num_parts));
+        let parts = T::from(count).expect("part count fits in the span type");
+        let size = len / parts;
+        let mode = SpanMode::Integer {
+            size,
+            remainder: len - size * parts,
+        };
+        (count, mode)
+    } else {
+        match T::from(num_parts) {
+            Some(parts) if end - start != T::zero() => (num_parts, SpanMode::Fractional { parts }),
+            _ => (0, SpanMode::Empty),
+        }
+    };
+
+    EqualSpans {
+        start,
+        end,
+        next: 0,
+        count,
+        mode,
+    }
+}
+
+/// Iterator over the sub-intervals of a numeric interval.
+///
+/// This iterator is created by [`split_span`].
+#[derive(Debug, Clone)]
+pub struct EqualSpans<T> {
+    start: T,
+    end: T,
+    /// Index of the next sub-interval.
+    next: usize,
+    /// Number of sub-intervals.
+    count: usize,
+    mode: SpanMode<T>,
+}
+
+#[derive(Debug, Clone)]
+enum SpanMode<T> {
+    Empty,
+    Wide {
+        origin: Origin,
+        size: u128,
+        remainder: u128,
+    },
+    Integer {
+        size: T,
+        remainder: T,
+    },
+    Fractional {
+        parts: T,
+    },
+}
+
+/// The start of a span of primitive integers, widened to 128 bits.
+#[derive(Debug, Clone, Copy)]
+enum Origin {
+    Signed(i128),
+    Unsigned(u128),
+}
+
+impl Origin {
+    /// Returns the widened start of `start..end` and its length, or `None` if either
+    /// end does not fit in a 128-bit integer.
+    ///
+    /// `start` must be less than `end`.
+    fn of<T: NumCast>(start: T, end: T) -> Option<(Origin, u128)> {
+        if let (Some(start), Some(end)) = (start.to_i128(), end.to_i128()) {
+            // The difference is below 2^128, so it is exact modulo 2^128.
+            return Some((Origin::Signed(start), end.wrapping_sub(start) as u128));
+        }
+        let (start, end) = (start.to_u128()?, end.to_u128()?);
+        Some((Origin::Unsigned(start), end - start))
+    }
+
+    /// Returns the value `offset` past the origin, which must lie within the span.
+    fn add<T: NumCast>(self, offset: u128) -> T {
+        match self {
+            Origin::Signed(start) => T::from(start.wrapping_add(offset as i128)),
+            Origin::Unsigned(start) => T::from(start + offset),
+        }
+        .expect("boundary lies within the span")
+    }
+}
+
+impl<T> EqualSpans<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    /// Returns the boundary before sub-interval `index`.
+    fn boundary(&self, index: usize) -> T {
+        if index == self.count {
+            return self.end;
+        }
+        match self.mode {
+            SpanMode::Empty => self.start,
+            SpanMode::Wide {
+                origin,
+                size,
+                remainder,
+            } => {
+                let index = index as u128;
+                origin.add(size * index + index.min(remainder))
+            }
+            SpanMode::Integer { size, remainder } => {
+                let index = T::from(index).expect("part index fits in the span type");
+                let extra = if index < remainder { index } else { remainder };
+                self.start + size * index + extra
+            }
+            SpanMode::Fractional { parts } => {
+                let index = T::from(index).expect("part index fits in the span type");
+                self.start + (self.end - self.start) * index / parts
+            }
+        }
+    }
+}
+
+impl<T> Iterator for EqualSpans<T>
+where
+    T: Num + NumCast + PartialOrd + Copy,
+{
+    type Item = Range<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next == self.count {
+            return None;
+        }
+
+        let index = self.next;
+        self.next += 1;
+        Some(self.boundary(index)..self.boundary(self.next))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_span;
+    use crate::EqualParts;
+
+    #[test]
+    fn integers_match_range_parts() {
+        for num_parts in 1..15 {
+            let spans: Vec<_> = split_span(0u64..11, num_parts).collect();
+            let ranges: Vec<_> = (0u64..11).equal_parts(num_parts).collect();
+            assert_eq!(spans, ranges);
+        }
+    }
+
+    #[test]
+    fn small_integer_types() {
+        // More parts than an i8 can count.
+        let spans: Vec<_> = split_span(-2i8..1, 1000).collect();
+        assert_eq!(spans, vec![-2..-1, -1..0, 0..1]);
+        let spans: Vec<_> = split_span(0u8..255, 2).collect();
+        assert_eq!(spans, vec![0..128, 128..255]);
+    }
+
+    #[test]
+    fn full_width_signed_spans() {
+        let spans: Vec<_> = split_span(i8::MIN..i8::MAX, 2).collect();
+        assert_eq!(spans, vec![-128..0, 0..127]);
+        let spans: Vec<_> = split_span(i8::MIN..i8::MAX, 1000).collect();
+        assert_eq!(spans.len(), 255);
+        assert!(spans.iter().all(|span| span.end - span.start == 1));
+
+        let spans: Vec<_> = split_span(i64::MIN..i64::MAX, 2).collect();
+        assert_eq!(spans, vec![i64::MIN..0, 0..i64::MAX]);
+        let spans: Vec<_> = split_span(i128::MIN..i128::MAX, 4).collect();
+        assert_eq!(spans[0], i128::MIN..i128::MIN / 2);
+        assert_eq!(spans[3].end, i128::MAX);
+        for pair in spans.windows(2) {
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+    }
+
+    #[test]
+    fn full_width_unsigned_spans() {
+        let spans: Vec<_> = split_span(0..u128::MAX, 2).collect();
+        assert_eq!(
+            spans,
+            vec![0..u128::MAX / 2 + 1, u128::MAX / 2 + 1..u128::MAX]
+        );
+        let spans: Vec<_> = split_span(0..u64::MAX, 2).collect();
+        let ranges: Vec<_> = (0..u64::MAX).equal_parts(2).collect();
+        assert_eq!(spans, ranges);
+    }
+
+    #[test]
+    fn floats_end_exactly() {
+        let spans: Vec<_> = split_span(0.1f64..0.7, 3).collect();
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0].start, 0.1);
+        assert_eq!(spans[2].end, 0.7);
+        for pair in spans.windows(2) {
+            assert_eq!(pair[0].end, pair[1].start);
+        }
+        let spans: Vec<_> = split_span(0.0f32..1.0, 1000).collect();
+        assert_eq!(spans.len(), 1000);
+    }
+
+    #[test]
+    fn empty_spans() {
+        assert_eq!(split_span(3..3, 2).next(), None);
+        assert_eq!(split_span(1.0..-1.0, 2).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = split_span(0.0..1.0, 0);
+    }
+}
@@ -0,0 +1,330 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+
+use crate::split_count;
+
+/// Reserves room for `additional` elements at the end of `vec` and splits that spare
+/// capacity into approximately equal uninitialized parts.
+///
+/// This lets a buffer be filled in parallel without first initializing it with
+/// placeholder values. Each [`SparePart`] covers a disjoint region and can be sent to
+/// its own thread to be filled, either safely with [`push`](SparePart::push) or
+/// directly through [`spare_mut`](SparePart::spare_mut). Once every part is full, pass
+/// them all to [`SpareCapacity::finalize`], which makes the new elements part of the
+/// `Vec` by extending its length once.
+///
+/// Part sizes follow [`equal_parts`](crate::EqualParts::equal_parts), so there are
+/// fewer than `num_parts` parts when `additional` is less than `num_parts`. Until
+/// `finalize` succeeds, the length of `vec` is unchanged.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+///
+/// use equal_parts::spare::split_spare_capacity;
+///
+/// let mut squares: Vec<u64> = Vec::new();
+/// let (spare, parts) = split_spare_capacity(&mut squares, 10, 3);
+///
+/// let parts = thread::scope(|scope| {
+///     let handles: Vec<_> = parts
+///         .into_iter()
+///         .map(|mut part| {
+///             scope.spawn(move || {
+///                 for i in part.offset()..part.offset() + part.len() {
+///                     part.push((i * i) as u64);
+///                 }
+///                 part
+///             })
+///         })
+///         .collect();
+///     handles.into_iter().map(|handle| handle.join().unwrap()).collect::<Vec<_>>()
+/// });
+///
+/// spare.finalize(parts).unwrap();
+/// assert_eq!(squares, vec![0, 1, 4, 9, 16, 25, 36, 49, 64, 81]);
+/// ```
+pub fn split_spare_capacity<T>(
+    vec: &mut Vec<T>,
+    additional: usize,
+    num_parts: usize,
+) -> (SpareCapacity<'_, T>, Vec<SparePart<'_, T>>) {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    vec.reserve(additional);
+    let base_len = vec.len();
+    let vec: *mut Vec<T> = vec;
+    // SAFETY: `vec` comes from a unique borrow that `SpareCapacity` holds on to for
+    // `'_`. The parts only cover the spare capacity, which the `Vec` never reads or
+    // writes through its own methods until `finalize` has taken the parts back.
+    let spare: &mut [MaybeUninit<T>] = unsafe { &mut (*vec).spare_capacity_mut()[..additional] };
+
+    let mut parts = Vec::with_capacity(num_parts.min(additional));
+    let mut rest = spare;
+    for (index, (offset, count)) in split_count(additional, num_parts)
+        .with_offsets()
+        .enumerate()
+    {
+        let (region, tail) = mem::take(&mut rest).split_at_mut(count);
+        rest = tail;
+        parts.push(SparePart {
+            region,
+            filled: 0,
+            index,
+            offset: base_len + offset,
+            owner: vec as usize,
+        });
+    }
+
+    let capacity = SpareCapacity {
+        vec,
+        base_len,
+        additional,
+        num_parts: parts.len(),
+        _borrow: PhantomData,
+    };
+    (capacity, parts)
+}
+
+/// Handle for committing the parts made by [`split_spare_capacity`] to the `Vec`.
+#[derive(Debug)]
+pub struct SpareCapacity<'a, T> {
+    vec: *mut Vec<T>,
+    base_len: usize,
+    additional: usize,
+    num_parts: usize,
+    _borrow: PhantomData<&'a mut Vec<T>>,
+}
+
+impl<'a, T> SpareCapacity<'a, T> {
+    /// Extends the `Vec` over all of its parts once every one of them is full.
+    ///
+    /// `parts` must contain every part returned alongside this handle, in any order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`IncompleteFill`] listing the parts that are missing or not full.
+    /// The length of the `Vec` is then left unchanged, and the elements already
+    /// written to the given parts are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `parts` contains a part that belongs to a different split.
+    pub fn finalize(
+        self,
+        parts: impl IntoIterator<Item = SparePart<'a, T>>,
+    ) -> Result<(), IncompleteFill> {
+        let mut complete = vec![false; self.num_parts];
+        let parts: Vec<SparePart<'a, T>> = parts.into_iter().collect();
+        for part in &parts {
+            assert!(
+                part.owner == self.vec as usize && part.index < self.num_parts,
+                "Part belongs to a different split"
+            );
+            complete[part.index] = part.is_full();
+        }
+
+        let missing: Vec<usize> = (0..self.num_parts)
+            .filter(|&index| !complete[index])
+            .collect();
+        if !missing.is_empty() {
+            // Dropping the parts drops whatever they hold.
+            return Err(IncompleteFill { missing });
+        }
+
+        for part in parts {
+            mem::forget(part);
+        }
+        // SAFETY: every part is full, so all `additional` elements after `base_len` are
+        // initialized, and the parts that could still access them were forgotten.
+        unsafe { (*self.vec).set_len(self.base_len + self.additional) };
+        Ok(())
+    }
+}
+
+/// An uninitialized region of a `Vec`'s spare capacity.
+///
+/// Created by [`split_spare_capacity`]. Dropping a part drops the elements already
+/// written to it, so they are never leaked or exposed half-filled.
+pub struct SparePart<'a, T> {
+    region: &'a mut [MaybeUninit<T>],
+    filled: usize,
+    index: usize,
+    offset: usize,
+    owner: usize,
+}
+
+impl<T> SparePart<'_, T> {
+    /// Returns the index in the `Vec` at which this part starts.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns the number of elements this part holds once full.
+    pub fn len(&self) -> usize {
+        self.region.len()
+    }
+
+    /// Returns `true` if this part has no room for any elements.
+    pub fn is_empty(&self) -> bool {
+        self.region.is_empty()
+    }
+
+    /// Returns the number of elements written so far.
+    pub fn filled(&self) -> usize {
+        self.filled
+    }
+
+    /// Returns `true` once every element of this part has been written.
+    pub fn is_full(&self) -> bool {
+        self.filled == self.region.len()
+    }
+
+    /// Writes the next element of this part.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the part is already full.
+    pub fn push(&mut self, value: T) {
+        assert!(!self.is_full(), "Part is already full");
+        self.region[self.filled].write(value);
+        self.filled += 1;
+    }
+
+    /// Returns the elements of this part that have not been written yet.
+    ///
+    /// After writing to a prefix of this slice directly, call
+    /// [`assume_filled`](Self::assume_filled) to record how many elements are now
+    /// initialized.
+    pub fn spare_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        &mut self.region[self.filled..]
+    }
+
+    /// Marks the first `count` elements of [`spare_mut`](Self::spare_mut) as written.
+    ///
+    /// # Safety
+    ///
+    /// Those `count` elements must have been initialized, and `count` must not exceed
+    /// the length of `spare_mut`.
+    pub unsafe fn assume_filled(&mut self, count: usize) {
+        debug_assert!(count <= self.region.len() - self.filled);
+        self.filled += count;
+    }
+}
+
+impl<T> Drop for SparePart<'_, T> {
+    fn drop(&mut self) {
+        // SAFETY: the first `filled` elements have been initialized, and the `Vec`
+        // never treats them as its own unless the part is forgotten by `finalize`.
+        unsafe {
+            ptr::drop_in_place(self.region[..self.filled].assume_init_mut());
+        }
+    }
+}
+
+impl<T> fmt::Debug for SparePart<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SparePart")
+            .field("offset", &self.offset)
+            .field("len", &self.len())
+            .field("filled", &self.filled)
+            .finish()
+    }
+}
+
+/// Error returned by [`SpareCapacity::finalize`] when some parts were not filled.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncompleteFill {
+    /// The indices of the parts that were missing or not full, in ascending order.
+    pub missing: Vec<usize>,
+}
+
+impl fmt::Display for IncompleteFill {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "parts {:?} were not completely filled", self.missing)
+    }
+}
+
+impl std::error::Error for IncompleteFill {}
+
+#[cfg(test)]
+mod tests {
+    use super::{IncompleteFill, split_spare_capacity};
+    use std::rc::Rc;
+
+    #[test]
+    fn appends_after_existing_elements() {
+        let mut data = vec![1, 2];
+        let (spare, mut parts) = split_spare_capacity(&mut data, 5, 2);
+        assert_eq!(
+            parts
+                .iter()
+                .map(|p| (p.offset(), p.len()))
+                .collect::<Vec<_>>(),
+            vec![(2, 3), (5, 2)]
+        );
+
+        // Fill the parts out of order, the second one through the raw region.
+        parts[1].spare_mut()[0].write(6);
+        parts[1].spare_mut()[1].write(7);
+        unsafe { parts[1].assume_filled(2) };
+        for value in 3..=5 {
+            parts[0].push(value);
+        }
+
+        parts.reverse();
+        spare.finalize(parts).unwrap();
+        assert_eq!(data, vec![1, 2, 3, 4, 5, 6, 7]);
+    }
+
+    #[test]
+    fn incomplete_parts_are_dropped() {
+        let marker = Rc::new(());
+        let mut data: Vec<Rc<()>> = Vec::new();
+        let (spare, mut parts) = split_spare_capacity(&mut data, 4, 2);
+        parts[0].push(marker.clone());
+        parts[0].push(marker.clone());
+        parts[1].push(marker.clone());
+        assert_eq!(Rc::strong_count(&marker), 4);
+
+        let err = spare.finalize(parts).unwrap_err();
+        assert_eq!(err, IncompleteFill { missing: vec![1] });
+        assert_eq!(Rc::strong_count(&marker), 1);
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn missing_parts_are_reported() {
+        let mut data: Vec<u8> = Vec::new();
+        let (spare, mut parts) = split_spare_capacity(&mut data, 3, 3);
+        parts.truncate(1);
+        parts[0].push(1);
+        assert_eq!(spare.finalize(parts).unwrap_err().missing, vec![1, 2]);
+        assert!(data.is_empty());
+    }
+
+    #[test]
+    fn zero_additional() {
+        let mut data = vec![1];
+        let (spare, parts) = split_spare_capacity(&mut data, 0, 4);
+        assert!(parts.is_empty());
+        spare.finalize(parts).unwrap();
+        assert_eq!(data, vec![1]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn push_past_end_panics() {
+        let mut data = Vec::new();
+        let (_spare, mut parts) = split_spare_capacity(&mut data, 1, 1);
+        parts[0].push(1);
+        parts[0].push(2);
+    }
+}
@@ -0,0 +1,169 @@
+use std::iter;
+
+use crossbeam_deque::{Injector, Stealer, Worker};
+
+use crate::EqualParts;
+use crate::parallel::scoped_map;
+
+/// Processes owned parts on `num_workers` work-stealing threads, returning the results
+/// in the order the parts were given.
+///
+/// Every part is pushed into a shared [`Injector`] up front. Each worker thread keeps
+/// its own deque, refills it from the injector in batches, and steals from the other
+/// workers once the injector runs dry, so a worker that draws cheap parts keeps helping
+/// the others until everything is done.
+///
+/// Work stealing pays off when there are several times more parts than workers, for
+/// example the output of [`into_equal_parts`](crate::IntoEqualParts::into_equal_parts)
+/// with `num_parts` set to a multiple of the thread count.
+///
+/// Where [`map_parts`](crate::parallel::map_parts) would run on the calling thread, the
+/// workers do too, one after another, so the first worker processes every part in order.
+///
+/// # Panics
+///
+/// Panics if `num_workers` is 0, or if `f` panics on any thread.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::IntoEqualParts;
+/// use equal_parts::steal::steal_map;
+///
+/// let data: Vec<u64> = (1..=100).collect();
+/// let sums = steal_map(data.into_equal_parts(16), 4, |part| part.iter().sum::<u64>());
+///
+/// assert_eq!(sums.len(), 16);
+/// assert_eq!(sums.iter().sum::<u64>(), 5050);
+/// assert_eq!(sums[0], 1 + 2 + 3 + 4 + 5 + 6 + 7);
+/// ```
+pub fn steal_map<P, R, I, F>(parts: I, num_workers: usize, f: F) -> Vec<R>
+where
+    I: IntoIterator<Item = P>,
+    P: Send,
+    R: Send,
+    F: Fn(P) -> R + Sync,
+{
+    assert!(num_workers > 0, "Number of workers must be greater than 0");
+
+    let injector = Injector::new();
+    let mut num_parts = 0;
+    for part in parts {
+        injector.push((num_parts, part));
+        num_parts += 1;
+    }
+
+    let workers: Vec<Worker<(usize, P)>> = (0..num_workers.min(num_parts))
+        .map(|_| Worker::new_fifo())
+        .collect();
+    let stealers: Vec<Stealer<(usize, P)>> = workers.iter().map(Worker::stealer).collect();
+    let (injector, stealers, f) = (&injector, &stealers, &f);
+
+    let mut results: Vec<Option<R>> = iter::repeat_with(|| None).take(num_parts).collect();
+    let done = scoped_map(workers.into_iter().map(|local| {
+        move || {
+            let mut done = Vec::new();
+            while let Some((index, part)) = find_task(&local, injector, stealers) {
+                done.push((index, f(part)));
+            }
+            done
+        }
+    }));
+    for (index, result) in done.into_iter().flatten() {
+        results[index] = Some(result);
+    }
+
+    results
+        .into_iter()
+        .map(|result| result.expect("every part is processed by a worker"))
+        .collect()
+}
+
+/// Processes `num_chunks` approximately equal chunks of `data` on `num_workers`
+/// work-stealing threads, returning one result per chunk in order.
+///
+/// This is [`steal_map`] over [`equal_parts`](EqualParts::equal_parts), for splitting
+/// a slice into many fine-grained chunks without first collecting them.
+///
+/// # Panics
+///
+/// Panics if `num_chunks` or `num_workers` is 0, or if `f` panics on any thread.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::steal::steal_map_chunks;
+///
+/// let data: Vec<u32> = (0..1000).collect();
+/// let maxima = steal_map_chunks(&data, 10, 3, |chunk| *chunk.iter().max().unwrap());
+/// assert_eq!(maxima, vec![99, 199, 299, 399, 499, 599, 699, 799, 899, 999]);
+/// ```
+pub fn steal_map_chunks<T, R, F>(data: &[T], num_chunks: usize, num_workers: usize, f: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&[T]) -> R + Sync,
+{
+    assert!(num_chunks > 0, "Number of parts must be greater than 0");
+    steal_map(data.equal_parts(num_chunks), num_workers, f)
+}
+
+/// Takes the next task from the local deque, the injector, or another worker, in that
+/// order. Returns `None` once all of them are empty.
+fn find_task<T>(local: &Worker<T>, injector: &Injector<T>, stealers: &[Stealer<T>]) -> Option<T> {
+    local.pop().or_else(|| {
+        iter::repeat_with(|| {
+            injector
+                .steal_batch_and_pop(local)
+                .or_else(|| stealers.iter().map(Stealer::steal).collect())
+        })
+        .find(|steal| !steal.is_retry())
+        .and_then(|steal| steal.success())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{steal_map, steal_map_chunks};
+    use crate::IntoEqualParts;
+    use std::thread::sleep;
+    use std::time::Duration;
+
+    #[test]
+    fn keeps_part_order() {
+        let data: Vec<u32> = (0..1000).collect();
+        let parts: Vec<Vec<u32>> = data.clone().into_equal_parts(37).collect();
+        let results = steal_map(parts.clone(), 4, |part| part);
+        assert_eq!(results, parts);
+    }
+
+    #[test]
+    fn uneven_costs() {
+        let results = steal_map(0..20u64, 3, |x| {
+            if x < 3 {
+                sleep(Duration::from_millis(20));
+            }
+            x * 10
+        });
+        assert_eq!(results, (0..20).map(|x| x * 10).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn more_workers_than_parts() {
+        assert_eq!(steal_map([1, 2], 8, |x| x + 1), vec![2, 3]);
+        assert!(steal_map(Vec::<u8>::new(), 2, |x| x).is_empty());
+    }
+
+    #[test]
+    fn chunks_match_equal_parts() {
+        let data: Vec<u8> = (0..50).collect();
+        let lengths = steal_map_chunks(&data, 7, 2, <[u8]>::len);
+        assert_eq!(lengths, vec![8, 7, 7, 7, 7, 7, 7]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn propagates_worker_panics() {
+        steal_map(0..10, 2, |x| assert_ne!(x, 5));
+    }
+}
@@ -0,0 +1,366 @@
+use std::ops::Range;
+
+use crate::count::SplitCountOffsets;
+use crate::split_count;
+
+/// Splits a mutable slice into parts for stencil computations, giving each part
+/// read-only margins of its neighbors' elements.
+///
+/// A part's core is an exclusive `&mut` slice, so the cores can be updated in parallel.
+/// Its left and right halos hold up to `halo_width` elements on either side of the core,
+/// clipped at the ends of `data`. Since those elements belong to neighboring cores that
+/// may be changing at the same time, the halos are copies taken when the split is made:
+/// every part sees its neighbors as they were before any part was updated, which is
+/// what a Jacobi-style stencil step needs.
+///
+/// Cores follow the same sizes as [`equal_parts`](crate::EqualParts::equal_parts). Call
+/// [`parts`](HaloSplit::parts) to borrow them.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use std::thread;
+///
+/// use equal_parts::stencil::halo_split;
+///
+/// let mut data = [1, 2, 3, 4, 5, 6];
+/// let mut split = halo_split(&mut data, 2, 1);
+///
+/// thread::scope(|scope| {
+///     for part in split.parts() {
+///         scope.spawn(move || {
+///             // Add each element's neighbors to it, treating the outside as 0.
+///             let before = part.left.last().copied().unwrap_or(0);
+///             let after = part.right.first().copied().unwrap_or(0);
+///             let mut input = vec![before];
+///             input.extend_from_slice(part.core);
+///             input.push(after);
+///             for (value, window) in part.core.iter_mut().zip(input.windows(3)) {
+///                 *value += window[0] + window[2];
+///             }
+///         });
+///     }
+/// });
+///
+/// assert_eq!(data, [3, 6, 9, 12, 15, 11]);
+/// ```
+pub fn halo_split<T: Clone>(
+    data: &mut [T],
+    num_parts: usize,
+    halo_width: usize,
+) -> HaloSplit<'_, T> {
+    let len = data.len();
+    let mut cores = Vec::with_capacity(num_parts.min(len));
+    let mut halo_ranges = Vec::with_capacity(cores.capacity());
+    let mut halos = Vec::new();
+
+    for (start, count) in split_count(len, num_parts).with_offsets() {
+        let end = start + count;
+
+        let left_start = halos.len();
+        halos.extend_from_slice(&data[start.saturating_sub(halo_width)..start]);
+        let right_start = halos.len();
+        halos.extend_from_slice(&data[end..end.saturating_add(halo_width).min(len)]);
+
+        cores.push(start..end);
+        halo_ranges.push((left_start..right_start, right_start..halos.len()));
+    }
+
+    HaloSplit {
+        data,
+        cores,
+        halos,
+        halo_ranges,
+    }
+}
+
+/// A slice split into parts with read-only halos.
+///
+/// Created by [`halo_split`].
+#[derive(Debug)]
+pub struct HaloSplit<'a, T> {
+    data: &'a mut [T],
+    cores: Vec<Range<usize>>,
+    halos: Vec<T>,
+    halo_ranges: Vec<(Range<usize>, Range<usize>)>,
+}
+
+impl<T> HaloSplit<'_, T> {
+    /// Borrows every part, with its exclusive core and its halos.
+    ///
+    /// The halos always hold the values from when the split was made, even if this is
+    /// called again after the cores have been modified.
+    pub fn parts(&mut self) -> Vec<HaloPart<'_, T>> {
+        let mut rest = &mut *self.data;
+        let mut parts = Vec::with_capacity(self.cores.len());
+        for (core, (left, right)) in self.cores.iter().zip(&self.halo_ranges) {
+            let (core_slice, tail) = rest.split_at_mut(core.len());
+            rest = tail;
+            parts.push(HaloPart {
+                left: &self.halos[left.clone()],
+                core: core_slice,
+                right: &self.halos[right.clone()],
+                offset: core.start,
+            });
+        }
+        parts
+    }
+
+    /// Returns the number of parts.
+    pub fn len(&self) -> usize {
+        self.cores.len()
+    }
+
+    /// Returns `true` if there are no parts, which happens when the slice is empty.
+    pub fn is_empty(&self) -> bool {
+        self.cores.is_empty()
+    }
+}
+
+/// A single part of a [`HaloSplit`].
+#[derive(Debug)]
+pub struct HaloPart<'s, T> {
+    /// Copies of up to `halo_width` elements immediately before the core.
+    pub left: &'s [T],
+    /// The elements this part owns exclusively.
+    pub core: &'s mut [T],
+    /// Copies of up to `halo_width` elements immediately after the core.
+    pub right: &'s [T],
+    /// The index of the first core element in the original slice.
+    pub offset: usize,
+}
+
+/// A part of a slice extended by overlapping halo elements on both sides.
+///
+/// Yielded by [`OverlapParts`].
+#[derive(Debug)]
+pub struct OverlapPart<'a, T> {
+    /// The part's own elements together with up to `halo` elements on either side,
+    /// clipped at the ends of the slice.
+    pub data: &'a [T],
+    /// The range of `data` holding the part's own elements.
+    ///
+    /// The inner ranges of all parts together cover the slice exactly once, so
+    /// writing back only the results for this range never counts an element twice.
+    pub inner: Range<usize>,
+    /// The index of the first element of `data` in the original slice.
+    pub offset: usize,
+}
+
+impl<T> Clone for OverlapPart<'_, T> {
+    fn clone(&self) -> Self {
+        OverlapPart {
+            data: self.data,
+            inner: self.inner.clone(),
+            offset: self.offset,
+        }
+    }
+}
+
+impl<'a, T> OverlapPart<'a, T> {
+    /// Returns the part's own elements, without the halos.
+    pub fn inner_data(&self) -> &'a [T] {
+        &self.data[self.inner.clone()]
+    }
+
+    /// Returns the range of the part's own elements in the original slice.
+    pub fn inner_range(&self) -> Range<usize> {
+        self.offset + self.inner.start..self.offset + self.inner.end
+    }
+}
+
+/// Iterator over overlapping parts of a slice.
+///
+/// This iterator is created by calling
+/// [`equal_parts_with_overlap`](EqualPartsWithOverlap::equal_parts_with_overlap) on a
+/// slice.
+#[derive(Debug, Clone)]
+pub struct OverlapParts<'a, T> {
+    data: &'a [T],
+    offsets: SplitCountOffsets,
+    halo: usize,
+}
+
+impl<'a, T> Iterator for OverlapParts<'a, T> {
+    type Item = OverlapPart<'a, T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (start, count) = self.offsets.next()?;
+        let end = start + count;
+        let outer_start = start.saturating_sub(self.halo);
+        let outer_end = end.saturating_add(self.halo).min(self.data.len());
+        Some(OverlapPart {
+            data: &self.data[outer_start..outer_end],
+            inner: start - outer_start..end - outer_start,
+            offset: outer_start,
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.offsets.size_hint()
+    }
+}
+
+impl<T> ExactSizeIterator for OverlapParts<'_, T> {}
+
+/// A trait for splitting slices into parts that overlap their neighbors.
+///
+/// This is the read-only counterpart of [`halo_split`]: since every part is a shared
+/// borrow, the halos can point straight into the slice instead of being copied.
+pub trait EqualPartsWithOverlap<'a, T> {
+    /// Splits the slice into approximately equal parts, extending each one by `halo`
+    /// elements on each side.
+    ///
+    /// The parts' own elements follow the same sizes as
+    /// [`equal_parts`](crate::EqualParts::equal_parts), and the halos are clipped at
+    /// the ends of the slice. This suits stencil computations, convolutions, and
+    /// windowed signal processing, where each output needs a few neighboring inputs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::stencil::EqualPartsWithOverlap;
+    ///
+    /// let signal = [1, 2, 3, 4, 5, 6, 7, 8];
+    /// let mut smoothed = vec![0; signal.len()];
+    ///
+    /// for part in signal.equal_parts_with_overlap(3, 1) {
+    ///     // A three-point moving sum over the part and its halos.
+    ///     for index in part.inner.clone() {
+    ///         let window = &part.data[index.saturating_sub(1)..(index + 2).min(part.data.len())];
+    ///         smoothed[part.offset + index] = window.iter().sum();
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(smoothed, vec![3, 6, 9, 12, 15, 18, 21, 15]);
+    /// ```
+    fn equal_parts_with_overlap(self, num_parts: usize, halo: usize) -> OverlapParts<'a, T>;
+}
+
+impl<'a, T> EqualPartsWithOverlap<'a, T> for &'a [T] {
+    fn equal_parts_with_overlap(self, num_parts: usize, halo: usize) -> OverlapParts<'a, T> {
+        OverlapParts {
+            data: self,
+            offsets: split_count(self.len(), num_parts).with_offsets(),
+            halo,
+        }
+    }
+}
+
+impl<'a, T> EqualPartsWithOverlap<'a, T> for &'a Vec<T> {
+    fn equal_parts_with_overlap(self, num_parts: usize, halo: usize) -> OverlapParts<'a, T> {
+        self.as_slice().equal_parts_with_overlap(num_parts, halo)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EqualPartsWithOverlap, halo_split};
+    use crate::EqualParts;
+
+    #[test]
+    fn halos_are_clipped_at_ends() {
+        let mut data: Vec<i32> = (0..10).collect();
+        let mut split = halo_split(&mut data, 3, 2);
+        let parts = split.parts();
+        assert_eq!(parts.len(), 3);
+
+        assert_eq!(parts[0].left, &[] as &[i32]);
+        assert_eq!(&*parts[0].core, &[0, 1, 2, 3]);
+        assert_eq!(parts[0].right, &[4, 5]);
+
+        assert_eq!(parts[1].left, &[2, 3]);
+        assert_eq!(&*parts[1].core, &[4, 5, 6]);
+        assert_eq!(parts[1].right, &[7, 8]);
+        assert_eq!(parts[1].offset, 4);
+
+        assert_eq!(parts[2].left, &[5, 6]);
+        assert_eq!(&*parts[2].core, &[7, 8, 9]);
+        assert_eq!(parts[2].right, &[] as &[i32]);
+    }
+
+    #[test]
+    fn halos_wider_than_neighbors() {
+        let mut data = [1, 2, 3, 4];
+        let mut split = halo_split(&mut data, 4, 3);
+        let parts = split.parts();
+        assert_eq!(parts[1].left, &[1]);
+        assert_eq!(parts[1].right, &[3, 4]);
+        assert_eq!(parts[2].left, &[1, 2]);
+    }
+
+    #[test]
+    fn halos_keep_original_values() {
+        let mut data = [1, 1, 1, 1];
+        let mut split = halo_split(&mut data, 2, 1);
+        for part in split.parts() {
+            part.core.fill(9);
+        }
+        let parts = split.parts();
+        assert_eq!(parts[0].right, &[1]);
+        assert_eq!(parts[1].left, &[1]);
+        drop(parts);
+        assert_eq!(data, [9, 9, 9, 9]);
+    }
+
+    #[test]
+    fn empty_slice() {
+        let mut data: [u8; 0] = [];
+        let mut split = halo_split(&mut data, 3, 1);
+        assert!(split.is_empty());
+        assert!(split.parts().is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = halo_split(&mut [1, 2], 0, 1);
+    }
+
+    #[test]
+    fn overlap_inner_parts_match_equal_parts() {
+        let data: Vec<u32> = (0..23).collect();
+        for num_parts in 1..30 {
+            for halo in [0, 1, 4, 30] {
+                let parts: Vec<_> = data.equal_parts_with_overlap(num_parts, halo).collect();
+                let expected: Vec<&[u32]> = data.equal_parts(num_parts).collect();
+                assert_eq!(parts.len(), expected.len());
+                for (part, inner) in parts.iter().zip(expected) {
+                    assert_eq!(part.inner_data(), inner);
+                    assert_eq!(&data[part.inner_range()], inner);
+                    assert_eq!(part.inner.start, part.inner_range().start.min(halo));
+                    let end = part.inner_range().end;
+                    assert_eq!(
+                        part.data.len() - part.inner.end,
+                        (data.len() - end).min(halo)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn overlap_halos_are_clipped_at_ends() {
+        let data = [0, 1, 2, 3, 4, 5];
+        let parts: Vec<_> = data.equal_parts_with_overlap(2, 2).collect();
+        assert_eq!(parts[0].data, &[0, 1, 2, 3, 4]);
+        assert_eq!(parts[0].inner, 0..3);
+        assert_eq!(parts[1].data, &[1, 2, 3, 4, 5]);
+        assert_eq!(parts[1].inner, 2..5);
+        assert_eq!(parts[1].offset, 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn overlap_panics_with_zero_parts() {
+        let _ = [1, 2].equal_parts_with_overlap(0, 1);
+    }
+}
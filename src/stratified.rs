@@ -0,0 +1,335 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::ratio::fraction_sizes;
+
+/// A trait for splitting collections so that every part has the same mix of labels as
+/// the whole.
+///
+/// Each element is given a label, such as its class in a classification data set.
+/// The elements of each label are then dealt out across the parts in turn, so every
+/// part gets as close to the same proportion of every label as whole elements allow.
+pub trait StratifiedParts<'a, T> {
+    /// Assigns each element to one of `num_parts` parts, balancing every label across
+    /// the parts, and returns the element indices of each part.
+    ///
+    /// For every label, the number of elements with that label in any two parts
+    /// differs by at most one. The part lengths also differ by at most one, with the
+    /// longer parts first, like [`equal_parts`](crate::EqualParts::equal_parts). If
+    /// there are fewer elements than `num_parts`, there is one part per element.
+    ///
+    /// Labels are dealt out in the order they first appear, and the indices in each
+    /// part are in ascending order, so the result is deterministic. Shuffle the input
+    /// first for random parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::stratified::StratifiedParts;
+    ///
+    /// let labels = ["cat", "cat", "cat", "cat", "dog", "dog"];
+    /// let parts = labels.stratified_assignments(2, |&label| label);
+    ///
+    /// assert_eq!(parts, vec![vec![0, 2, 4], vec![1, 3, 5]]);
+    /// ```
+    fn stratified_assignments<L, F>(self, num_parts: usize, label: F) -> Vec<Vec<usize>>
+    where
+        L: Eq + Hash,
+        F: FnMut(&T) -> L;
+
+    /// Splits the collection into `num_parts` parts with the same mix of labels.
+    ///
+    /// This is [`stratified_assignments`](StratifiedParts::stratified_assignments)
+    /// with each index replaced by a reference to its element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::stratified::StratifiedParts;
+    ///
+    /// // (id, is_positive): 1 positive for every 3 negatives.
+    /// let samples: Vec<(u32, bool)> = (0..12).map(|id| (id, id % 4 == 0)).collect();
+    /// let parts = samples.stratified_parts(3, |&(_, positive)| positive);
+    ///
+    /// for part in &parts {
+    ///     assert_eq!(part.len(), 4);
+    ///     assert_eq!(part.iter().filter(|(_, positive)| *positive).count(), 1);
+    /// }
+    /// ```
+    fn stratified_parts<L, F>(self, num_parts: usize, label: F) -> Vec<Vec<&'a T>>
+    where
+        L: Eq + Hash,
+        F: FnMut(&T) -> L;
+
+    /// Splits the element indices by `fractions`, such as `[0.8, 0.1, 0.1]` for a
+    /// train/validation/test split, keeping the mix of labels in every part.
+    ///
+    /// The part sizes are exactly [`fraction_sizes`] of the whole length, and the
+    /// elements of every label are divided among the parts in proportion to those
+    /// sizes. Labels are rounded against running totals, in the order they first
+    /// appear: each element of a label goes to the part that is furthest behind its
+    /// share of all labels seen so far, with ties going to the earlier part. Rare
+    /// labels therefore still reach the smaller parts, instead of all landing in the
+    /// largest one, and no part drifts more than one element from its share. Within a
+    /// label, the earlier parts get the earlier elements. There is one list per
+    /// fraction, in order, and the indices in each list are in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`fraction_sizes`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::stratified::StratifiedParts;
+    ///
+    /// let labels = ["spam", "ham", "ham", "ham", "spam", "ham", "ham", "ham", "ham", "ham"];
+    /// let parts = labels.stratified_split_by_fractions(&[0.5, 0.5], |&label| label);
+    ///
+    /// assert_eq!(parts, vec![vec![0, 1, 2, 3, 5], vec![4, 6, 7, 8, 9]]);
+    /// ```
+    fn stratified_split_by_fractions<L, F>(self, fractions: &[f64], label: F) -> Vec<Vec<usize>>
+    where
+        L: Eq + Hash,
+        F: FnMut(&T) -> L;
+}
+
+/// Groups the indices of `data` by label, in the order the labels first appear.
+fn strata<T, L, F>(data: &[T], mut label: F) -> Vec<Vec<usize>>
+where
+    L: Eq + Hash,
+    F: FnMut(&T) -> L,
+{
+    let mut strata: Vec<Vec<usize>> = Vec::new();
+    let mut stratum_of: HashMap<L, usize> = HashMap::new();
+    for (index, item) in data.iter().enumerate() {
+        let stratum = *stratum_of.entry(label(item)).or_insert_with(|| {
+            strata.push(Vec::new());
+            strata.len() - 1
+        });
+        strata[stratum].push(index);
+    }
+    strata
+}
+
+impl<'a, T> StratifiedParts<'a, T> for &'a [T] {
+    fn stratified_assignments<L, F>(self, num_parts: usize, label: F) -> Vec<Vec<usize>>
+    where
+        L: Eq + Hash,
+        F: FnMut(&T) -> L,
+    {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        // Dealing the strata out back to back keeps both every label and the part
+        // lengths within one element of each other.
+        let num_parts = num_parts.min(self.len());
+        let mut parts = vec![Vec::new(); num_parts];
+        for (position, index) in strata(self, label).into_iter().flatten().enumerate() {
+            parts[position % num_parts].push(index);
+        }
+        for part in &mut parts {
+            part.sort_unstable();
+        }
+        parts
+    }
+
+    fn stratified_parts<L, F>(self, num_parts: usize, label: F) -> Vec<Vec<&'a T>>
+    where
+        L: Eq + Hash,
+        F: FnMut(&T) -> L,
+    {
+        self.stratified_assignments(num_parts, label)
+            .into_iter()
+            .map(|indices| indices.into_iter().map(|index| &self[index]).collect())
+            .collect()
+    }
+
+    fn stratified_split_by_fractions<L, F>(self, fractions: &[f64], label: F) -> Vec<Vec<usize>>
+    where
+        L: Eq + Hash,
+        F: FnMut(&T) -> L,
+    {
+        let len = self.len();
+        let targets = fraction_sizes(len, fractions);
+
+        // How far each part is behind its share of the strata seen so far, scaled by
+        // `len` so that the shares `stratum.len() * target / len` stay integers.
+        let mut deficits = vec![0i128; targets.len()];
+        let mut parts = vec![Vec::new(); targets.len()];
+        for stratum in strata(self, label) {
+            let mut sizes = vec![0; targets.len()];
+            for (deficit, &target) in deficits.iter_mut().zip(&targets) {
+                *deficit += stratum.len() as i128 * target as i128;
+            }
+            for _ in 0..stratum.len() {
+                let (part, _) = deficits
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .max_by_key(|&(_, deficit)| deficit)
+                    .expect("there is at least one fraction");
+                deficits[part] -= len as i128;
+                sizes[part] += 1;
+            }
+
+            let mut rest = stratum.as_slice();
+            for (part, size) in parts.iter_mut().zip(sizes) {
+                let (head, tail) = rest.split_at(size);
+                part.extend_from_slice(head);
+                rest = tail;
+            }
+        }
+        for part in &mut parts {
+            part.sort_unstable();
+        }
+        parts
+    }
+}
+
+impl<'a, T> StratifiedParts<'a, T> for &'a Vec<T> {
+    fn stratified_assignments<L, F>(self, num_parts: usize, label: F) -> Vec<Vec<usize>>
+    where
+        L: Eq + Hash,
+        F: FnMut(&T) -> L,
+    {
+        self.as_slice().stratified_assignments(num_parts, label)
+    }
+
+    fn stratified_parts<L, F>(self, num_parts: usize, label: F) -> Vec<Vec<&'a T>>
+    where
+        L: Eq + Hash,
+        F: FnMut(&T) -> L,
+    {
+        self.as_slice().stratified_parts(num_parts, label)
+    }
+
+    fn stratified_split_by_fractions<L, F>(self, fractions: &[f64], label: F) -> Vec<Vec<usize>>
+    where
+        L: Eq + Hash,
+        F: FnMut(&T) -> L,
+    {
+        self.as_slice()
+            .stratified_split_by_fractions(fractions, label)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StratifiedParts;
+    use crate::ratio::fraction_sizes;
+
+    #[test]
+    fn every_label_is_balanced() {
+        let labels: Vec<u8> = (0..97u32).map(|i| (i * i % 7) as u8).collect();
+        for num_parts in 1..12 {
+            let parts = labels.stratified_assignments(num_parts, |&label| label);
+            assert_eq!(parts.len(), num_parts);
+
+            let lens: Vec<usize> = parts.iter().map(Vec::len).collect();
+            assert!(lens.windows(2).all(|pair| pair[0] >= pair[1]));
+            assert!(lens[0] - lens[num_parts - 1] <= 1);
+
+            for label in 0..7 {
+                let counts: Vec<usize> = parts
+                    .iter()
+                    .map(|part| part.iter().filter(|&&i| labels[i] == label).count())
+                    .collect();
+                let max = counts.iter().max().unwrap();
+                let min = counts.iter().min().unwrap();
+                assert!(max - min <= 1);
+            }
+
+            let mut all = parts.concat();
+            all.sort_unstable();
+            assert_eq!(all, (0..97).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    fn fewer_elements_than_parts() {
+        let parts = ["a", "b"].stratified_parts(4, |s| s.len());
+        assert_eq!(parts, vec![vec![&"a"], vec![&"b"]]);
+        assert!(Vec::<u8>::new().stratified_parts(2, |&x| x).is_empty());
+    }
+
+    #[test]
+    fn fractions_split_every_label() {
+        let labels: Vec<u8> = (0..200u32).map(|i| (i % 3 + i % 5) as u8).collect();
+        let fractions = [0.7, 0.2, 0.1];
+        let parts = labels.stratified_split_by_fractions(&fractions, |&label| label);
+        assert_eq!(parts.len(), 3);
+
+        for label in 0..7 {
+            let count = labels.iter().filter(|&&l| l == label).count();
+            for (part, fraction) in parts.iter().zip(fractions) {
+                let in_part = part.iter().filter(|&&i| labels[i] == label).count();
+                assert!((in_part as f64 - count as f64 * fraction).abs() < 1.0);
+            }
+        }
+
+        let mut all = parts.concat();
+        all.sort_unstable();
+        assert_eq!(all, (0..200).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn fractions_reach_small_parts_with_many_rare_labels() {
+        let fractions = [0.8, 0.1, 0.1];
+        for len in [1, 7, 10, 99, 100, 333] {
+            // Every row has its own label.
+            let rows: Vec<usize> = (0..len).collect();
+            let parts = rows.stratified_split_by_fractions(&fractions, |&row| row);
+            let lens: Vec<usize> = parts.iter().map(Vec::len).collect();
+            assert_eq!(lens, fraction_sizes(len, &fractions));
+
+            // Two rows per label.
+            let parts = rows.stratified_split_by_fractions(&fractions, |&row| row / 2);
+            let lens: Vec<usize> = parts.iter().map(Vec::len).collect();
+            assert_eq!(lens, fraction_sizes(len, &fractions));
+        }
+    }
+
+    #[test]
+    fn fraction_totals_match_fraction_sizes() {
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+        for _ in 0..200 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let len = (seed % 300) as usize;
+            let num_labels = (seed >> 20) % 40 + 1;
+            let labels: Vec<u64> = (0..len as u64)
+                .map(|i| ((i * 2_654_435_761) ^ seed) % num_labels)
+                .collect();
+            let fractions = [0.6, 0.25, 0.1, 0.05];
+
+            let parts = labels.stratified_split_by_fractions(&fractions, |&label| label);
+            let lens: Vec<usize> = parts.iter().map(Vec::len).collect();
+            assert_eq!(lens, fraction_sizes(len, &fractions));
+
+            let mut all = parts.concat();
+            all.sort_unstable();
+            assert_eq!(all, (0..len).collect::<Vec<_>>());
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn fractions_are_checked_for_empty_input() {
+        let _ = Vec::<u8>::new().stratified_split_by_fractions(&[0.5], |&x| x);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = [1].stratified_assignments(0, |&x| x);
+    }
+}
@@ -0,0 +1,326 @@
+use std::collections::VecDeque;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Waker};
+
+use futures_core::Stream;
+
+use crate::full_part_count;
+
+/// Splits a stream of known length into approximately equal contiguous sub-streams.
+///
+/// The returned iterator yields `num_parts` [`StreamPart`]s (or fewer, if `len` is less
+/// than `num_parts`). Each part produces its contiguous share of the first `len` items
+/// of `stream`, with larger parts first, exactly like
+/// [`equal_parts`](crate::EqualParts::equal_parts) does for slices.
+///
+/// The parts share the underlying stream, which is always read in order. When a part
+/// is polled while the stream is positioned inside an earlier part's range, the items
+/// it reads along the way are buffered for their owners. Polling the parts in order
+/// therefore needs no buffering at all, while polling them concurrently buffers only
+/// the items that a later part had to read through to reach its own range. Items that
+/// belong to a part which has already been dropped are read past and discarded.
+///
+/// If the stream ends before `len` items, the remaining parts end early. Items past
+/// `len` are never read.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::stream_equal_parts;
+/// use futures::executor::block_on;
+/// use futures::stream::{self, StreamExt};
+///
+/// let source = stream::iter(1..=7);
+/// let parts: Vec<_> = stream_equal_parts(source, 7, 3).collect();
+///
+/// let collected: Vec<Vec<i32>> = block_on(async {
+///     let mut collected = Vec::new();
+///     for part in parts {
+///         collected.push(part.collect().await);
+///     }
+///     collected
+/// });
+/// assert_eq!(collected, vec![vec![1, 2, 3], vec![4, 5], vec![6, 7]]);
+/// ```
+pub fn stream_equal_parts<S: Stream>(stream: S, len: usize, num_parts: usize) -> StreamParts<S> {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    let part_size = len.div_ceil(num_parts);
+    let full_parts = full_part_count(len, num_parts);
+
+    let mut ends = Vec::with_capacity(num_parts.min(len));
+    let mut end = 0;
+    while end < len {
+        end += if ends.len() < full_parts {
+            part_size
+        } else {
+            part_size - 1
+        };
+        ends.push(end);
+    }
+
+    let part_count = ends.len();
+    StreamParts {
+        shared: Arc::new(Mutex::new(Shared {
+            stream: Box::pin(stream),
+            consumed: 0,
+            current: 0,
+            ends,
+            buffers: (0..part_count).map(|_| VecDeque::new()).collect(),
+            wakers: vec![None; part_count],
+            closed: vec![false; part_count],
+            finished: false,
+        })),
+        next_index: 0,
+        part_count,
+    }
+}
+
+/// Iterator over the sub-streams created by [`stream_equal_parts`].
+pub struct StreamParts<S: Stream> {
+    shared: Arc<Mutex<Shared<S>>>,
+    next_index: usize,
+    part_count: usize,
+}
+
+impl<S: Stream> Iterator for StreamParts<S> {
+    type Item = StreamPart<S>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.next_index == self.part_count {
+            return None;
+        }
+
+        let index = self.next_index;
+        self.next_index += 1;
+        Some(StreamPart {
+            shared: Arc::clone(&self.shared),
+            index,
+        })
+    }
+}
+
+/// A contiguous share of a stream split by [`stream_equal_parts`].
+pub struct StreamPart<S: Stream> {
+    shared: Arc<Mutex<Shared<S>>>,
+    index: usize,
+}
+
+struct Shared<S: Stream> {
+    stream: Pin<Box<S>>,
+    /// Number of items read from the stream so far.
+    consumed: usize,
+    /// Index of the part that owns the next item read from the stream.
+    current: usize,
+    /// Exclusive end offset of each part.
+    ends: Vec<usize>,
+    /// Items read on behalf of a part by another part's poll.
+    buffers: Vec<VecDeque<S::Item>>,
+    /// Wakers of parts waiting on the stream.
+    wakers: Vec<Option<Waker>>,
+    /// Whether each part has been dropped, so its items are discarded.
+    closed: Vec<bool>,
+    /// Whether the stream has ended.
+    finished: bool,
+}
+
+impl<S: Stream> Shared<S> {
+    fn wake_waiting(&mut self) {
+        for waker in self.wakers.iter_mut().filter_map(Option::take) {
+            waker.wake();
+        }
+    }
+
+    fn close(&mut self, index: usize) {
+        self.closed[index] = true;
+        self.buffers[index] = VecDeque::new();
+    }
+}
+
+impl<S: Stream> Stream for StreamPart<S> {
+    type Item = S::Item;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let index = self.index;
+        let mut shared = self.shared.lock().unwrap_or_else(|err| err.into_inner());
+
+        loop {
+            if let Some(item) = shared.buffers[index].pop_front() {
+                return Poll::Ready(Some(item));
+            }
+            if shared.finished || shared.consumed >= shared.ends[index] {
+                return Poll::Ready(None);
+            }
+
+            match shared.stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => {
+                    let owner = shared.current;
+                    shared.consumed += 1;
+                    if shared.consumed == shared.ends[owner] {
+                        shared.current += 1;
+                    }
+                    // Any part parked on the stream may now be able to make progress.
+                    shared.wake_waiting();
+
+                    if owner == index {
+                        return Poll::Ready(Some(item));
+                    }
+                    if !shared.closed[owner] {
+                        shared.buffers[owner].push_back(item);
+                    }
+                }
+                Poll::Ready(None) => {
+                    shared.finished = true;
+                    shared.wake_waiting();
+                }
+                Poll::Pending => {
+                    shared.wakers[index] = Some(cx.waker().clone());
+                    return Poll::Pending;
+                }
+            }
+        }
+    }
+}
+
+impl<S: Stream> Drop for StreamPart<S> {
+    fn drop(&mut self) {
+        // Nobody can read this part's items any more, so stop buffering them.
+        //
+        // The stream only keeps the waker of the last part that polled it. If that was
+        // this part, nobody would be woken when the stream makes progress, so hand the
+        // wakeup to every other waiting part instead.
+        let mut shared = self.shared.lock().unwrap_or_else(|err| err.into_inner());
+        shared.close(self.index);
+        shared.wakers[self.index] = None;
+        shared.wake_waiting();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::task::{Context, Poll};
+
+    use super::stream_equal_parts;
+    use futures::channel::mpsc;
+    use futures::executor::block_on;
+    use futures::future::join_all;
+    use futures::stream::{self, Stream, StreamExt};
+    use futures::task::{ArcWake, waker};
+
+    /// A waker that records whether it was woken.
+    struct Flag(AtomicBool);
+
+    impl ArcWake for Flag {
+        fn wake_by_ref(arc_self: &Arc<Self>) {
+            arc_self.0.store(true, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn sequential_parts() {
+        let parts = stream_equal_parts(stream::iter(1..=10), 10, 4);
+        let collected: Vec<Vec<i32>> =
+            block_on(async { join_all(parts.map(|part| part.collect())).await });
+        assert_eq!(
+            collected,
+            vec![vec![1, 2, 3], vec![4, 5, 6], vec![7, 8], vec![9, 10]]
+        );
+    }
+
+    #[test]
+    fn later_part_first() {
+        let mut parts: Vec<_> = stream_equal_parts(stream::iter(1..=6), 6, 3).collect();
+        let last: Vec<i32> = block_on(parts.pop().unwrap().collect());
+        let first: Vec<i32> = block_on(parts.remove(0).collect());
+        let middle: Vec<i32> = block_on(parts.remove(0).collect());
+        assert_eq!(first, vec![1, 2]);
+        assert_eq!(middle, vec![3, 4]);
+        assert_eq!(last, vec![5, 6]);
+    }
+
+    #[test]
+    fn fewer_items_than_parts() {
+        let parts: Vec<_> = stream_equal_parts(stream::iter(1..=2), 2, 5).collect();
+        assert_eq!(parts.len(), 2);
+    }
+
+    #[test]
+    fn short_stream_ends_parts_early() {
+        let parts = stream_equal_parts(stream::iter(1..=3), 6, 2);
+        let collected: Vec<Vec<i32>> =
+            block_on(async { join_all(parts.map(|part| part.collect())).await });
+        assert_eq!(collected, vec![vec![1, 2, 3], vec![]]);
+    }
+
+    #[test]
+    fn ignores_items_past_len() {
+        let parts = stream_equal_parts(stream::iter(1..), 4, 2);
+        let collected: Vec<Vec<i32>> =
+            block_on(async { join_all(parts.map(|part| part.collect())).await });
+        assert_eq!(collected, vec![vec![1, 2], vec![3, 4]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = stream_equal_parts(stream::iter(1..=3), 3, 0);
+    }
+
+    #[test]
+    fn near_max_len() {
+        let parts = stream_equal_parts(stream::empty::<()>(), usize::MAX, 2);
+        assert_eq!(parts.count(), 2);
+    }
+
+    #[test]
+    fn dropped_parts_are_not_buffered() {
+        let mut parts = stream_equal_parts(stream::iter(1..=8), 8, 4);
+        let first = parts.next().unwrap();
+        let second = parts.next().unwrap();
+        let third = parts.next().unwrap();
+        drop(first);
+
+        assert_eq!(block_on(third.collect::<Vec<_>>()), vec![5, 6]);
+        let shared = second.shared.lock().unwrap();
+        assert_eq!(shared.buffers[0].len(), 0);
+        assert_eq!(shared.buffers[1].len(), 2);
+        drop(shared);
+        assert_eq!(block_on(second.collect::<Vec<_>>()), vec![3, 4]);
+    }
+
+    #[test]
+    fn dropping_a_pending_part_wakes_the_others() {
+        let (sender, receiver) = mpsc::unbounded();
+        let mut parts = stream_equal_parts(receiver, 2, 2);
+        let mut first = parts.next().unwrap();
+        let mut second = parts.next().unwrap();
+
+        let first_flag = Arc::new(Flag(AtomicBool::new(false)));
+        let first_waker = waker(Arc::clone(&first_flag));
+        let mut first_cx = Context::from_waker(&first_waker);
+        assert!(pin!(&mut first).poll_next(&mut first_cx).is_pending());
+
+        // The second part polls last, so the stream now only knows its waker.
+        let second_flag = Arc::new(Flag(AtomicBool::new(false)));
+        let second_waker = waker(Arc::clone(&second_flag));
+        let mut second_cx = Context::from_waker(&second_waker);
+        assert!(pin!(&mut second).poll_next(&mut second_cx).is_pending());
+
+        drop(second);
+        assert!(first_flag.0.load(Ordering::SeqCst));
+
+        sender.unbounded_send(7).unwrap();
+        assert_eq!(
+            pin!(&mut first).poll_next(&mut first_cx),
+            Poll::Ready(Some(7))
+        );
+    }
+}
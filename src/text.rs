@@ -0,0 +1,334 @@
+use crate::boundary::snapped_boundaries;
+use crate::full_part_count;
+use crate::{EqualParts, split_count};
+
+/// Iterator that yields approximately equal parts of a string slice.
+///
+/// This iterator is created by calling [`equal_parts`](EqualParts::equal_parts) on a
+/// `&str`. Parts are measured in `char`s rather than bytes, and every part is itself a
+/// `&str`, so no UTF-8 code point is ever split.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::EqualParts;
+///
+/// let text = "añb€c😀d";
+/// let parts: Vec<&str> = text.equal_parts(3).collect();
+/// assert_eq!(parts, vec!["añb", "€c", "😀d"]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct StrEqualPartsIter<'a> {
+    text: &'a str,
+    part_size: usize,
+    full_parts_left: usize,
+}
+
+impl<'a> Iterator for StrEqualPartsIter<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.text.is_empty() {
+            None
+        } else {
+            let chars = self.part_size - (self.full_parts_left.min(1) ^ 1);
+            self.full_parts_left -= self.full_parts_left.min(1);
+
+            let split_point = self
+                .text
+                .char_indices()
+                .nth(chars)
+                .map_or(self.text.len(), |(index, _)| index);
+            let (chunk, rest) = self.text.split_at(split_point);
+            self.text = rest;
+            Some(chunk)
+        }
+    }
+}
+
+impl<'a> EqualParts for &'a str {
+    type Item = &'a str;
+    type Iter = StrEqualPartsIter<'a>;
+
+    /// Splits the string into parts with approximately equal numbers of `char`s.
+    ///
+    /// Counting the characters takes one pass over the string up front.
+    fn equal_parts(self, num_parts: usize) -> Self::Iter {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let len = self.chars().count();
+        let part_size = len.div_ceil(num_parts);
+        StrEqualPartsIter {
+            text: self,
+            part_size,
+            full_parts_left: full_part_count(len, num_parts),
+        }
+    }
+}
+
+/// A trait for splitting string slices into parts balanced by something other than
+/// `char` count.
+///
+/// [`equal_parts`](EqualParts::equal_parts) on a `&str` balances the number of
+/// `char`s per part. The methods of this trait balance other measures of size, and
+/// always return `&str` parts that join back into the original string.
+pub trait StrParts<'a> {
+    /// Splits the string into up to `num_parts` parts of approximately equal length in
+    /// UTF-8 bytes, never splitting a `char`.
+    ///
+    /// This is the right measure when the parts go somewhere with a byte limit, such as
+    /// a request body: with non-ASCII text, parts with equal `char` counts can differ
+    /// a lot in bytes. The boundaries start at the byte offsets
+    /// [`equal_parts`](EqualParts::equal_parts) would use for a byte slice, and each
+    /// one moves to the nearest `char` boundary, so it moves by at most two bytes. Ties
+    /// go to the earlier boundary. No part is empty, and there are fewer than
+    /// `num_parts` parts only if the string has fewer `char`s than that.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::text::StrParts;
+    ///
+    /// // 4 ASCII bytes followed by 4 three-byte characters.
+    /// let text = "abcd日本語文";
+    /// let parts = text.equal_byte_parts(2);
+    ///
+    /// assert_eq!(parts, vec!["abcd日", "本語文"]);
+    /// assert_eq!(parts.iter().map(|part| part.len()).collect::<Vec<_>>(), vec![7, 9]);
+    /// ```
+    fn equal_byte_parts(self, num_parts: usize) -> Vec<&'a str>;
+
+    /// Splits the string into up to `num_parts` parts with approximately equal numbers
+    /// of lines, splitting only at line breaks.
+    ///
+    /// A line is everything up to and including a `\n`, plus any text after the last
+    /// one, so `\r\n` line endings stay with their lines. The lines are divided like
+    /// the elements of [`equal_parts`](EqualParts::equal_parts): line counts differ by
+    /// at most one, the parts with more lines come first, and there is one part per
+    /// line if there are fewer lines than `num_parts`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::text::StrParts;
+    ///
+    /// let text = "first\nsecond line is longer\nthird\nfourth\nfifth";
+    /// let parts = text.equal_line_parts(2);
+    ///
+    /// assert_eq!(parts, vec!["first\nsecond line is longer\nthird\n", "fourth\nfifth"]);
+    /// ```
+    fn equal_line_parts(self, num_parts: usize) -> Vec<&'a str>;
+
+    /// Splits the string into up to `num_parts` parts with approximately equal numbers
+    /// of words, splitting only at the start of a word.
+    ///
+    /// Words are separated by whitespace, as in [`str::split_whitespace`]. Each part
+    /// keeps the whitespace that follows its words, and the first part also keeps any
+    /// leading whitespace, so the parts join back into the original string. Word counts
+    /// differ by at most one, the parts with more words come first, and there is one
+    /// part per word if there are fewer words than `num_parts`. A string with no words
+    /// at all, only whitespace, is returned as a single part.
+    ///
+    /// Word counts are a cheap stand-in for token counts when chunking documents for
+    /// language models and other NLP pipelines.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::text::StrParts;
+    ///
+    /// let text = "The quick brown fox jumps over the lazy dog";
+    /// let parts = text.equal_word_parts(3);
+    ///
+    /// assert_eq!(parts, vec!["The quick brown ", "fox jumps over ", "the lazy dog"]);
+    /// ```
+    fn equal_word_parts(self, num_parts: usize) -> Vec<&'a str>;
+}
+
+impl<'a> StrParts<'a> for &'a str {
+    fn equal_byte_parts(self, num_parts: usize) -> Vec<&'a str> {
+        let boundaries = snapped_boundaries(self.len(), num_parts, |position| {
+            self.is_char_boundary(position)
+        });
+        split_str_at(self, &boundaries)
+    }
+
+    fn equal_line_parts(self, num_parts: usize) -> Vec<&'a str> {
+        let line_starts: Vec<usize> = std::iter::once(0)
+            .chain(
+                self.match_indices('\n')
+                    .map(|(index, _)| index + 1)
+                    .filter(|&start| start < self.len()),
+            )
+            .collect();
+        let line_count = if self.is_empty() {
+            0
+        } else {
+            line_starts.len()
+        };
+
+        let boundaries: Vec<usize> = split_count(line_count, num_parts)
+            .with_offsets()
+            .skip(1)
+            .map(|(line, _)| line_starts[line])
+            .collect();
+        split_str_at(self, &boundaries)
+    }
+
+    fn equal_word_parts(self, num_parts: usize) -> Vec<&'a str> {
+        let mut word_starts = Vec::new();
+        let mut after_space = true;
+        for (index, c) in self.char_indices() {
+            let is_space = c.is_whitespace();
+            if after_space && !is_space {
+                word_starts.push(index);
+            }
+            after_space = is_space;
+        }
+
+        // The first part starts at 0 to keep any leading whitespace.
+        let boundaries: Vec<usize> = split_count(word_starts.len(), num_parts)
+            .with_offsets()
+            .skip(1)
+            .map(|(word, _)| word_starts[word])
+            .collect();
+        split_str_at(self, &boundaries)
+    }
+}
+
+/// Splits `text` at the given strictly increasing inner byte boundaries, which must
+/// all be `char` boundaries.
+pub(crate) fn split_str_at<'a>(text: &'a str, boundaries: &[usize]) -> Vec<&'a str> {
+    if text.is_empty() {
+        return Vec::new();
+    }
+    let mut parts = Vec::with_capacity(boundaries.len() + 1);
+    let mut start = 0;
+    for &boundary in boundaries {
+        parts.push(&text[start..boundary]);
+        start = boundary;
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StrParts;
+    use crate::EqualParts;
+
+    #[test]
+    fn ascii_matches_bytes() {
+        let parts: Vec<&str> = "abcdefg".equal_parts(3).collect();
+        assert_eq!(parts, vec!["abc", "de", "fg"]);
+    }
+
+    #[test]
+    fn counts_chars_not_bytes() {
+        let text = "ééééé";
+        let parts: Vec<&str> = text.equal_parts(2).collect();
+        assert_eq!(parts, vec!["ééé", "éé"]);
+        assert_eq!(parts.concat(), text);
+    }
+
+    #[test]
+    fn fewer_chars_than_parts() {
+        let parts: Vec<&str> = "日本".equal_parts(5).collect();
+        assert_eq!(parts, vec!["日", "本"]);
+        assert_eq!("".equal_parts(3).next(), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = "abc".equal_parts(0);
+    }
+
+    #[test]
+    fn byte_parts_balance_bytes() {
+        let text = "añb€c😀d".repeat(20);
+        for num_parts in 1..40 {
+            let parts = text.equal_byte_parts(num_parts);
+            assert_eq!(parts.len(), num_parts);
+            assert_eq!(parts.concat(), text);
+
+            let share = text.len() as f64 / num_parts as f64;
+            for part in &parts {
+                assert!((part.len() as f64 - share).abs() <= 4.0 + 1.0);
+            }
+        }
+    }
+
+    #[test]
+    fn byte_parts_of_ascii_match_equal_parts() {
+        let parts = "abcdefg".equal_byte_parts(3);
+        assert_eq!(parts, vec!["abc", "de", "fg"]);
+    }
+
+    #[test]
+    fn line_parts_balance_lines() {
+        let text: String = (0..37)
+            .map(|i| format!("{}\r\n", "x".repeat(i * 7 % 11)))
+            .collect();
+        for num_parts in 1..45 {
+            let parts = text.equal_line_parts(num_parts);
+            assert_eq!(parts.concat(), text);
+            let lines: Vec<usize> = parts.iter().map(|part| part.lines().count()).collect();
+            let expected: Vec<usize> = crate::split_count(37, num_parts).collect();
+            assert_eq!(lines, expected);
+            assert!(parts.iter().all(|part| part.ends_with("\r\n")));
+        }
+    }
+
+    #[test]
+    fn line_parts_edge_cases() {
+        assert!("".equal_line_parts(3).is_empty());
+        assert_eq!("no newline".equal_line_parts(3), vec!["no newline"]);
+        assert_eq!("\n\n\n".equal_line_parts(2), vec!["\n\n", "\n"]);
+        assert_eq!("a\nb".equal_line_parts(2), vec!["a\n", "b"]);
+    }
+
+    #[test]
+    fn word_parts_balance_words() {
+        let text: String = (0..41)
+            .map(|i| format!("{}{}", "w".repeat(i % 5 + 1), [" ", "\n", "\t  "][i % 3]))
+            .collect();
+        let text = format!("  {text}");
+        for num_parts in 1..50 {
+            let parts = text.equal_word_parts(num_parts);
+            assert_eq!(parts.concat(), text);
+            let words: Vec<usize> = parts
+                .iter()
+                .map(|part| part.split_whitespace().count())
+                .collect();
+            let expected: Vec<usize> = crate::split_count(41, num_parts).collect();
+            assert_eq!(words, expected);
+        }
+    }
+
+    #[test]
+    fn word_parts_edge_cases() {
+        assert!("".equal_word_parts(2).is_empty());
+        assert_eq!("   ".equal_word_parts(2), vec!["   "]);
+        assert_eq!(" a  b ".equal_word_parts(5), vec![" a  ", "b "]);
+        assert_eq!("héllo wörld".equal_word_parts(2), vec!["héllo ", "wörld"]);
+    }
+
+    #[test]
+    fn byte_parts_with_few_chars() {
+        assert_eq!("😀😁".equal_byte_parts(5), vec!["😀", "😁"]);
+        assert!("".equal_byte_parts(2).is_empty());
+    }
+}
@@ -0,0 +1,528 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::mem::size_of_val;
+
+use crate::min_max::min_max_ranges;
+
+/// The number of payload bytes an element holds, used as its weight by
+/// [`equal_parts_by_size`](WeightedEqualParts::equal_parts_by_size).
+///
+/// For strings and vectors this is the length of their contents in bytes. It is
+/// shallow: the payload of a `Vec<String>` is the size of its `String` handles, not
+/// of the text they point to.
+pub trait PayloadSize {
+    /// Returns the number of payload bytes.
+    fn payload_size(&self) -> usize;
+}
+
+impl PayloadSize for str {
+    fn payload_size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl PayloadSize for String {
+    fn payload_size(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<T> PayloadSize for [T] {
+    fn payload_size(&self) -> usize {
+        size_of_val(self)
+    }
+}
+
+impl<T> PayloadSize for Vec<T> {
+    fn payload_size(&self) -> usize {
+        self.as_slice().payload_size()
+    }
+}
+
+impl<T: PayloadSize + ?Sized> PayloadSize for Box<T> {
+    fn payload_size(&self) -> usize {
+        (**self).payload_size()
+    }
+}
+
+impl<T: PayloadSize + ?Sized> PayloadSize for &T {
+    fn payload_size(&self) -> usize {
+        (**self).payload_size()
+    }
+}
+
+/// A trait for splitting slices into parts of balanced total weight rather than
+/// balanced length.
+///
+/// When the cost of processing elements varies a lot, parts with equal numbers of
+/// elements can take very different amounts of time. The methods of this trait take
+/// a `cost` function that gives each element's weight and balance the sum of the
+/// weights in each part instead.
+/// The algorithm used to assign elements to parts in
+/// [`balanced_assignments_with`](WeightedEqualParts::balanced_assignments_with).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BalanceAlgorithm {
+    /// Longest-Processing-Time-first: each element, heaviest first, goes to the
+    /// currently lightest part.
+    ///
+    /// This is fast and its heaviest part is never more than 4/3 of the optimum.
+    #[default]
+    Lpt,
+    /// The Karmarkar–Karp differencing method, generalized to any number of parts.
+    ///
+    /// Partial partitions are repeatedly merged so that the heaviest parts of one
+    /// are combined with the lightest parts of the other, starting with the pair
+    /// that is most uneven. It usually balances much better than LPT when the
+    /// weights vary widely, at the cost of `O(len * num_parts)` extra memory and
+    /// `O(len * num_parts * log(num_parts))` extra time.
+    KarmarkarKarp,
+}
+
+pub trait WeightedEqualParts<'a, T> {
+    /// Splits the slice into up to `num_parts` contiguous parts, choosing the
+    /// boundaries to minimize the largest total weight of any part.
+    ///
+    /// The result is optimal: no other contiguous split into `num_parts` parts has a
+    /// lighter heaviest part. Exactly `num_parts` non-empty parts are returned, or one
+    /// per element if there are fewer elements than that.
+    ///
+    /// The boundaries are found by binary searching for the smallest feasible weight
+    /// limit, which takes `O(len * log(total weight))` time. `cost` is called once per
+    /// element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::weighted::WeightedEqualParts;
+    ///
+    /// // One expensive job among cheap ones.
+    /// let costs = [1u64, 1, 1, 1, 100, 1, 1, 1];
+    /// let parts = costs.equal_parts_by_weight(3, |&cost| cost);
+    ///
+    /// assert_eq!(parts, vec![&costs[..4], &costs[4..5], &costs[5..]]);
+    /// ```
+    fn equal_parts_by_weight<F>(self, num_parts: usize, cost: F) -> Vec<&'a [T]>
+    where
+        F: FnMut(&T) -> u64;
+
+    /// Splits the slice into up to `num_parts` contiguous parts with approximately
+    /// equal total payload sizes.
+    ///
+    /// This is [`equal_parts_by_weight`](WeightedEqualParts::equal_parts_by_weight)
+    /// with each element weighted by its [`PayloadSize`], such as the length of a
+    /// `String` or `Vec<u8>`. When element sizes span orders of magnitude, balancing
+    /// element counts says little about how much work each part holds.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::weighted::WeightedEqualParts;
+    ///
+    /// let documents = vec![
+    ///     "a".repeat(900),
+    ///     "b".repeat(50),
+    ///     "c".repeat(50),
+    ///     "d".repeat(400),
+    ///     "e".repeat(500),
+    /// ];
+    /// let parts = documents.equal_parts_by_size(2);
+    ///
+    /// assert_eq!(parts, vec![&documents[..2], &documents[2..]]);
+    /// ```
+    fn equal_parts_by_size(self, num_parts: usize) -> Vec<&'a [T]>
+    where
+        Self: Sized,
+        T: PayloadSize,
+    {
+        self.equal_parts_by_weight(num_parts, |item| item.payload_size() as u64)
+    }
+
+    /// Assigns each element to one of `num_parts` parts so that the total weights of
+    /// the parts are as even as possible, ignoring the order of the elements.
+    ///
+    /// This uses the Longest-Processing-Time-first rule: elements are visited from the
+    /// heaviest to the lightest, and each goes to the part with the smallest total so
+    /// far. The heaviest part is never more than 4/3 of the optimum, and usually much
+    /// closer. It runs in `O(len * log(len))` time.
+    ///
+    /// Exactly `num_parts` lists of element indices are returned, each sorted in
+    /// ascending order. Lists are empty when there are fewer elements than parts.
+    /// Equal weights are assigned in index order, so the result is deterministic.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::weighted::WeightedEqualParts;
+    ///
+    /// let costs = [7u64, 5, 4, 3, 3, 2];
+    /// let parts = costs.balanced_assignments(2, |&cost| cost);
+    ///
+    /// assert_eq!(parts, vec![vec![0, 3, 5], vec![1, 2, 4]]);
+    /// ```
+    fn balanced_assignments<F>(self, num_parts: usize, cost: F) -> Vec<Vec<usize>>
+    where
+        F: FnMut(&T) -> u64;
+
+    /// Assigns each element to one of `num_parts` parts like
+    /// [`balanced_assignments`](WeightedEqualParts::balanced_assignments), using the
+    /// given algorithm.
+    ///
+    /// The result has the same shape for every algorithm: exactly `num_parts` lists
+    /// of element indices, each sorted in ascending order. Which part ends up with
+    /// which elements is deterministic but depends on the algorithm.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::weighted::{BalanceAlgorithm, WeightedEqualParts};
+    ///
+    /// let costs = [8u64, 7, 6, 5, 4];
+    /// let total = |parts: &[Vec<usize>]| -> Vec<u64> {
+    ///     parts.iter().map(|part| part.iter().map(|&i| costs[i]).sum()).collect()
+    /// };
+    ///
+    /// let lpt = costs.balanced_assignments_with(2, BalanceAlgorithm::Lpt, |&c| c);
+    /// assert_eq!(total(&lpt), vec![17, 13]);
+    ///
+    /// let kk = costs.balanced_assignments_with(2, BalanceAlgorithm::KarmarkarKarp, |&c| c);
+    /// assert_eq!(total(&kk), vec![16, 14]);
+    /// ```
+    fn balanced_assignments_with<F>(
+        self,
+        num_parts: usize,
+        algorithm: BalanceAlgorithm,
+        cost: F,
+    ) -> Vec<Vec<usize>>
+    where
+        F: FnMut(&T) -> u64;
+
+    /// Gathers the elements into `num_parts` groups of balanced total weight.
+    ///
+    /// This is [`balanced_assignments`](WeightedEqualParts::balanced_assignments) with
+    /// each index replaced by a reference to its element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_parts` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use equal_parts::weighted::WeightedEqualParts;
+    ///
+    /// let files = ["a.log", "huge.log", "b.log", "c.log"];
+    /// let sizes = |name: &&str| if name.starts_with("huge") { 30 } else { 10 };
+    /// let groups = files.balanced_groups(2, sizes);
+    ///
+    /// assert_eq!(groups, vec![vec![&"huge.log"], vec![&"a.log", &"b.log", &"c.log"]]);
+    /// ```
+    fn balanced_groups<F>(self, num_parts: usize, cost: F) -> Vec<Vec<&'a T>>
+    where
+        F: FnMut(&T) -> u64;
+}
+
+impl<'a, T> WeightedEqualParts<'a, T> for &'a [T] {
+    fn equal_parts_by_weight<F>(self, num_parts: usize, cost: F) -> Vec<&'a [T]>
+    where
+        F: FnMut(&T) -> u64,
+    {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let costs: Vec<u64> = self.iter().map(cost).collect();
+        min_max_ranges(&costs, num_parts)
+            .into_iter()
+            .map(|range| &self[range])
+            .collect()
+    }
+
+    fn balanced_assignments<F>(self, num_parts: usize, cost: F) -> Vec<Vec<usize>>
+    where
+        F: FnMut(&T) -> u64,
+    {
+        self.balanced_assignments_with(num_parts, BalanceAlgorithm::Lpt, cost)
+    }
+
+    fn balanced_assignments_with<F>(
+        self,
+        num_parts: usize,
+        algorithm: BalanceAlgorithm,
+        cost: F,
+    ) -> Vec<Vec<usize>>
+    where
+        F: FnMut(&T) -> u64,
+    {
+        assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+        let costs: Vec<u64> = self.iter().map(cost).collect();
+        match algorithm {
+            BalanceAlgorithm::Lpt => lpt_assignments(&costs, num_parts),
+            BalanceAlgorithm::KarmarkarKarp => karmarkar_karp_assignments(&costs, num_parts),
+        }
+    }
+
+    fn balanced_groups<F>(self, num_parts: usize, cost: F) -> Vec<Vec<&'a T>>
+    where
+        F: FnMut(&T) -> u64,
+    {
+        self.balanced_assignments(num_parts, cost)
+            .into_iter()
+            .map(|indices| indices.into_iter().map(|index| &self[index]).collect())
+            .collect()
+    }
+}
+
+impl<'a, T> WeightedEqualParts<'a, T> for &'a Vec<T> {
+    fn equal_parts_by_weight<F>(self, num_parts: usize, cost: F) -> Vec<&'a [T]>
+    where
+        F: FnMut(&T) -> u64,
+    {
+        self.as_slice().equal_parts_by_weight(num_parts, cost)
+    }
+
+    fn balanced_assignments<F>(self, num_parts: usize, cost: F) -> Vec<Vec<usize>>
+    where
+        F: FnMut(&T) -> u64,
+    {
+        self.as_slice().balanced_assignments(num_parts, cost)
+    }
+
+    fn balanced_assignments_with<F>(
+        self,
+        num_parts: usize,
+        algorithm: BalanceAlgorithm,
+        cost: F,
+    ) -> Vec<Vec<usize>>
+    where
+        F: FnMut(&T) -> u64,
+    {
+        self.as_slice()
+            .balanced_assignments_with(num_parts, algorithm, cost)
+    }
+
+    fn balanced_groups<F>(self, num_parts: usize, cost: F) -> Vec<Vec<&'a T>>
+    where
+        F: FnMut(&T) -> u64,
+    {
+        self.as_slice().balanced_groups(num_parts, cost)
+    }
+}
+
+/// Assigns the indices of `costs` to `num_parts` parts with the
+/// Longest-Processing-Time-first rule.
+pub(crate) fn lpt_assignments(costs: &[u64], num_parts: usize) -> Vec<Vec<usize>> {
+    let mut order: Vec<usize> = (0..costs.len()).collect();
+    // Stable, so equal costs keep their index order.
+    order.sort_by_key(|&index| Reverse(costs[index]));
+
+    // Ties between equally loaded parts go to the lowest part index.
+    let mut loads: BinaryHeap<Reverse<(u128, usize)>> =
+        (0..num_parts).map(|part| Reverse((0, part))).collect();
+    let mut parts = vec![Vec::new(); num_parts];
+    for index in order {
+        let Reverse((load, part)) = loads.pop().expect("there is at least one part");
+        parts[part].push(index);
+        loads.push(Reverse((load + costs[index] as u128, part)));
+    }
+
+    for part in &mut parts {
+        part.sort_unstable();
+    }
+    parts
+}
+
+/// A partial partition for the differencing method: `num_parts` subsets with their
+/// totals, sorted from the heaviest to the lightest.
+type Subsets = Vec<(u128, Vec<usize>)>;
+
+/// Assigns the indices of `costs` to `num_parts` parts with the multi-way
+/// Karmarkar–Karp differencing method.
+fn karmarkar_karp_assignments(costs: &[u64], num_parts: usize) -> Vec<Vec<usize>> {
+    if costs.is_empty() {
+        return vec![Vec::new(); num_parts];
+    }
+
+    let spread = |subsets: &Subsets| subsets[0].0 - subsets[num_parts - 1].0;
+
+    // Every element starts as a partial partition of its own. The heap is keyed by
+    // spread, with ties going to the partition created first.
+    let mut partitions: Vec<Option<Subsets>> = Vec::with_capacity(2 * costs.len());
+    let mut heap = BinaryHeap::with_capacity(costs.len());
+    for (index, &cost) in costs.iter().enumerate() {
+        let mut subsets = vec![(0, Vec::new()); num_parts];
+        subsets[0] = (cost as u128, vec![index]);
+        heap.push((spread(&subsets), Reverse(partitions.len())));
+        partitions.push(Some(subsets));
+    }
+
+    loop {
+        let (_, Reverse(first)) = heap.pop().expect("there is at least one partition");
+        let Some((_, Reverse(second))) = heap.pop() else {
+            let mut parts: Vec<Vec<usize>> = partitions[first]
+                .take()
+                .expect("partitions are used once")
+                .into_iter()
+                .map(|(_, indices)| indices)
+                .collect();
+            for part in &mut parts {
+                part.sort_unstable();
+            }
+            return parts;
+        };
+
+        // Pair the heaviest subsets of one with the lightest of the other.
+        let first = partitions[first].take().expect("partitions are used once");
+        let second = partitions[second].take().expect("partitions are used once");
+        let mut merged: Subsets = first
+            .into_iter()
+            .zip(second.into_iter().rev())
+            .map(|((total, mut indices), (other_total, other_indices))| {
+                indices.extend(other_indices);
+                (total + other_total, indices)
+            })
+            .collect();
+        merged.sort_by_key(|&(total, _)| Reverse(total));
+
+        heap.push((spread(&merged), Reverse(partitions.len())));
+        partitions.push(Some(merged));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        BalanceAlgorithm, WeightedEqualParts, karmarkar_karp_assignments, lpt_assignments,
+    };
+
+    #[test]
+    fn fewer_elements_than_parts() {
+        let data = vec!["a", "bb"];
+        let parts = data.equal_parts_by_weight(5, |s| s.len() as u64);
+        assert_eq!(parts, vec![&["a"][..], &["bb"]]);
+        assert!(Vec::<u8>::new().equal_parts_by_weight(3, |_| 1).is_empty());
+    }
+
+    #[test]
+    fn parts_by_size_balance_bytes() {
+        let chunks: Vec<Vec<u8>> = [10, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 10]
+            .iter()
+            .map(|&len| vec![0; len])
+            .collect();
+        let lens: Vec<usize> = chunks
+            .equal_parts_by_size(2)
+            .iter()
+            .map(|part| part.len())
+            .collect();
+        assert_eq!(lens, vec![6, 6]);
+
+        let words: Vec<&str> = vec!["aaaa", "b", "b", "b", "b", "cccc"];
+        let parts = words.equal_parts_by_size(3);
+        assert_eq!(parts, vec![&words[..1], &words[1..5], &words[5..]]);
+    }
+
+    #[test]
+    fn payload_sizes() {
+        use super::PayloadSize;
+
+        assert_eq!("héllo".payload_size(), 6);
+        assert_eq!(vec![0u32; 3].payload_size(), 12);
+        assert_eq!(Box::<[u16]>::from(vec![1, 2]).payload_size(), 4);
+    }
+
+    #[test]
+    fn zero_weights() {
+        let data = [0u64; 6];
+        assert_eq!(data.equal_parts_by_weight(3, |&w| w).len(), 3);
+    }
+
+    #[test]
+    fn assignments_cover_every_index_once() {
+        let costs: Vec<u64> = (0..50).map(|i| (i * 37 % 23) + 1).collect();
+        for num_parts in 1..8 {
+            let parts = lpt_assignments(&costs, num_parts);
+            assert_eq!(parts.len(), num_parts);
+            let mut all: Vec<usize> = parts.concat();
+            all.sort_unstable();
+            assert_eq!(all, (0..50).collect::<Vec<_>>());
+
+            // LPT never exceeds 4/3 of the trivial lower bounds.
+            let total: u64 = costs.iter().sum();
+            let bound = (total.div_ceil(num_parts as u64)).max(*costs.iter().max().unwrap());
+            let heaviest = parts
+                .iter()
+                .map(|part| part.iter().map(|&i| costs[i]).sum::<u64>())
+                .max()
+                .unwrap();
+            assert!(heaviest * 3 <= bound * 4);
+        }
+    }
+
+    #[test]
+    fn assignments_beat_contiguous_parts() {
+        let costs = [9u64, 8, 1, 1, 1, 1, 1, 1, 1, 1];
+        let weight = |part: &[usize]| part.iter().map(|&i| costs[i]).sum::<u64>();
+        let parts = costs.balanced_assignments(2, |&cost| cost);
+        assert_eq!(parts.iter().map(|part| weight(part)).max(), Some(13));
+    }
+
+    #[test]
+    fn karmarkar_karp_covers_every_index_once() {
+        let costs: Vec<u64> = (0..40).map(|i| (i * i * 7919) % 1000 + 1).collect();
+        for num_parts in 1..7 {
+            let parts = karmarkar_karp_assignments(&costs, num_parts);
+            assert_eq!(parts.len(), num_parts);
+            let mut all: Vec<usize> = parts.concat();
+            all.sort_unstable();
+            assert_eq!(all, (0..40).collect::<Vec<_>>());
+        }
+        assert_eq!(
+            karmarkar_karp_assignments(&[], 3),
+            vec![Vec::<usize>::new(); 3]
+        );
+    }
+
+    #[test]
+    fn karmarkar_karp_balances_widely_varying_weights() {
+        let costs: Vec<u64> = (0..30).map(|i| 1u64 << (i % 17)).collect();
+        let heaviest = |parts: Vec<Vec<usize>>| {
+            parts
+                .iter()
+                .map(|part| part.iter().map(|&i| costs[i]).sum::<u64>())
+                .max()
+                .unwrap()
+        };
+        for num_parts in 2..5 {
+            let lpt = costs.balanced_assignments_with(num_parts, BalanceAlgorithm::Lpt, |&c| c);
+            let kk =
+                costs.balanced_assignments_with(num_parts, BalanceAlgorithm::KarmarkarKarp, |&c| c);
+            assert!(heaviest(kk) <= heaviest(lpt));
+        }
+    }
+
+    #[test]
+    fn more_parts_than_elements_leaves_empty_lists() {
+        let parts = vec![3u64, 1].balanced_assignments(4, |&cost| cost);
+        assert_eq!(parts, vec![vec![0], vec![1], vec![], vec![]]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = [1u64].equal_parts_by_weight(0, |&w| w);
+    }
+}
@@ -0,0 +1,127 @@
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::full_part_count;
+
+/// Splits a string into parts of approximately equal terminal display width.
+///
+/// Byte and char counts are poor proxies for how much room text takes up on screen:
+/// CJK characters and many emoji occupy two columns, while combining marks occupy
+/// none. This function balances the parts by their display width as reported by
+/// [`unicode-width`](https://docs.rs/unicode-width), and only ever cuts between
+/// extended grapheme clusters, so a character is never separated from its combining
+/// marks and emoji sequences stay intact.
+///
+/// Each boundary is placed at the grapheme boundary whose cumulative width is closest
+/// to the ideal equal split, with larger parts first when the total width doesn't
+/// divide evenly. Since graphemes can be wider than one column, the widths are not
+/// guaranteed to differ by at most one. Parts that would be empty are skipped, so
+/// fewer than `num_parts` parts are returned when there are too few graphemes.
+///
+/// # Panics
+///
+/// Panics if `num_parts` is 0.
+///
+/// # Examples
+///
+/// ```
+/// use equal_parts::equal_width_parts;
+///
+/// // Each of these CJK characters is two columns wide.
+/// let parts = equal_width_parts("ab漢字cd", 2);
+/// assert_eq!(parts, vec!["ab漢", "字cd"]);
+/// ```
+pub fn equal_width_parts(s: &str, num_parts: usize) -> Vec<&str> {
+    assert!(num_parts > 0, "Number of parts must be greater than 0");
+
+    // Byte offset and cumulative width at the end of each grapheme.
+    let mut total_width = 0;
+    let ends: Vec<(usize, usize)> = s
+        .grapheme_indices(true)
+        .map(|(offset, grapheme)| {
+            total_width += grapheme.width();
+            (offset + grapheme.len(), total_width)
+        })
+        .collect();
+
+    let part_size = total_width.div_ceil(num_parts);
+    let full_parts = full_part_count(total_width, num_parts);
+
+    let mut parts = Vec::with_capacity(num_parts.min(ends.len()));
+    let mut start = 0;
+    let mut next = 0;
+    let mut target = 0;
+    for part in 0..num_parts - 1 {
+        target += if part < full_parts {
+            part_size
+        } else {
+            part_size - 1
+        };
+
+        // Advance while the next grapheme boundary is at least as close to the target.
+        let mut end = start;
+        let mut width = ends[..next].last().map_or(0, |&(_, width)| width);
+        while let Some(&(offset, next_width)) = ends.get(next) {
+            if next_width.abs_diff(target) > width.abs_diff(target) || width >= target {
+                break;
+            }
+            end = offset;
+            width = next_width;
+            next += 1;
+        }
+
+        if end > start {
+            parts.push(&s[start..end]);
+            start = end;
+        }
+    }
+    if start < s.len() {
+        parts.push(&s[start..]);
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::equal_width_parts;
+
+    #[test]
+    fn ascii_is_balanced_like_chars() {
+        assert_eq!(equal_width_parts("abcdefg", 3), vec!["abc", "de", "fg"]);
+    }
+
+    #[test]
+    fn wide_characters_count_double() {
+        assert_eq!(
+            equal_width_parts("漢字漢字abcd", 2),
+            vec!["漢字漢", "字abcd"]
+        );
+        assert_eq!(equal_width_parts("abcd漢字", 2), vec!["abcd", "漢字"]);
+    }
+
+    #[test]
+    fn never_splits_graphemes() {
+        // "e" followed by a combining acute accent, and a family emoji ZWJ sequence.
+        let text = "e\u{301}e\u{301}👨\u{200d}👩\u{200d}👧";
+        let parts = equal_width_parts(text, 3);
+        assert_eq!(parts.concat(), text);
+        assert_eq!(parts, vec!["e\u{301}e\u{301}", "👨\u{200d}👩\u{200d}👧"]);
+    }
+
+    #[test]
+    fn fewer_graphemes_than_parts() {
+        assert_eq!(equal_width_parts("ab", 4), vec!["a", "b"]);
+        assert_eq!(equal_width_parts("漢", 3), vec!["漢"]);
+    }
+
+    #[test]
+    fn empty_string() {
+        assert!(equal_width_parts("", 3).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_with_zero_parts() {
+        let _ = equal_width_parts("abc", 0);
+    }
+}
@@ -0,0 +1,94 @@
+#![cfg(feature = "derive")]
+
+use equal_parts::{EqualParts, EqualPartsError, IntoEqualParts};
+
+#[derive(Debug, PartialEq, EqualParts)]
+struct Batch(Vec<u32>);
+
+#[derive(Debug, PartialEq, EqualParts)]
+struct Named {
+    rows: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, EqualParts)]
+struct Generic<T>(Vec<T>);
+
+#[derive(Debug, PartialEq, EqualParts)]
+struct Bounded<T: Clone>
+where
+    T: PartialEq,
+{
+    items: Vec<T>,
+}
+
+#[derive(EqualParts)]
+struct Samples([f32]);
+
+impl Samples {
+    fn new(data: &[f32]) -> &Samples {
+        // SAFETY: `Samples` is a newtype around `[f32]`, with the same layout.
+        unsafe { &*(data as *const [f32] as *const Samples) }
+    }
+}
+
+#[test]
+fn tuple_newtype() {
+    let batch = Batch(vec![1, 2, 3, 4, 5]);
+    let slices: Vec<&[u32]> = batch.equal_parts(2).collect();
+    assert_eq!(slices, vec![&[1, 2, 3][..], &[4, 5]]);
+
+    let batches: Vec<Batch> = batch.into_equal_parts(2).collect();
+    assert_eq!(batches, vec![Batch(vec![1, 2, 3]), Batch(vec![4, 5])]);
+}
+
+#[test]
+fn named_field() {
+    let named = Named {
+        rows: ["a", "b", "c"].map(String::from).to_vec(),
+    };
+    let slices: Vec<&[String]> = named.equal_parts(2).collect();
+    assert_eq!(slices, vec![&["a", "b"][..], &["c"]]);
+
+    let parts: Vec<Named> = named.into_equal_parts(3).collect();
+    assert_eq!(parts.len(), 3);
+    assert_eq!(parts[2].rows, vec!["c"]);
+}
+
+#[test]
+fn generic_newtypes() {
+    let generic = Generic(vec!['x', 'y', 'z']);
+    assert_eq!(generic.equal_parts(3).count(), 3);
+    let parts: Vec<Generic<char>> = generic.into_equal_parts(2).collect();
+    assert_eq!(parts, vec![Generic(vec!['x', 'y']), Generic(vec!['z'])]);
+
+    let bounded = Bounded {
+        items: vec![1.5, 2.5],
+    };
+    let parts: Vec<Bounded<f64>> = bounded.into_equal_parts(2).collect();
+    assert_eq!(parts[1], Bounded { items: vec![2.5] });
+}
+
+#[test]
+fn slice_newtype() {
+    let samples = Samples::new(&[0.0, 0.5, 1.0, 1.5]);
+    let parts: Vec<&[f32]> = samples.equal_parts(2).collect();
+    assert_eq!(parts, vec![&[0.0, 0.5][..], &[1.0, 1.5]]);
+}
+
+#[test]
+fn zero_parts_is_an_error() {
+    assert_eq!(
+        Batch(vec![1]).try_into_equal_parts(0).err(),
+        Some(EqualPartsError::ZeroParts)
+    );
+    assert_eq!(
+        Samples::new(&[1.0]).try_equal_parts(0).err(),
+        Some(EqualPartsError::ZeroParts)
+    );
+}
+
+#[test]
+fn diagnostics() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*.rs");
+}
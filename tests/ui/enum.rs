@@ -0,0 +1,8 @@
+use equal_parts::EqualParts;
+
+#[derive(EqualParts)]
+enum Batch {
+    Rows(Vec<u32>),
+}
+
+fn main() {}
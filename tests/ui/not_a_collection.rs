@@ -0,0 +1,6 @@
+use equal_parts::EqualParts;
+
+#[derive(EqualParts)]
+struct Batch(Box<[u32]>);
+
+fn main() {}
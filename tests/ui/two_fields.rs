@@ -0,0 +1,9 @@
+use equal_parts::EqualParts;
+
+#[derive(EqualParts)]
+struct Batch {
+    rows: Vec<u32>,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use equal_parts::EqualParts;
+
+#[derive(EqualParts)]
+struct Batch;
+
+fn main() {}